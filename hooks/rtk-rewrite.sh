@@ -86,6 +86,8 @@ elif echo "$MATCH_CMD" | grep -qE '^cargo[[:space:]]+install([[:space:]]|$)'; th
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^cargo install/rtk cargo install/')"
 elif echo "$MATCH_CMD" | grep -qE '^cargo[[:space:]]+fmt([[:space:]]|$)'; then
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^cargo fmt/rtk cargo fmt/')"
+elif echo "$MATCH_CMD" | grep -qE '^cargo[[:space:]]+(nextest[[:space:]]+run|bench|doc|tree|udeps)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^cargo /rtk cargo /')"
 
 # --- File operations ---
 elif echo "$MATCH_CMD" | grep -qE '^cat[[:space:]]+'; then
@@ -159,6 +161,14 @@ elif echo "$MATCH_CMD" | grep -qE '^wget[[:space:]]+'; then
 elif echo "$MATCH_CMD" | grep -qE '^pnpm[[:space:]]+(list|ls|outdated)([[:space:]]|$)'; then
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^pnpm /rtk pnpm /')"
 
+# --- yarn / bun package management ---
+elif echo "$MATCH_CMD" | grep -qE '^yarn[[:space:]]+(install|add|outdated|why|list|run)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^yarn /rtk yarn /')"
+elif echo "$MATCH_CMD" | grep -qE '^bun[[:space:]]+pm[[:space:]]+ls([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's/^bun pm ls/rtk bun list/')"
+elif echo "$MATCH_CMD" | grep -qE '^bun[[:space:]]+(install|add|outdated|why|run|test)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^bun /rtk bun /')"
+
 # --- Python tooling ---
 elif echo "$MATCH_CMD" | grep -qE '^pytest([[:space:]]|$)'; then
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^pytest/rtk pytest/')"
@@ -170,6 +180,30 @@ elif echo "$MATCH_CMD" | grep -qE '^pip[[:space:]]+(list|outdated|install|show)(
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^pip /rtk pip /')"
 elif echo "$MATCH_CMD" | grep -qE '^uv[[:space:]]+pip[[:space:]]+(list|outdated|install|show)([[:space:]]|$)'; then
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^uv pip /rtk pip /')"
+elif echo "$MATCH_CMD" | grep -qE '^uv[[:space:]]+(sync|lock|add|tree)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^uv /rtk uv /')"
+elif echo "$MATCH_CMD" | grep -qE '^poetry[[:space:]]+(install|lock|show)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^poetry /rtk poetry /')"
+elif echo "$MATCH_CMD" | grep -qE '^(uv|poetry)[[:space:]]+run[[:space:]]+(pytest|ruff|mypy|python3?[[:space:]]+-m[[:space:]]+pytest)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's/^(uv|poetry) /rtk \1 /')"
+
+# --- Ruby tooling (rtk adds bundle exec itself when a Gemfile exists) ---
+elif echo "$MATCH_CMD" | grep -qE '^(bundle[[:space:]]+exec[[:space:]]+)?rspec([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's/^(bundle exec )?rspec/rtk rspec/')"
+elif echo "$MATCH_CMD" | grep -qE '^(bundle[[:space:]]+exec[[:space:]]+)?rake[[:space:]]+test([[:space:]:]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's/^(bundle exec )?rake /rtk rake /')"
+elif echo "$MATCH_CMD" | grep -qE '^(bundle[[:space:]]+exec[[:space:]]+)?rubocop([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's/^(bundle exec )?rubocop/rtk rubocop/')"
+elif echo "$MATCH_CMD" | grep -qE '^bundle[[:space:]]+(install|update|outdated)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^bundle /rtk bundle /')"
+
+# --- PHP tooling (rtk prefers vendor/bin itself) ---
+elif echo "$MATCH_CMD" | grep -qE '^(vendor/bin/)?phpunit([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's#^(vendor/bin/)?phpunit#rtk phpunit#')"
+elif echo "$MATCH_CMD" | grep -qE '^(vendor/bin/)?phpstan([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's#^(vendor/bin/)?phpstan#rtk phpstan#')"
+elif echo "$MATCH_CMD" | grep -qE '^composer[[:space:]]+(install|update|outdated|require|remove)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^composer /rtk composer /')"
 
 # --- Go tooling ---
 elif echo "$MATCH_CMD" | grep -qE '^go[[:space:]]+test([[:space:]]|$)'; then
@@ -180,6 +214,30 @@ elif echo "$MATCH_CMD" | grep -qE '^go[[:space:]]+vet([[:space:]]|$)'; then
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^go vet/rtk go vet/')"
 elif echo "$MATCH_CMD" | grep -qE '^golangci-lint([[:space:]]|$)'; then
   REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^golangci-lint/rtk golangci-lint/')"
+
+# --- C/C++ build tools ---
+elif echo "$MATCH_CMD" | grep -qE '^make([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^make/rtk make/')"
+elif echo "$MATCH_CMD" | grep -qE '^cmake[[:space:]]+--build([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^cmake /rtk cmake /')"
+elif echo "$MATCH_CMD" | grep -qE '^ninja([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^ninja/rtk ninja/')"
+
+# --- JVM build tools (rtk picks the project wrapper itself) ---
+elif echo "$MATCH_CMD" | grep -qE '^(mvn|\./mvnw)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's#^(mvn|\./mvnw)#rtk mvn#')"
+elif echo "$MATCH_CMD" | grep -qE '^(gradle|\./gradlew)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed -E 's#^(gradle|\./gradlew)#rtk gradle#')"
+
+# --- .NET ---
+elif echo "$MATCH_CMD" | grep -qE '^dotnet[[:space:]]+(build|test|restore)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^dotnet /rtk dotnet /')"
+
+# --- Terraform ---
+elif echo "$MATCH_CMD" | grep -qE '^terraform[[:space:]]+(plan|apply|validate|init)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^terraform /rtk terraform /')"
+elif echo "$MATCH_CMD" | grep -qE '^tofu[[:space:]]+(plan|apply|validate|init)([[:space:]]|$)'; then
+  REWRITTEN="${ENV_PREFIX}$(echo "$CMD_BODY" | sed 's/^tofu /rtk tofu /')"
 fi
 
 # If no rewrite needed, approve as-is
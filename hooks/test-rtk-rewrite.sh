@@ -113,6 +113,14 @@ test_rewrite "cargo test" \
   "cargo test" \
   "rtk cargo test"
 
+test_rewrite "cargo nextest run" \
+  "cargo nextest run --workspace" \
+  "rtk cargo nextest run --workspace"
+
+test_rewrite "cargo tree -d" \
+  "cargo tree -d" \
+  "rtk cargo tree -d"
+
 test_rewrite "npx prisma migrate" \
   "npx prisma migrate" \
   "rtk prisma migrate"
@@ -165,6 +173,38 @@ test_rewrite "npm test" \
   "npm test" \
   "rtk npm test"
 
+test_rewrite "yarn add lodash" \
+  "yarn add lodash" \
+  "rtk yarn add lodash"
+
+test_rewrite "yarn outdated" \
+  "yarn outdated" \
+  "rtk yarn outdated"
+
+test_rewrite "bun test" \
+  "bun test src/" \
+  "rtk bun test src/"
+
+test_rewrite "bun pm ls" \
+  "bun pm ls" \
+  "rtk bun list"
+
+test_rewrite "uv sync" \
+  "uv sync --frozen" \
+  "rtk uv sync --frozen"
+
+test_rewrite "uv run pytest" \
+  "uv run pytest -x tests/" \
+  "rtk uv run pytest -x tests/"
+
+test_rewrite "poetry show --tree" \
+  "poetry show --tree" \
+  "rtk poetry show --tree"
+
+test_rewrite "poetry run python -m pytest" \
+  "poetry run python -m pytest" \
+  "rtk poetry run python -m pytest"
+
 test_rewrite "vue-tsc -b" \
   "vue-tsc -b" \
   "rtk tsc -b"
@@ -221,6 +261,66 @@ test_rewrite "kubectl apply -f deploy.yaml" \
   "kubectl apply -f deploy.yaml" \
   "rtk kubectl apply -f deploy.yaml"
 
+test_rewrite "terraform plan -var-file=prod.tfvars" \
+  "terraform plan -var-file=prod.tfvars" \
+  "rtk terraform plan -var-file=prod.tfvars"
+
+test_rewrite "tofu apply -auto-approve" \
+  "tofu apply -auto-approve" \
+  "rtk tofu apply -auto-approve"
+
+test_rewrite "make -j8" \
+  "make -j8" \
+  "rtk make -j8"
+
+test_rewrite "cmake --build build" \
+  "cmake --build build" \
+  "rtk cmake --build build"
+
+test_rewrite "./gradlew test" \
+  "./gradlew test" \
+  "rtk gradle test"
+
+test_rewrite "mvn -q verify" \
+  "mvn -q verify" \
+  "rtk mvn -q verify"
+
+test_rewrite "dotnet test --no-build" \
+  "dotnet test --no-build" \
+  "rtk dotnet test --no-build"
+
+test_rewrite "bundle exec rspec spec/models" \
+  "bundle exec rspec spec/models" \
+  "rtk rspec spec/models"
+
+test_rewrite "bundle exec rake test" \
+  "bundle exec rake test" \
+  "rtk rake test"
+
+test_rewrite "rubocop -a" \
+  "rubocop -a" \
+  "rtk rubocop -a"
+
+test_rewrite "bundle outdated" \
+  "bundle outdated" \
+  "rtk bundle outdated"
+
+test_rewrite "vendor/bin/phpunit --filter UserTest" \
+  "vendor/bin/phpunit --filter UserTest" \
+  "rtk phpunit --filter UserTest"
+
+test_rewrite "vendor/bin/phpstan analyse src" \
+  "vendor/bin/phpstan analyse src" \
+  "rtk phpstan analyse src"
+
+test_rewrite "composer install" \
+  "composer install" \
+  "rtk composer install"
+
+test_rewrite "terraform fmt (not rewritten)" \
+  "terraform fmt" \
+  ""
+
 echo ""
 
 # ---- SECTION 4: Vitest edge case (fixed double "run" bug) ----
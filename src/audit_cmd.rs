@@ -0,0 +1,1026 @@
+//! Vulnerability audit summaries: runs npm/pnpm audit, cargo audit or
+//! pip-audit with JSON output and normalizes the reports into severity
+//! counts plus one line per advisory.
+
+use crate::tracking;
+use crate::utils::detect_package_manager;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Advisory lines shown before collapsing.
+const MAX_ADVISORIES_SHOWN: usize = 30;
+/// Virtual node the project's direct dependencies hang off.
+const ROOT: &str = "";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Severity {
+    Critical,
+    High,
+    Moderate,
+    Low,
+    Unknown,
+}
+
+impl Severity {
+    fn parse(s: &str) -> Self {
+        match s.to_ascii_lowercase().as_str() {
+            "critical" => Severity::Critical,
+            "high" => Severity::High,
+            "moderate" | "medium" => Severity::Moderate,
+            "low" | "info" => Severity::Low,
+            _ => Severity::Unknown,
+        }
+    }
+
+    fn from_score(score: f64) -> Self {
+        match score {
+            s if s >= 9.0 => Severity::Critical,
+            s if s >= 7.0 => Severity::High,
+            s if s >= 4.0 => Severity::Moderate,
+            _ => Severity::Low,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Severity::Critical => "critical",
+            Severity::High => "high",
+            Severity::Moderate => "moderate",
+            Severity::Low => "low",
+            Severity::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Advisory {
+    package: String,
+    installed: Option<String>,
+    fixed: Option<String>,
+    id: String,
+    title: String,
+    severity: Severity,
+    /// Shortest dependency path (1 = direct dependency)
+    depth: Option<usize>,
+    /// Distinct dependency paths leading to the package
+    paths: usize,
+}
+
+/// Normalized result of one audit tool run.
+#[derive(Debug, Default)]
+struct AuditReport {
+    advisories: Vec<Advisory>,
+    dependencies: Option<usize>,
+    notes: Vec<String>,
+}
+
+impl AuditReport {
+    /// Merge advisories reported more than once (same package, version and
+    /// ID reached through different paths), keeping the shortest path.
+    fn dedup(&mut self) {
+        let mut merged: Vec<Advisory> = Vec::new();
+        for adv in self.advisories.drain(..) {
+            match merged.iter_mut().find(|m| {
+                m.package == adv.package && m.installed == adv.installed && m.id == adv.id
+            }) {
+                Some(existing) => {
+                    existing.paths += adv.paths;
+                    existing.depth = match (existing.depth, adv.depth) {
+                        (Some(a), Some(b)) => Some(a.min(b)),
+                        (a, b) => a.or(b),
+                    };
+                    existing.severity = existing.severity.min(adv.severity);
+                    if existing.fixed.is_none() {
+                        existing.fixed = adv.fixed;
+                    }
+                }
+                None => merged.push(adv),
+            }
+        }
+        merged.sort_by(|a, b| {
+            a.severity
+                .cmp(&b.severity)
+                .then_with(|| a.package.cmp(&b.package))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        self.advisories = merged;
+    }
+}
+
+/// An audit tool invocation for one detected ecosystem.
+struct AuditTool {
+    /// Ecosystem name accepted by `--only` ("cargo", "npm", "pip")
+    ecosystem: &'static str,
+    label: &'static str,
+    program: &'static str,
+    args: Vec<&'static str>,
+    parse: fn(&str, &Path) -> Result<AuditReport>,
+}
+
+fn detect_tools(dir: &Path) -> Vec<AuditTool> {
+    let mut tools = Vec::new();
+
+    if dir.join("Cargo.toml").exists() || dir.join("Cargo.lock").exists() {
+        tools.push(AuditTool {
+            ecosystem: "cargo",
+            label: "cargo audit",
+            program: "cargo",
+            args: vec!["audit", "--json"],
+            parse: parse_cargo_audit,
+        });
+    }
+
+    if dir.join("package.json").exists() {
+        let pnpm = dir.join("pnpm-lock.yaml").exists()
+            || (dir == Path::new(".") && detect_package_manager() == "pnpm");
+        tools.push(if pnpm {
+            AuditTool {
+                ecosystem: "npm",
+                label: "pnpm audit",
+                program: "pnpm",
+                args: vec!["audit", "--json"],
+                parse: parse_npm_audit,
+            }
+        } else {
+            AuditTool {
+                ecosystem: "npm",
+                label: "npm audit",
+                program: "npm",
+                args: vec!["audit", "--json"],
+                parse: parse_npm_audit,
+            }
+        });
+    }
+
+    if dir.join("requirements.txt").exists() {
+        tools.push(AuditTool {
+            ecosystem: "pip",
+            label: "pip-audit",
+            program: "pip-audit",
+            args: vec!["-f", "json", "-r", "requirements.txt"],
+            parse: parse_pip_audit,
+        });
+    } else if dir.join("pyproject.toml").exists() {
+        // Audit the project itself, not the environment pip-audit runs in
+        tools.push(AuditTool {
+            ecosystem: "pip",
+            label: "pip-audit",
+            program: "pip-audit",
+            args: vec!["-f", "json", "."],
+            parse: parse_pip_audit,
+        });
+    }
+
+    tools
+}
+
+/// Audit dependencies of the project at `path` for known vulnerabilities.
+///
+/// `only` restricts the run to one ecosystem; extra args are tool-specific,
+/// so they need a single ecosystem to go to.
+pub fn run(path: &Path, only: Option<&str>, extra_args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let dir = if path.is_file() {
+        path.parent().unwrap_or(Path::new("."))
+    } else {
+        path
+    };
+
+    let mut tools = detect_tools(dir);
+    if let Some(only) = only {
+        if !["cargo", "npm", "pip"].contains(&only) {
+            anyhow::bail!("Unknown ecosystem '{}' (expected cargo, npm or pip)", only);
+        }
+        tools.retain(|t| t.ecosystem == only);
+    }
+    if tools.is_empty() {
+        println!(
+            "No supported manifest found in {} (Cargo.toml, package.json, requirements.txt, pyproject.toml)",
+            dir.display()
+        );
+        return Ok(());
+    }
+    if !extra_args.is_empty() && tools.len() > 1 {
+        let ecosystems: Vec<&str> = tools.iter().map(|t| t.ecosystem).collect();
+        anyhow::bail!(
+            "Extra arguments are tool-specific but {} audits were detected; pick one with --only {}",
+            tools.len(),
+            ecosystems.join("|")
+        );
+    }
+
+    let mut raw = String::new();
+    let mut rtk = Vec::new();
+    let mut exit_code = 0;
+
+    for tool in &tools {
+        if verbose > 0 {
+            eprintln!(
+                "Running: {} {} {}",
+                tool.program,
+                tool.args.join(" "),
+                extra_args.join(" ")
+            );
+        }
+
+        // A missing tool shouldn't discard the reports of the others
+        let output = match Command::new(tool.program)
+            .args(&tool.args)
+            .args(extra_args)
+            .current_dir(dir)
+            .output()
+        {
+            Ok(output) => output,
+            Err(e) => {
+                rtk.push(format!(
+                    "❌ {}: failed to run {} ({}). Is it installed?",
+                    tool.label, tool.program, e
+                ));
+                if exit_code == 0 {
+                    exit_code = 1;
+                }
+                continue;
+            }
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        raw.push_str(&stdout);
+        raw.push_str(&stderr);
+
+        match (tool.parse)(&stdout, dir) {
+            Ok(mut report) => {
+                report.dedup();
+                rtk.push(format_report(tool.label, &report, verbose));
+            }
+            Err(e) => {
+                // No JSON at all usually means the tool itself is missing or broke
+                let mut msg = format!("❌ {}: {}", tool.label, e);
+                for line in stderr.lines().filter(|l| !l.trim().is_empty()).take(10) {
+                    msg.push_str(&format!("\n  {}", line.trim()));
+                }
+                rtk.push(msg);
+            }
+        }
+
+        if !output.status.success() && exit_code == 0 {
+            exit_code = output.status.code().unwrap_or(1);
+        }
+    }
+
+    let rtk = rtk.join("\n");
+    println!("{}", rtk);
+
+    let labels: Vec<&str> = tools.iter().map(|t| t.label).collect();
+    timer.track(&labels.join(" && "), "rtk audit", &raw, &rtk);
+
+    // Audit tools exit non-zero when vulnerabilities are found; keep that
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+    Ok(())
+}
+
+fn format_report(label: &str, report: &AuditReport, verbose: u8) -> String {
+    let scanned = report
+        .dependencies
+        .map(|n| format!(" in {} dependencies", n))
+        .unwrap_or_default();
+
+    if report.advisories.is_empty() {
+        let mut lines = vec![format!("✓ {}: no known vulnerabilities{}", label, scanned)];
+        lines.extend(report.notes.iter().map(|n| format!("  {}", n)));
+        return lines.join("\n");
+    }
+
+    let mut counts: Vec<(Severity, usize)> = Vec::new();
+    for adv in &report.advisories {
+        match counts.iter_mut().find(|(s, _)| *s == adv.severity) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((adv.severity, 1)),
+        }
+    }
+    let breakdown: Vec<String> = counts
+        .iter()
+        .map(|(s, n)| format!("{} {}", n, s.label()))
+        .collect();
+
+    let mut lines = vec![format!(
+        "🔒 {}: {} advisories ({}){}",
+        label,
+        report.advisories.len(),
+        breakdown.join(", "),
+        scanned
+    )];
+
+    for adv in report.advisories.iter().take(MAX_ADVISORIES_SHOWN) {
+        let versions = match (&adv.installed, &adv.fixed) {
+            (Some(i), Some(f)) => format!(" {} → {}", i, f),
+            (Some(i), None) => format!(" {} (no fix)", i),
+            (None, Some(f)) => format!(" → {}", f),
+            (None, None) => String::new(),
+        };
+        let mut line = format!(
+            "  {:<8} {}{}  {}",
+            adv.severity.label(),
+            adv.package,
+            versions,
+            adv.id
+        );
+        if let Some(depth) = adv.depth {
+            if adv.paths > 1 {
+                line.push_str(&format!("  (depth {}, {} paths)", depth, adv.paths));
+            } else {
+                line.push_str(&format!("  (depth {})", depth));
+            }
+        }
+        if verbose > 0 && !adv.title.is_empty() {
+            line.push_str(&format!(" — {}", adv.title));
+        }
+        lines.push(line);
+    }
+    if report.advisories.len() > MAX_ADVISORIES_SHOWN {
+        lines.push(format!(
+            "  ... +{} more",
+            report.advisories.len() - MAX_ADVISORIES_SHOWN
+        ));
+    }
+    lines.extend(report.notes.iter().map(|n| format!("  {}", n)));
+
+    lines.join("\n")
+}
+
+/// Shortest depth and number of distinct paths from the project to `node`,
+/// given each node's dependents.
+fn path_stats(
+    node: &str,
+    dependents: &HashMap<String, Vec<String>>,
+    memo: &mut HashMap<String, Option<(usize, usize)>>,
+    visiting: &mut HashSet<String>,
+) -> Option<(usize, usize)> {
+    if node == ROOT {
+        return Some((0, 1));
+    }
+    if let Some(cached) = memo.get(node) {
+        return *cached;
+    }
+    if !visiting.insert(node.to_string()) {
+        return None;
+    }
+
+    let mut best: Option<(usize, usize)> = None;
+    for parent in dependents.get(node).into_iter().flatten() {
+        if let Some((depth, paths)) = path_stats(parent, dependents, memo, visiting) {
+            best = Some(match best {
+                Some((d, p)) => (d.min(depth + 1), p.saturating_add(paths)),
+                None => (depth + 1, paths),
+            });
+        }
+    }
+
+    visiting.remove(node);
+    memo.insert(node.to_string(), best);
+    best
+}
+
+/// First version outside a vulnerable range: "<1.2.6" or ">=0.7.0 <0.7.2"
+fn fixed_from_vulnerable_range(range: &str) -> Option<String> {
+    let last = range.split_whitespace().last()?;
+    if last.starts_with("<=") {
+        return None;
+    }
+    last.strip_prefix('<').map(String::from)
+}
+
+/// Lowest patched version: ">=1.2.6", "^2.0.1 || >=3.1.0"
+fn fixed_from_patched_range(range: &str) -> Option<String> {
+    if range.trim() == "<0.0.0" {
+        return None;
+    }
+    let first = range.split("||").next()?.split_whitespace().next()?;
+    let version = first.trim_start_matches(['>', '=', '^', '~']);
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// Advisory ID from a GitHub/npm advisory URL, falling back to the numeric source.
+fn advisory_id(url: Option<&str>, source: Option<&Value>) -> String {
+    url.and_then(|u| u.rsplit('/').next())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .or_else(|| source.map(|s| s.to_string()))
+        .unwrap_or_else(|| "?".to_string())
+}
+
+/// npm 7+ (`auditReportVersion: 2`) and the legacy npm 6 / pnpm format
+/// with an `advisories` map.
+fn parse_npm_audit(json: &str, dir: &Path) -> Result<AuditReport> {
+    let value: Value = serde_json::from_str(json.trim()).context("no JSON audit report")?;
+    if let Some(msg) = value.pointer("/error/summary").and_then(Value::as_str) {
+        anyhow::bail!("{}", msg);
+    }
+    if value.get("advisories").is_some() {
+        parse_legacy_npm_audit(&value)
+    } else {
+        let lock = fs::read_to_string(dir.join("package-lock.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str::<Value>(&s).ok());
+        Ok(parse_npm_v2_audit(&value, lock.as_ref()))
+    }
+}
+
+fn parse_npm_v2_audit(value: &Value, lock: Option<&Value>) -> AuditReport {
+    let empty = serde_json::Map::new();
+    let vulns = value
+        .get("vulnerabilities")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    // Vulnerable packages list the packages that pull them in under "effects"
+    let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+    for (name, entry) in vulns {
+        if entry.get("isDirect").and_then(Value::as_bool) == Some(true) {
+            dependents
+                .entry(name.clone())
+                .or_default()
+                .push(ROOT.to_string());
+        }
+        for effect in entry
+            .get("effects")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            dependents
+                .entry(name.clone())
+                .or_default()
+                .push(effect.to_string());
+        }
+    }
+
+    let mut memo = HashMap::new();
+    let mut advisories = Vec::new();
+    for (name, entry) in vulns {
+        let stats = path_stats(name, &dependents, &mut memo, &mut HashSet::new());
+        let nodes: Vec<&str> = entry
+            .get("nodes")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let mut versions: Vec<Option<String>> = nodes
+            .iter()
+            .map(|node| {
+                lock.and_then(|l| l.get("packages"))
+                    .and_then(|p| p.get(*node))
+                    .and_then(|p| p.get("version"))
+                    .and_then(Value::as_str)
+                    .map(String::from)
+            })
+            .collect();
+        versions.dedup();
+        if versions.is_empty() {
+            versions.push(None);
+        }
+        let fix_version = entry
+            .get("fixAvailable")
+            .filter(|f| f.get("name").and_then(Value::as_str) == Some(name.as_str()))
+            .and_then(|f| f.get("version"))
+            .and_then(Value::as_str);
+
+        // String "via" entries only point at another vulnerable package
+        for via in entry
+            .get("via")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|v| v.is_object())
+        {
+            let range = via.get("range").and_then(Value::as_str).unwrap_or("");
+            for installed in &versions {
+                advisories.push(Advisory {
+                    package: name.clone(),
+                    installed: installed.clone(),
+                    fixed: fix_version
+                        .map(String::from)
+                        .or_else(|| fixed_from_vulnerable_range(range)),
+                    id: advisory_id(via.get("url").and_then(Value::as_str), via.get("source")),
+                    title: via
+                        .get("title")
+                        .and_then(Value::as_str)
+                        .unwrap_or("")
+                        .to_string(),
+                    severity: Severity::parse(
+                        via.get("severity").and_then(Value::as_str).unwrap_or(""),
+                    ),
+                    depth: stats.map(|(d, _)| d),
+                    paths: stats.map(|(_, p)| p).unwrap_or(1),
+                });
+            }
+        }
+    }
+
+    AuditReport {
+        advisories,
+        dependencies: value
+            .pointer("/metadata/dependencies/total")
+            .and_then(Value::as_u64)
+            .map(|n| n as usize),
+        notes: Vec::new(),
+    }
+}
+
+#[derive(Deserialize)]
+struct LegacyAdvisory {
+    #[serde(default)]
+    id: Option<Value>,
+    module_name: String,
+    #[serde(default)]
+    severity: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    github_advisory_id: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    patched_versions: String,
+    #[serde(default)]
+    findings: Vec<LegacyFinding>,
+}
+
+#[derive(Deserialize)]
+struct LegacyFinding {
+    version: String,
+    #[serde(default)]
+    paths: Vec<String>,
+}
+
+fn parse_legacy_npm_audit(value: &Value) -> Result<AuditReport> {
+    let advisories_map: HashMap<String, LegacyAdvisory> =
+        serde_json::from_value(value.get("advisories").cloned().unwrap_or(Value::Null))
+            .context("unexpected advisories format")?;
+
+    let mut advisories = Vec::new();
+    for adv in advisories_map.values() {
+        let id = adv
+            .github_advisory_id
+            .clone()
+            .unwrap_or_else(|| advisory_id(adv.url.as_deref(), adv.id.as_ref()));
+        for finding in &adv.findings {
+            // ".>mkdirp>minimist": the package sits two levels below the project
+            let depth = finding
+                .paths
+                .iter()
+                .map(|p| p.split('>').count().saturating_sub(1))
+                .min();
+            advisories.push(Advisory {
+                package: adv.module_name.clone(),
+                installed: Some(finding.version.clone()),
+                fixed: fixed_from_patched_range(&adv.patched_versions),
+                id: id.clone(),
+                title: adv.title.clone(),
+                severity: Severity::parse(&adv.severity),
+                depth,
+                paths: finding.paths.len().max(1),
+            });
+        }
+    }
+
+    let dependencies = value.pointer("/metadata/dependencies").and_then(|d| {
+        d.as_u64()
+            .or_else(|| d.get("total").and_then(Value::as_u64))
+    });
+
+    Ok(AuditReport {
+        advisories,
+        dependencies: dependencies.map(|n| n as usize),
+        notes: Vec::new(),
+    })
+}
+
+#[derive(Deserialize)]
+struct CargoAudit {
+    #[serde(default)]
+    lockfile: Option<CargoAuditLockfile>,
+    vulnerabilities: CargoAuditVulnerabilities,
+    #[serde(default)]
+    warnings: HashMap<String, Vec<CargoAuditEntry>>,
+}
+
+#[derive(Deserialize)]
+struct CargoAuditLockfile {
+    #[serde(rename = "dependency-count")]
+    dependency_count: usize,
+}
+
+#[derive(Deserialize)]
+struct CargoAuditVulnerabilities {
+    #[serde(default)]
+    list: Vec<CargoAuditEntry>,
+}
+
+#[derive(Deserialize)]
+struct CargoAuditEntry {
+    #[serde(default)]
+    advisory: Option<CargoAdvisory>,
+    #[serde(default)]
+    versions: Option<CargoAdvisoryVersions>,
+    package: CargoPackage,
+}
+
+#[derive(Deserialize)]
+struct CargoAdvisory {
+    id: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    cvss: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CargoAdvisoryVersions {
+    #[serde(default)]
+    patched: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CargoPackage {
+    name: String,
+    version: String,
+}
+
+fn parse_cargo_audit(json: &str, dir: &Path) -> Result<AuditReport> {
+    let report: CargoAudit = serde_json::from_str(json.trim()).context("no JSON audit report")?;
+    let dependents = fs::read_to_string(dir.join("Cargo.lock"))
+        .map(|lock| cargo_lock_dependents(&lock))
+        .unwrap_or_default();
+    let mut memo = HashMap::new();
+
+    let advisories = report
+        .vulnerabilities
+        .list
+        .iter()
+        .filter_map(|entry| {
+            let advisory = entry.advisory.as_ref()?;
+            let key = format!("{} {}", entry.package.name, entry.package.version);
+            let stats = path_stats(&key, &dependents, &mut memo, &mut HashSet::new());
+            Some(Advisory {
+                package: entry.package.name.clone(),
+                installed: Some(entry.package.version.clone()),
+                fixed: entry
+                    .versions
+                    .as_ref()
+                    .and_then(|v| v.patched.first())
+                    .and_then(|r| fixed_from_patched_range(r)),
+                id: advisory.id.clone(),
+                title: advisory.title.clone(),
+                severity: advisory
+                    .cvss
+                    .as_deref()
+                    .and_then(cvss3_base_score)
+                    .map(Severity::from_score)
+                    .unwrap_or(Severity::Unknown),
+                depth: stats.map(|(d, _)| d),
+                paths: stats.map(|(_, p)| p).unwrap_or(1),
+            })
+        })
+        .collect();
+
+    // Unmaintained/yanked crates are warnings, not vulnerabilities
+    let mut kinds: Vec<&String> = report.warnings.keys().collect();
+    kinds.sort();
+    let notes = kinds
+        .into_iter()
+        .filter(|k| !report.warnings[*k].is_empty())
+        .map(|kind| {
+            let names: Vec<&str> = report.warnings[kind]
+                .iter()
+                .map(|w| w.package.name.as_str())
+                .collect();
+            format!("⚠️  {}: {}", kind, names.join(", "))
+        })
+        .collect();
+
+    Ok(AuditReport {
+        advisories,
+        dependencies: report.lockfile.map(|l| l.dependency_count),
+        notes,
+    })
+}
+
+/// Maps "name version" of every Cargo.lock package to the packages depending
+/// on it; workspace members (no `source`) collapse into the project root.
+fn cargo_lock_dependents(lock: &str) -> HashMap<String, Vec<String>> {
+    struct Package {
+        name: String,
+        version: String,
+        local: bool,
+        deps: Vec<String>,
+    }
+
+    let mut packages: Vec<Package> = Vec::new();
+    let mut in_deps = false;
+    for line in lock.lines() {
+        let line = line.trim();
+        if line == "[[package]]" {
+            packages.push(Package {
+                name: String::new(),
+                version: String::new(),
+                local: true,
+                deps: Vec::new(),
+            });
+            in_deps = false;
+            continue;
+        }
+        let Some(pkg) = packages.last_mut() else {
+            continue;
+        };
+        if in_deps {
+            if line.starts_with(']') {
+                in_deps = false;
+            } else {
+                pkg.deps
+                    .push(line.trim_matches([' ', '"', ',']).to_string());
+            }
+        } else if let Some(v) = line.strip_prefix("name = ") {
+            pkg.name = v.trim_matches('"').to_string();
+        } else if let Some(v) = line.strip_prefix("version = ") {
+            pkg.version = v.trim_matches('"').to_string();
+        } else if line.starts_with("source = ") {
+            pkg.local = false;
+        } else if line.starts_with("dependencies = [") {
+            in_deps = !line.ends_with(']');
+        }
+    }
+
+    // Dependency entries are "name" when unambiguous, else "name version (source)"
+    let key = |dep: &str| -> Option<String> {
+        let mut parts = dep.split_whitespace();
+        let name = parts.next()?;
+        match parts.next() {
+            Some(version) => Some(format!("{} {}", name, version)),
+            None => packages
+                .iter()
+                .find(|p| p.name == name)
+                .map(|p| format!("{} {}", p.name, p.version)),
+        }
+    };
+
+    let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+    for pkg in &packages {
+        let parent = if pkg.local {
+            ROOT.to_string()
+        } else {
+            format!("{} {}", pkg.name, pkg.version)
+        };
+        for dep in pkg.deps.iter().filter_map(|d| key(d)) {
+            dependents.entry(dep).or_default().push(parent.clone());
+        }
+    }
+    dependents
+}
+
+/// CVSS v3 base score from a vector like "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+fn cvss3_base_score(vector: &str) -> Option<f64> {
+    let metrics: HashMap<&str, &str> = vector
+        .split('/')
+        .skip(1)
+        .filter_map(|m| m.split_once(':'))
+        .collect();
+    let changed = *metrics.get("S")? == "C";
+
+    let av = match *metrics.get("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        _ => 0.2,
+    };
+    let ac = if *metrics.get("AC")? == "L" {
+        0.77
+    } else {
+        0.44
+    };
+    let pr = match (*metrics.get("PR")?, changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        (_, false) => 0.27,
+        (_, true) => 0.5,
+    };
+    let ui = if *metrics.get("UI")? == "N" {
+        0.85
+    } else {
+        0.62
+    };
+    let cia = |key: &str| -> Option<f64> {
+        Some(match *metrics.get(key)? {
+            "H" => 0.56,
+            "L" => 0.22,
+            _ => 0.0,
+        })
+    };
+
+    let iss = 1.0 - (1.0 - cia("C")?) * (1.0 - cia("I")?) * (1.0 - cia("A")?);
+    let impact = if changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+    } else {
+        6.42 * iss
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let exploitability = 8.22 * av * ac * pr * ui;
+    let score = if changed {
+        1.08 * (impact + exploitability)
+    } else {
+        impact + exploitability
+    };
+    // Round up to one decimal, as the spec does
+    Some(((score.min(10.0) * 10.0) - 1e-9).ceil() / 10.0)
+}
+
+#[derive(Deserialize)]
+struct PipAuditDependency {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    vulns: Vec<PipAuditVuln>,
+}
+
+#[derive(Deserialize)]
+struct PipAuditVuln {
+    id: String,
+    #[serde(default)]
+    fix_versions: Vec<String>,
+    #[serde(default)]
+    description: String,
+}
+
+/// pip-audit JSON: `{"dependencies": [...]}`, or a bare array from older releases
+fn parse_pip_audit(json: &str, _dir: &Path) -> Result<AuditReport> {
+    let value: Value = serde_json::from_str(json.trim()).context("no JSON audit report")?;
+    let deps = value.get("dependencies").cloned().unwrap_or(value);
+    let deps: Vec<PipAuditDependency> =
+        serde_json::from_value(deps).context("unexpected pip-audit format")?;
+
+    let advisories = deps
+        .iter()
+        .flat_map(|dep| {
+            dep.vulns.iter().map(move |vuln| Advisory {
+                package: dep.name.clone(),
+                installed: dep.version.clone(),
+                fixed: vuln.fix_versions.first().cloned(),
+                id: vuln.id.clone(),
+                title: vuln.description.lines().next().unwrap_or("").to_string(),
+                // pip-audit reports no severity or dependency paths
+                severity: Severity::Unknown,
+                depth: None,
+                paths: 1,
+            })
+        })
+        .collect();
+
+    Ok(AuditReport {
+        advisories,
+        dependencies: Some(deps.len()),
+        notes: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(mut report: AuditReport) -> String {
+        report.dedup();
+        format_report("audit", &report, 0)
+    }
+
+    #[test]
+    fn test_npm_v2_audit() {
+        let input = include_str!("../tests/fixtures/npm_audit.json");
+        let lock = serde_json::json!({"packages": {
+            "node_modules/minimist": {"version": "1.2.0"},
+            "node_modules/lodash": {"version": "4.17.15"}
+        }});
+        let value: Value = serde_json::from_str(input).unwrap();
+        assert_eq!(
+            report(parse_npm_v2_audit(&value, Some(&lock))),
+            "\
+🔒 audit: 3 advisories (1 critical, 1 high, 1 moderate) in 214 dependencies
+  critical minimist 1.2.0 → 1.2.6  GHSA-xvch-5gv4-984h  (depth 2, 2 paths)
+  high     lodash 4.17.15 → 4.17.21  GHSA-35jh-r3h4-6jhm  (depth 1)
+  moderate lodash 4.17.15 → 4.17.19  GHSA-p6mc-m468-83gw  (depth 1)"
+        );
+    }
+
+    #[test]
+    fn test_legacy_audit_merges_paths() {
+        let input = r#"{"advisories": {
+            "1179": {"id": 1179, "module_name": "minimist", "severity": "critical",
+              "github_advisory_id": "GHSA-xvch-5gv4-984h", "patched_versions": ">=1.2.6",
+              "findings": [{"version": "1.2.0", "paths": [".>mkdirp>minimist", "packages__web>minimist"]}]},
+            "1180": {"id": 1180, "module_name": "minimist", "severity": "critical",
+              "github_advisory_id": "GHSA-xvch-5gv4-984h", "patched_versions": ">=1.2.6",
+              "findings": [{"version": "1.2.0", "paths": [".>optimist>minimist"]}]},
+            "1500": {"id": 1500, "module_name": "yargs-parser", "severity": "low",
+              "patched_versions": "<0.0.0",
+              "findings": [{"version": "5.0.0", "paths": [".>yargs>yargs-parser"]}]}
+          }, "metadata": {"dependencies": 350}}"#;
+        let value: Value = serde_json::from_str(input).unwrap();
+        assert_eq!(
+            report(parse_legacy_npm_audit(&value).unwrap()),
+            "\
+🔒 audit: 2 advisories (1 critical, 1 low) in 350 dependencies
+  critical minimist 1.2.0 → 1.2.6  GHSA-xvch-5gv4-984h  (depth 1, 3 paths)
+  low      yargs-parser 5.0.0 (no fix)  1500  (depth 2)"
+        );
+    }
+
+    #[test]
+    fn test_cargo_audit_with_lock_depth() {
+        let input = include_str!("../tests/fixtures/cargo_audit.json");
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("Cargo.lock"),
+            r#"
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "chrono",
+]
+
+[[package]]
+name = "chrono"
+version = "0.4.19"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "time 0.1.45",
+]
+
+[[package]]
+name = "time"
+version = "0.1.45"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            report(parse_cargo_audit(input, dir.path()).unwrap()),
+            "\
+🔒 audit: 1 advisories (1 moderate) in 120 dependencies
+  moderate time 0.1.45 → 0.2.23  RUSTSEC-2020-0071  (depth 2)
+  ⚠️  unmaintained: ansi_term"
+        );
+    }
+
+    #[test]
+    fn test_pip_audit_and_clean_report() {
+        let input = r#"{"dependencies": [
+            {"name": "requests", "version": "2.25.0", "vulns": [
+              {"id": "PYSEC-2023-74", "fix_versions": ["2.31.0"], "aliases": ["CVE-2023-32681"]}]},
+            {"name": "idna", "version": "3.6", "vulns": []}
+          ], "fixes": []}"#;
+        assert_eq!(
+            report(parse_pip_audit(input, Path::new(".")).unwrap()),
+            "\
+🔒 audit: 1 advisories (1 unknown) in 2 dependencies
+  unknown  requests 2.25.0 → 2.31.0  PYSEC-2023-74"
+        );
+        assert_eq!(
+            report(parse_pip_audit("[]", Path::new(".")).unwrap()),
+            "✓ audit: no known vulnerabilities in 0 dependencies"
+        );
+    }
+
+    #[test]
+    fn test_cvss3_base_score() {
+        let score = |v| cvss3_base_score(v).unwrap();
+        assert_eq!(score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"), 9.8);
+        assert_eq!(score("CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:N/I:N/A:H"), 5.9);
+        assert_eq!(score("CVSS:3.1/AV:N/AC:L/PR:L/UI:R/S:C/C:L/I:L/A:N"), 5.4);
+        assert_eq!(score("CVSS:3.0/AV:L/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N"), 0.0);
+    }
+
+    #[test]
+    fn test_detect_tools_per_ecosystem() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+        fs::write(dir.path().join("pyproject.toml"), "").unwrap();
+
+        let tools = detect_tools(dir.path());
+        let ecosystems: Vec<&str> = tools.iter().map(|t| t.ecosystem).collect();
+        assert_eq!(ecosystems, vec!["cargo", "pip"]);
+        // pip-audit must target the project, not its own environment
+        assert_eq!(tools[1].args, vec!["-f", "json", "."]);
+    }
+}
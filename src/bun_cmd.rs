@@ -0,0 +1,712 @@
+use crate::npm_cmd::filter_npm_output;
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, Dependency,
+    DependencyState, FormatMode, OutputParser, ParseResult, TestFailure, TestResult,
+    TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::{relative_path, truncate};
+use crate::yarn_cmd::{format_dependency_list, split_package_spec};
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::ffi::OsString;
+use std::process::Command;
+
+/// Packages listed by name after an install/add.
+const MAX_ADDED_SHOWN: usize = 10;
+/// Warnings kept from a successful install.
+const MAX_WARNINGS: usize = 5;
+/// Lines kept from errors and unrecognized output.
+const MAX_OTHER_LINES: usize = 30;
+/// Stack frames kept per test failure.
+const MAX_FRAMES: usize = 3;
+
+lazy_static::lazy_static! {
+    // "142 packages installed [2.41s]", "Checked 142 installs across 160 packages (no changes) [18.00ms]"
+    static ref INSTALL_SUMMARY: Regex = Regex::new(
+        r"^(\d+ packages? (?:installed|removed)|Checked \d+ installs? across \d+ packages?(?: \(no changes\))?) \[([^\]]+)\]$"
+    ).unwrap();
+    // "src/math.test.ts:"
+    static ref TEST_FILE: Regex = Regex::new(r"^(\S+\.[cm]?[jt]sx?):$").unwrap();
+    // "(fail) add > handles negatives [0.45ms]" or "✗ add > handles negatives [0.45ms]"
+    static ref TEST_LINE: Regex =
+        Regex::new(r"^(\(pass\)|\(fail\)|\(skip\)|\(todo\)|✓|✗|»|✎) (.+?)(?: \[[\d.]+m?s\])?$").unwrap();
+    // " 2 pass", " 1 fail"
+    static ref COUNT_LINE: Regex = Regex::new(r"^(\d+) (pass|fail|skip|todo)$").unwrap();
+    // "Ran 5 tests across 2 files. [32.00ms]"
+    static ref RAN_LINE: Regex =
+        Regex::new(r"^Ran (\d+) tests? across \d+ files?\. \[([\d.]+)(ms|s)\]").unwrap();
+    // "at <anonymous> (/app/src/math.test.ts:11:22)" or "at /app/src/math.test.ts:11:22"
+    static ref FRAME: Regex = Regex::new(r"^at (?:.*\()?(\S+?:\d+):\d+\)?$").unwrap();
+    // Source excerpt printed above an assertion error: "11 |   expect(...)"
+    static ref EXCERPT: Regex = Regex::new(r"^\d+ \|").unwrap();
+}
+
+#[derive(Debug, Clone)]
+pub enum BunCommand {
+    List { depth: usize },
+    Outdated,
+    Install,
+    Add,
+    Why,
+    Run,
+    Test,
+}
+
+pub fn run(cmd: BunCommand, args: &[String], verbose: u8) -> Result<()> {
+    match cmd {
+        BunCommand::List { depth } => run_list(depth, args, verbose),
+        BunCommand::Outdated => run_outdated(args, verbose),
+        BunCommand::Install => run_install("install", args, verbose),
+        BunCommand::Add => run_install("add", args, verbose),
+        BunCommand::Why => run_why(args, verbose),
+        BunCommand::Run => run_script(args, verbose),
+        BunCommand::Test => run_test(args, verbose),
+    }
+}
+
+fn run_install(subcommand: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: bun {} {}", subcommand, args.join(" "));
+    }
+
+    let output = Command::new("bun")
+        .arg(subcommand)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run bun {}. Is bun installed?", subcommand))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter_bun_install(subcommand, &raw, output.status.success());
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bun {} {}", subcommand, args.join(" ")),
+        &format!("rtk bun {} {}", subcommand, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Filter bun install/add output - drop resolve/download progress, keep
+/// added packages, the summary line, warnings and errors.
+fn filter_bun_install(subcommand: &str, output: &str, success: bool) -> String {
+    let mut summary: Option<(String, String)> = None;
+    let mut added: Vec<String> = Vec::new();
+    let mut warnings: Vec<&str> = Vec::new();
+    let mut other: Vec<&str> = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(caps) = INSTALL_SUMMARY.captures(trimmed) {
+            summary = Some((caps[1].to_string(), caps[2].to_string()));
+            continue;
+        }
+        if trimmed.starts_with("+ ") || trimmed.starts_with("- ") {
+            added.push(trimmed.to_string());
+            continue;
+        }
+        // `bun add` reports "installed lodash@4.17.21"
+        if let Some(pkg) = trimmed.strip_prefix("installed ") {
+            added.push(format!("+ {}", pkg));
+            continue;
+        }
+        if let Some(warning) = trimmed.strip_prefix("warn: ") {
+            warnings.push(warning);
+            continue;
+        }
+        if trimmed.starts_with(&format!("bun {} v", subcommand))
+            || trimmed.starts_with("Resolving dependencies")
+            || trimmed.starts_with("Resolved, downloaded and extracted")
+            || trimmed.starts_with("Saved lockfile")
+            || trimmed == "done"
+        {
+            continue;
+        }
+        other.push(trimmed);
+    }
+
+    let status = if success { "✓" } else { "❌" };
+    let mut lines = vec![match &summary {
+        Some((text, time)) => format!("{} bun {}: {} ({})", status, subcommand, text, time),
+        None if success => format!("{} bun {}", status, subcommand),
+        None => format!("{} bun {} failed", status, subcommand),
+    }];
+
+    for pkg in added.iter().take(MAX_ADDED_SHOWN) {
+        lines.push(format!("  {}", pkg));
+    }
+    if added.len() > MAX_ADDED_SHOWN {
+        lines.push(format!("  ... +{} more", added.len() - MAX_ADDED_SHOWN));
+    }
+    if !warnings.is_empty() {
+        lines.push(format!("  ⚠ {} warnings:", warnings.len()));
+        for warning in warnings.iter().take(MAX_WARNINGS) {
+            lines.push(format!("    {}", warning));
+        }
+        if warnings.len() > MAX_WARNINGS {
+            lines.push(format!("    ... +{} more", warnings.len() - MAX_WARNINGS));
+        }
+    }
+    for line in other.iter().take(MAX_OTHER_LINES) {
+        lines.push(format!("  {}", line));
+    }
+    if other.len() > MAX_OTHER_LINES {
+        lines.push(format!(
+            "  ... +{} more lines",
+            other.len() - MAX_OTHER_LINES
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // `bun pm ls` shows top-level packages; --all shows the full tree
+    let mut cmd = Command::new("bun");
+    cmd.args(["pm", "ls"]);
+    if depth > 0 {
+        cmd.arg("--all");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: bun pm ls {}", args.join(" "));
+    }
+
+    let output = cmd.output().context("Failed to run bun pm ls")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if !output.status.success() {
+        anyhow::bail!("bun pm ls failed: {}", stderr.trim());
+    }
+
+    let filtered = match BunListParser::parse(&stdout) {
+        ParseResult::Full(data) | ParseResult::Degraded(data, _) => format_dependency_list(&data),
+        ParseResult::Passthrough(raw) => {
+            emit_passthrough_warning("bun pm ls", "No package lines found");
+            raw
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bun pm ls {}", args.join(" ")),
+        &format!("rtk bun list {}", args.join(" ")),
+        &stdout,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Parser for `bun pm ls` tree output
+pub struct BunListParser;
+
+impl OutputParser for BunListParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        let dependencies: Vec<Dependency> = input
+            .lines()
+            .filter_map(|l| l.strip_prefix("├── ").or_else(|| l.strip_prefix("└── ")))
+            .filter_map(|spec| split_package_spec(spec.trim()))
+            .map(|(name, version)| Dependency {
+                name: name.to_string(),
+                current_version: version.to_string(),
+                latest_version: None,
+                wanted_version: None,
+                dev_dependency: false,
+            })
+            .collect();
+
+        if dependencies.is_empty() && !input.contains("node_modules") {
+            return ParseResult::Passthrough(truncate_output(input.trim(), 500));
+        }
+        ParseResult::Full(DependencyState {
+            total_packages: dependencies.len(),
+            outdated_count: 0,
+            dependencies,
+        })
+    }
+}
+
+fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: bun outdated {}", args.join(" "));
+    }
+
+    let output = Command::new("bun")
+        .arg("outdated")
+        .args(args)
+        .output()
+        .context("Failed to run bun outdated")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = match BunOutdatedParser::parse(&stdout) {
+        ParseResult::Full(data) => data.format(mode),
+        ParseResult::Degraded(data, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("bun outdated", &warnings.join(", "));
+            }
+            data.format(mode)
+        }
+        ParseResult::Passthrough(truncated) => {
+            emit_passthrough_warning("bun outdated", "No outdated table found");
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bun outdated {}", args.join(" ")),
+        &format!("rtk bun outdated {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Parser for the `bun outdated` box table
+pub struct BunOutdatedParser;
+
+impl OutputParser for BunOutdatedParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        let mut head: Vec<String> = Vec::new();
+        let mut dependencies = Vec::new();
+
+        for line in input.lines().filter(|l| l.starts_with('│')) {
+            let cells: Vec<&str> = line
+                .trim_matches('│')
+                .split('│')
+                .map(|c| c.trim())
+                .collect();
+            if head.is_empty() {
+                head = cells.iter().map(|c| c.to_string()).collect();
+                continue;
+            }
+            let cell = |name: &str| {
+                head.iter()
+                    .position(|h| h == name)
+                    .and_then(|i| cells.get(i))
+                    .map(|c| c.to_string())
+            };
+            let (Some(package), Some(current)) = (cell("Package"), cell("Current")) else {
+                continue;
+            };
+            let (name, dev) = match package.strip_suffix(" (dev)") {
+                Some(name) => (name.to_string(), true),
+                None => (package, false),
+            };
+            dependencies.push(Dependency {
+                name,
+                current_version: current,
+                latest_version: cell("Latest"),
+                wanted_version: cell("Update"),
+                dev_dependency: dev,
+            });
+        }
+
+        // No table at all means everything is current
+        if head.is_empty()
+            && !input
+                .lines()
+                .all(|l| l.trim().is_empty() || l.starts_with("bun outdated v"))
+        {
+            return ParseResult::Passthrough(truncate_output(input.trim(), 500));
+        }
+
+        let outdated_count = dependencies
+            .iter()
+            .filter(|d| d.latest_version.as_ref() != Some(&d.current_version))
+            .count();
+        ParseResult::Full(DependencyState {
+            total_packages: dependencies.len(),
+            outdated_count,
+            dependencies,
+        })
+    }
+}
+
+fn run_why(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: bun why {}", args.join(" "));
+    }
+
+    let output = Command::new("bun")
+        .arg("why")
+        .args(args)
+        .output()
+        .context("Failed to run bun why")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    // Only the version banner and spacing are noise; the tree is the answer
+    let filtered = raw
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with("bun why v"))
+        .map(|l| l.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bun why {}", args.join(" ")),
+        &format!("rtk bun why {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+fn run_script(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: bun run {}", args.join(" "));
+    }
+
+    let output = Command::new("bun")
+        .arg("run")
+        .args(args)
+        .output()
+        .context("Failed to run bun run")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    // bun echoes the script as "$ vite build" before running it
+    let mut echoed = false;
+    let kept: Vec<&str> = raw
+        .lines()
+        .filter(|l| {
+            if l.starts_with("$ ") && !echoed {
+                echoed = true;
+                return false;
+            }
+            true
+        })
+        .collect();
+    let filtered = filter_npm_output(&kept.join("\n"));
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bun run {}", args.join(" ")),
+        &format!("rtk bun run {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+fn run_test(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: bun test {}", args.join(" "));
+    }
+
+    let output = Command::new("bun")
+        .arg("test")
+        .args(args)
+        .output()
+        .context("Failed to run bun test")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = match BunTestParser::parse(&raw) {
+        ParseResult::Full(result) => result.format(mode),
+        ParseResult::Degraded(result, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("bun test", &warnings.join(", "));
+            }
+            result.format(mode)
+        }
+        ParseResult::Passthrough(truncated) => {
+            emit_passthrough_warning("bun test", "No test summary found");
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bun test {}", args.join(" ")),
+        &format!("rtk bun test {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Parser for `bun test` output. Error details are printed before the
+/// "(fail)" line they belong to.
+pub struct BunTestParser;
+
+impl OutputParser for BunTestParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let mut result = TestResult {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+        let mut counted = (0, 0, 0);
+        let mut found_summary = false;
+        let mut current_file = String::new();
+        let mut pending: Vec<&str> = Vec::new();
+
+        for line in input.lines() {
+            let trimmed = line.trim();
+
+            if let Some(caps) = TEST_FILE.captures(trimmed) {
+                current_file = caps[1].to_string();
+                pending.clear();
+                continue;
+            }
+            if let Some(caps) = COUNT_LINE.captures(trimmed) {
+                found_summary = true;
+                let n: usize = caps[1].parse().unwrap_or(0);
+                match &caps[2] {
+                    "pass" => result.passed = n,
+                    "fail" => result.failed = n,
+                    _ => result.skipped += n,
+                }
+                continue;
+            }
+            if let Some(caps) = RAN_LINE.captures(trimmed) {
+                found_summary = true;
+                result.total = caps[1].parse().unwrap_or(0);
+                let value: f64 = caps[2].parse().unwrap_or(0.0);
+                let ms = if &caps[3] == "s" {
+                    value * 1000.0
+                } else {
+                    value
+                };
+                result.duration_ms = Some(ms as u64);
+                continue;
+            }
+
+            let Some(caps) = TEST_LINE.captures(trimmed) else {
+                pending.push(trimmed);
+                continue;
+            };
+            match &caps[1] {
+                "(pass)" | "✓" => counted.0 += 1,
+                "(fail)" | "✗" => {
+                    counted.1 += 1;
+                    result
+                        .failures
+                        .push(test_failure(&caps[2], &current_file, &pending));
+                }
+                _ => counted.2 += 1,
+            }
+            pending.clear();
+        }
+
+        if !found_summary {
+            if result.failures.is_empty() && counted.0 == 0 {
+                return ParseResult::Passthrough(truncate_output(input.trim(), 2000));
+            }
+            // Interrupted run: count the result lines we saw
+            result.passed = counted.0;
+            result.failed = counted.1;
+            result.skipped = counted.2;
+            result.total = counted.0 + counted.1 + counted.2;
+            return ParseResult::Degraded(result, vec!["no summary line".to_string()]);
+        }
+        if result.total == 0 {
+            result.total = result.passed + result.failed + result.skipped;
+        }
+        ParseResult::Full(result)
+    }
+}
+
+/// Builds a failure from the lines printed since the previous test result:
+/// source excerpt, "error: ..." message, expected/received, then frames.
+fn test_failure(name: &str, file: &str, lines: &[&str]) -> TestFailure {
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("error"))
+        .unwrap_or(0);
+    let mut message: Vec<&str> = Vec::new();
+    let mut frames: Vec<String> = Vec::new();
+
+    for line in &lines[start..] {
+        if let Some(caps) = FRAME.captures(line) {
+            let location = &caps[1];
+            if !location.contains("node_modules") && frames.len() < MAX_FRAMES {
+                frames.push(relative_path(location));
+            }
+        } else if !line.is_empty() && !EXCERPT.is_match(line) && line.trim() != "^" {
+            message.push(line);
+        }
+    }
+
+    TestFailure {
+        test_name: name.to_string(),
+        file_path: frames.first().cloned().unwrap_or_else(|| file.to_string()),
+        error_message: truncate(&message.join(" "), 200),
+        stack_trace: if frames.is_empty() {
+            None
+        } else {
+            Some(frames.join("\n"))
+        },
+    }
+}
+
+/// Runs an unsupported bun subcommand by passing it through directly
+pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("bun passthrough: {:?}", args);
+    }
+    let status = Command::new("bun")
+        .args(args)
+        .status()
+        .context("Failed to run bun")?;
+
+    let args_str = tracking::args_display(args);
+    timer.track_passthrough(
+        &format!("bun {}", args_str),
+        &format!("rtk bun {} (passthrough)", args_str),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_bun_install() {
+        let input = include_str!("../tests/fixtures/bun_install.txt");
+        assert_eq!(
+            filter_bun_install("install", input, true),
+            "\
+✓ bun install: 142 packages installed (2.41s)
+  + @types/node@20.12.7 (v20.14.2 available)
+  + react@18.2.0
+  + typescript@5.4.5
+  ⚠ 1 warnings:
+    incorrect peer dependency \"react@17.0.2\""
+        );
+    }
+
+    #[test]
+    fn test_filter_bun_add() {
+        let input = "bun add v1.1.8 (89d25807)\n\ninstalled lodash@4.17.21\n\n1 package installed [312.00ms]\n";
+        assert_eq!(
+            filter_bun_install("add", input, true),
+            "✓ bun add: 1 package installed (312.00ms)\n  + lodash@4.17.21"
+        );
+    }
+
+    #[test]
+    fn test_bun_outdated_parser() {
+        let input = include_str!("../tests/fixtures/bun_outdated.txt");
+        let data = BunOutdatedParser::parse(input).unwrap();
+        assert_eq!(data.outdated_count, 2);
+        assert!(data.dependencies[0].dev_dependency);
+        assert_eq!(
+            data.format(FormatMode::Compact),
+            "\
+2 outdated packages (of 2)
+typescript: 5.4.5 → 5.7.2
+react: 18.2.0 → 19.0.0"
+        );
+        let current = BunOutdatedParser::parse("bun outdated v1.1.38 (bf2f153f)\n").unwrap();
+        assert_eq!(
+            current.format(FormatMode::Compact),
+            "All packages up-to-date ✓"
+        );
+    }
+
+    #[test]
+    fn test_bun_list_parser() {
+        let input = "\
+/home/user/app node_modules (142)
+├── @types/node@20.12.7
+├── react@18.2.0
+└── typescript@5.4.5
+";
+        let data = BunListParser::parse(input).unwrap();
+        assert_eq!(
+            format_dependency_list(&data),
+            "3 packages\n@types/node@20.12.7\nreact@18.2.0\ntypescript@5.4.5"
+        );
+    }
+
+    #[test]
+    fn test_bun_test_parser() {
+        let input = include_str!("../tests/fixtures/bun_test.txt");
+        let result = BunTestParser::parse(input);
+        assert_eq!(result.tier(), 1);
+        let result = result.unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (5, 2, 1, 2)
+        );
+        assert_eq!(result.duration_ms, Some(32));
+        let failure = &result.failures[0];
+        assert_eq!(failure.test_name, "add > handles negatives");
+        assert_eq!(failure.file_path, "/app/src/math.test.ts:11");
+        assert_eq!(
+            failure.error_message,
+            "error: expect(received).toBe(expected) Expected: -1 Received: 1"
+        );
+    }
+}
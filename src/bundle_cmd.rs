@@ -0,0 +1,355 @@
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, Dependency,
+    DependencyState, FormatMode, OutputParser, ParseResult, TokenFormatter,
+};
+use crate::tracking;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::process::Command;
+
+/// Lines kept from a failed install.
+const MAX_ERROR_LINES: usize = 30;
+/// Newly installed gems listed by name.
+const MAX_INSTALLED_SHOWN: usize = 10;
+
+lazy_static::lazy_static! {
+    // "Installing pg 1.5.5 with native extensions", "Installing nokogiri 1.16.2 (x86_64-linux)"
+    static ref INSTALLING: Regex = Regex::new(r"^Installing (\S+) (\S+)").unwrap();
+    // "Bundle complete! 14 Gemfile dependencies, 82 gems now installed."
+    static ref COMPLETE: Regex = Regex::new(
+        r"^Bundle (?:complete|updated)! (\d+) Gemfile dependenc(?:y|ies), (\d+) gems? now installed"
+    ).unwrap();
+    // "rails (newest 7.1.3.2, installed 7.1.3, requested ~> 7.1.0)"
+    static ref OUTDATED_LINE: Regex = Regex::new(
+        r"^\s*(?:\* )?(\S+) \(newest ([^,]+), installed ([^,)]+)(?:, requested ([^)]+))?\)"
+    ).unwrap();
+    // Table format (bundler >= 2.2 without --parseable): "rails  7.1.3  7.1.3.2  ~> 7.1.0  default"
+    static ref OUTDATED_ROW: Regex = Regex::new(
+        r"^(\S+)\s+(\d\S*)\s+(\d\S*)(?:\s+(.+?))?(?:\s{2,}\S+)?\s*$"
+    ).unwrap();
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    match args.first().map(|s| s.as_str()) {
+        Some("install") | Some("update") | None => run_install(args, verbose),
+        Some("outdated") => run_outdated(&args[1..], verbose),
+        Some(_) => run_passthrough(args, verbose),
+    }
+}
+
+fn run_install(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: bundle {}", args.join(" "));
+    }
+
+    let output = Command::new("bundle")
+        .args(args)
+        .output()
+        .context("Failed to run bundle. Is Bundler installed? Try: gem install bundler")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter_bundle_install(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bundle {}", args.join(" ")),
+        &format!("rtk bundle {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Strip fetch/resolve/"Using" noise and post-install messages; keep newly
+/// installed gems, the final count, and anything that looks like a problem.
+fn filter_bundle_install(output: &str) -> String {
+    let mut installed: Vec<String> = Vec::new();
+    let mut summary: Option<(usize, usize)> = None;
+    let mut other: Vec<&str> = Vec::new();
+    let mut post_install = 0;
+    let mut in_post_install = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+
+        if let Some(caps) = COMPLETE.captures(trimmed) {
+            summary = Some((caps[1].parse().unwrap_or(0), caps[2].parse().unwrap_or(0)));
+            continue;
+        }
+        if trimmed.starts_with("Post-install message from ") {
+            post_install += 1;
+            in_post_install = true;
+            continue;
+        }
+        // Post-install messages run to the end of the output
+        if in_post_install || trimmed.is_empty() {
+            continue;
+        }
+        if let Some(caps) = INSTALLING.captures(trimmed) {
+            installed.push(format!("{} {}", &caps[1], &caps[2]));
+            continue;
+        }
+        if trimmed.starts_with("Fetching ")
+            || trimmed.starts_with("Using ")
+            || trimmed.starts_with("Resolving dependencies")
+            || trimmed.starts_with("Use `bundle info")
+            || trimmed.starts_with("Installing ")
+            || trimmed.starts_with("Following files may not be writable")
+        {
+            continue;
+        }
+        other.push(trimmed);
+    }
+
+    let mut lines = Vec::new();
+    match summary {
+        Some((deps, gems)) => {
+            let mut header = format!("✓ bundle: {} gems ({} Gemfile dependencies)", gems, deps);
+            if !installed.is_empty() {
+                header.push_str(&format!(", {} installed", installed.len()));
+            }
+            lines.push(header);
+        }
+        None => lines.push("❌ bundle failed".to_string()),
+    }
+
+    if !installed.is_empty() {
+        let shown: Vec<&str> = installed
+            .iter()
+            .take(MAX_INSTALLED_SHOWN)
+            .map(|s| s.as_str())
+            .collect();
+        let mut list = format!("  + {}", shown.join(", "));
+        if installed.len() > MAX_INSTALLED_SHOWN {
+            list.push_str(&format!(
+                " ... +{} more",
+                installed.len() - MAX_INSTALLED_SHOWN
+            ));
+        }
+        lines.push(list);
+    }
+
+    for line in other.iter().take(MAX_ERROR_LINES) {
+        lines.push(format!("  {}", line));
+    }
+    if other.len() > MAX_ERROR_LINES {
+        lines.push(format!(
+            "  ... +{} more lines",
+            other.len() - MAX_ERROR_LINES
+        ));
+    }
+
+    if post_install > 0 {
+        lines.push(format!("  ({} post-install messages hidden)", post_install));
+    }
+
+    lines.join("\n")
+}
+
+fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("bundle");
+    cmd.arg("outdated");
+    if !args
+        .iter()
+        .any(|a| a == "--parseable" || a == "--porcelain")
+    {
+        cmd.arg("--parseable");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: bundle outdated --parseable {}", args.join(" "));
+    }
+
+    let output = cmd.output().context("Failed to run bundle outdated")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = match BundleOutdatedParser::parse(&stdout) {
+        ParseResult::Full(data) => data.format(FormatMode::from_verbosity(verbose)),
+        ParseResult::Degraded(data, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("bundle outdated", &warnings.join(", "));
+            }
+            data.format(FormatMode::from_verbosity(verbose))
+        }
+        ParseResult::Passthrough(truncated) => {
+            emit_passthrough_warning("bundle outdated", "No outdated gem lines found");
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("bundle outdated {}", args.join(" ")),
+        &format!("rtk bundle outdated {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    // Bundler exits 1 when gems are outdated; that is the answer, not a failure
+    let has_error = stderr.lines().any(|l| {
+        let l = l.trim();
+        !l.is_empty() && !l.starts_with("Fetching") && !l.starts_with("Resolving")
+    });
+    if !output.status.success() && has_error {
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Parser for `bundle outdated --parseable` (table output as fallback)
+pub struct BundleOutdatedParser;
+
+impl OutputParser for BundleOutdatedParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        let mut dependencies = Vec::new();
+
+        for line in input.lines() {
+            if let Some(caps) = OUTDATED_LINE.captures(line) {
+                dependencies.push(Dependency {
+                    name: caps[1].to_string(),
+                    current_version: caps[3].trim().to_string(),
+                    latest_version: Some(caps[2].trim().to_string()),
+                    wanted_version: caps.get(4).map(|m| m.as_str().trim().to_string()),
+                    dev_dependency: false,
+                });
+            }
+        }
+        if !dependencies.is_empty() || input.contains("Bundle up to date!") {
+            return ParseResult::Full(DependencyState {
+                total_packages: dependencies.len(),
+                outdated_count: dependencies.len(),
+                dependencies,
+            });
+        }
+
+        // Tier 2: "Gem  Current  Latest  Requested  Groups" table
+        let mut in_table = false;
+        for line in input.lines() {
+            if line.starts_with("Gem ") && line.contains("Current") {
+                in_table = true;
+                continue;
+            }
+            if !in_table {
+                continue;
+            }
+            if let Some(caps) = OUTDATED_ROW.captures(line) {
+                dependencies.push(Dependency {
+                    name: caps[1].to_string(),
+                    current_version: caps[2].to_string(),
+                    latest_version: Some(caps[3].to_string()),
+                    wanted_version: caps.get(4).map(|m| m.as_str().trim().to_string()),
+                    dev_dependency: false,
+                });
+            }
+        }
+        if dependencies.is_empty() {
+            return ParseResult::Passthrough(truncate_output(input.trim(), 500));
+        }
+        ParseResult::Degraded(
+            DependencyState {
+                total_packages: dependencies.len(),
+                outdated_count: dependencies.len(),
+                dependencies,
+            },
+            vec!["parsed table output".to_string()],
+        )
+    }
+}
+
+fn run_passthrough(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: bundle {}", args.join(" "));
+    }
+
+    let status = Command::new("bundle")
+        .args(args)
+        .status()
+        .context("Failed to run bundle")?;
+
+    timer.track_passthrough(
+        &format!("bundle {}", args.join(" ")),
+        &format!("rtk bundle {} (passthrough)", args.join(" ")),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_bundle_install() {
+        let input = include_str!("../tests/fixtures/bundle_install.txt");
+        assert_eq!(
+            filter_bundle_install(input),
+            "\
+✓ bundle: 82 gems (14 Gemfile dependencies), 3 installed
+  + rack 3.0.9.1, nokogiri 1.16.2, pg 1.5.5
+  (1 post-install messages hidden)"
+        );
+    }
+
+    #[test]
+    fn test_filter_bundle_install_failure() {
+        let input = "\
+Fetching gem metadata from https://rubygems.org/.........
+Resolving dependencies...
+Using rake 13.1.0
+Installing pg 1.5.5 with native extensions
+Gem::Ext::BuildError: ERROR: Failed to build gem native extension.
+
+An error occurred while installing pg (1.5.5), and Bundler cannot continue.
+";
+        let result = filter_bundle_install(input);
+        assert!(result.starts_with("❌ bundle failed\n  + pg 1.5.5"));
+        assert!(result.contains("Gem::Ext::BuildError"));
+        assert!(result.contains("An error occurred while installing pg"));
+        assert!(!result.contains("Using rake"));
+    }
+
+    #[test]
+    fn test_parse_bundle_outdated() {
+        let input = include_str!("../tests/fixtures/bundle_outdated.txt");
+        let result = BundleOutdatedParser::parse(input).unwrap();
+        assert_eq!(result.outdated_count, 3);
+        assert_eq!(
+            result.format(FormatMode::Compact),
+            "\
+3 outdated packages (of 3)
+nokogiri: 1.16.2 → 1.16.3
+rails: 7.1.3 → 7.1.3.2
+rack: 3.0.9.1 → 3.0.10"
+        );
+    }
+
+    #[test]
+    fn test_parse_bundle_outdated_up_to_date() {
+        let result = BundleOutdatedParser::parse("Bundle up to date!\n").unwrap();
+        assert_eq!(
+            result.format(FormatMode::Compact),
+            "All packages up-to-date ✓"
+        );
+    }
+}
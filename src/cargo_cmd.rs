@@ -1,10 +1,20 @@
+use crate::parser::junit::parse_junit;
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, xml_attr, FormatMode,
+    OutputParser, ParseResult, TestFailure, TestResult, TokenFormatter,
+};
 use crate::tracking;
 use crate::utils::truncate;
 use anyhow::{Context, Result};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::ffi::OsString;
-use std::process::Command;
+use std::process::{Command, Output};
 use std::sync::OnceLock;
+use std::time::SystemTime;
+
+/// `cargo tree --depth` applied unless the caller sets one
+const TREE_DEFAULT_DEPTH: usize = 1;
 
 #[derive(Debug, Clone)]
 pub enum CargoCommand {
@@ -13,6 +23,11 @@ pub enum CargoCommand {
     Clippy,
     Check,
     Install,
+    Nextest,
+    Bench,
+    Doc,
+    Tree,
+    Udeps,
 }
 
 pub fn run(cmd: CargoCommand, args: &[String], verbose: u8) -> Result<()> {
@@ -22,6 +37,11 @@ pub fn run(cmd: CargoCommand, args: &[String], verbose: u8) -> Result<()> {
         CargoCommand::Clippy => run_clippy(args, verbose),
         CargoCommand::Check => run_check(args, verbose),
         CargoCommand::Install => run_install(args, verbose),
+        CargoCommand::Nextest => run_nextest(args, verbose),
+        CargoCommand::Bench => run_bench(args, verbose),
+        CargoCommand::Doc => run_doc(args, verbose),
+        CargoCommand::Tree => run_tree(args, verbose),
+        CargoCommand::Udeps => run_udeps(args, verbose),
     }
 }
 
@@ -86,6 +106,163 @@ fn run_install(args: &[String], verbose: u8) -> Result<()> {
     run_cargo_filtered("install", args, verbose, filter_cargo_install)
 }
 
+fn run_bench(args: &[String], verbose: u8) -> Result<()> {
+    run_cargo_filtered("bench", args, verbose, filter_cargo_bench)
+}
+
+fn run_doc(args: &[String], verbose: u8) -> Result<()> {
+    run_cargo_filtered("doc", args, verbose, filter_cargo_doc)
+}
+
+fn run_udeps(args: &[String], verbose: u8) -> Result<()> {
+    run_cargo_filtered("udeps", args, verbose, filter_cargo_udeps)
+}
+
+fn run_tree(args: &[String], verbose: u8) -> Result<()> {
+    if args.iter().any(|a| a == "-d" || a == "--duplicates") {
+        return run_cargo_filtered("tree", args, verbose, filter_cargo_tree_duplicates);
+    }
+
+    let capped = !args
+        .iter()
+        .any(|a| a == "--depth" || a.starts_with("--depth="));
+    let mut tree_args = args.to_vec();
+    if capped {
+        tree_args.push("--depth".to_string());
+        tree_args.push(TREE_DEFAULT_DEPTH.to_string());
+    }
+    run_cargo_filtered("tree", &tree_args, verbose, |output| {
+        let mut filtered = filter_cargo_tree(output);
+        if capped {
+            filtered.push_str(&format!(
+                "\n(--depth {} applied, pass --depth N to expand)",
+                TREE_DEFAULT_DEPTH
+            ));
+        }
+        filtered
+    })
+}
+
+fn run_nextest(args: &[String], verbose: u8) -> Result<()> {
+    // list/archive/show-config are already terse
+    if args.first().map(String::as_str) != Some("run") {
+        let mut passthrough: Vec<OsString> = vec!["nextest".into()];
+        passthrough.extend(args.iter().map(OsString::from));
+        return run_passthrough(&passthrough, verbose);
+    }
+
+    let timer = tracking::TimedExecution::start();
+    let started = SystemTime::now();
+
+    if verbose > 0 {
+        eprintln!("Running: cargo nextest {}", args.join(" "));
+    }
+
+    let exec = |json: bool| -> Result<Output> {
+        let mut cmd = Command::new("cargo");
+        cmd.arg("nextest").arg("run");
+        if json {
+            cmd.arg("--message-format")
+                .arg("libtest-json")
+                .env("NEXTEST_EXPERIMENTAL_LIBTEST_JSON", "1");
+        }
+        cmd.args(&args[1..]).output().context(
+            "Failed to run cargo nextest. Is it installed? Try: cargo install cargo-nextest",
+        )
+    };
+
+    let json = !args.iter().any(|a| a.starts_with("--message-format"));
+    let mut output = exec(json)?;
+    // Releases without libtest-json reject the flag before running anything
+    if json
+        && !output.status.success()
+        && String::from_utf8_lossy(&output.stderr).contains("'--message-format'")
+    {
+        output = exec(false)?;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let parsed = match NextestParser::parse(&raw) {
+        ParseResult::Passthrough(truncated) => {
+            match parse_nextest_junit(&nextest_junit_reports(started)) {
+                Some(result) => ParseResult::Full(result),
+                None => ParseResult::Passthrough(truncated),
+            }
+        }
+        parsed => parsed,
+    };
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = match parsed {
+        ParseResult::Full(result) => result.format(mode),
+        ParseResult::Degraded(result, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("cargo nextest", &warnings.join(", "));
+            }
+            result.format(mode)
+        }
+        ParseResult::Passthrough(truncated) => {
+            emit_passthrough_warning("cargo nextest", "No test results found");
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("cargo nextest {}", args.join(" ")),
+        &format!("rtk cargo nextest {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// nextest JUnit reports: `classname` is the binary id and the failure text
+/// is the captured test output, summarized like the libtest-json path.
+fn parse_nextest_junit(xml: &str) -> Option<TestResult> {
+    parse_junit(xml, |attrs, text| TestFailure {
+        test_name: xml_attr(attrs, "name").unwrap_or_default(),
+        file_path: xml_attr(attrs, "classname").unwrap_or_default(),
+        error_message: panic_summary(text),
+        stack_trace: None,
+    })
+}
+
+/// JUnit reports nextest wrote during this run (`[profile.*.junit]` in
+/// `.config/nextest.toml`), under `target/nextest/<profile>/`.
+fn nextest_junit_reports(since: SystemTime) -> String {
+    let target = std::env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".to_string());
+    let Ok(profiles) = std::fs::read_dir(std::path::Path::new(&target).join("nextest")) else {
+        return String::new();
+    };
+
+    let mut reports = String::new();
+    for profile in profiles.filter_map(|e| e.ok()) {
+        let Ok(files) = std::fs::read_dir(profile.path()) else {
+            continue;
+        };
+        for file in files.filter_map(|e| e.ok()) {
+            let fresh = file
+                .metadata()
+                .ok()
+                .and_then(|m| m.modified().ok())
+                .is_some_and(|modified| modified >= since);
+            if fresh && file.file_name().to_string_lossy().ends_with(".xml") {
+                if let Ok(content) = std::fs::read_to_string(file.path()) {
+                    reports.push_str(&content);
+                }
+            }
+        }
+    }
+    reports
+}
+
 /// Format crate name + version into a display string
 fn format_crate_info(name: &str, version: &str, fallback: &str) -> String {
     if name.is_empty() {
@@ -98,7 +275,7 @@ fn format_crate_info(name: &str, version: &str, fallback: &str) -> String {
 }
 
 /// Filter cargo install output - strip dep compilation, keep installed/replaced/errors
-fn filter_cargo_install(output: &str) -> String {
+pub(crate) fn filter_cargo_install(output: &str) -> String {
     let mut errors: Vec<String> = Vec::new();
     let mut error_count = 0;
     let mut compiled = 0;
@@ -270,7 +447,7 @@ fn filter_cargo_install(output: &str) -> String {
 }
 
 /// Filter cargo build/check output - strip "Compiling"/"Checking" lines, keep errors + summary
-fn filter_cargo_build(output: &str) -> String {
+pub(crate) fn filter_cargo_build(output: &str) -> String {
     let mut errors: Vec<String> = Vec::new();
     let mut warnings = 0;
     let mut error_count = 0;
@@ -459,7 +636,7 @@ impl AggregatedTestResult {
 }
 
 /// Filter cargo test output - show failures + summary only
-fn filter_cargo_test(output: &str) -> String {
+pub(crate) fn filter_cargo_test(output: &str) -> String {
     let mut failures: Vec<String> = Vec::new();
     let mut summary_lines: Vec<String> = Vec::new();
     let mut in_failure_section = false;
@@ -577,7 +754,7 @@ fn filter_cargo_test(output: &str) -> String {
 }
 
 /// Filter cargo clippy output - group warnings by lint rule
-fn filter_cargo_clippy(output: &str) -> String {
+pub(crate) fn filter_cargo_clippy(output: &str) -> String {
     let mut by_rule: HashMap<String, Vec<String>> = HashMap::new();
     let mut error_count = 0;
     let mut warning_count = 0;
@@ -672,6 +849,721 @@ fn filter_cargo_clippy(output: &str) -> String {
     result.trim().to_string()
 }
 
+/// Cargo status lines that carry no information once the command finished.
+fn is_cargo_progress(line: &str) -> bool {
+    const PROGRESS: &[&str] = &[
+        "Compiling",
+        "Checking",
+        "Documenting",
+        "Downloading",
+        "Downloaded",
+        "Updating",
+        "Locking",
+        "Blocking",
+        "Fresh",
+        "Finished",
+        "Running",
+    ];
+    let trimmed = line.trim_start();
+    PROGRESS.iter().any(|p| trimmed.starts_with(p))
+}
+
+/// Output of a cargo subcommand whose format was not recognized: compile
+/// errors when the build failed, the last meaningful lines otherwise.
+fn filter_unrecognized(output: &str) -> String {
+    if output.contains("could not compile") {
+        return filter_cargo_build(output);
+    }
+    let meaningful: Vec<&str> = output
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !is_cargo_progress(l))
+        .collect();
+    let skip = meaningful.len().saturating_sub(20);
+    meaningful[skip..].join("\n")
+}
+
+/// "assertion failed (src/lib.rs:12:9)" plus the next few message lines of a
+/// captured panic; the first lines of `text` when there is no panic.
+fn panic_summary(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let Some(start) = lines
+        .iter()
+        .position(|l| l.starts_with("thread '") && l.contains("panicked at "))
+    else {
+        return lines
+            .iter()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+            .take(3)
+            .collect::<Vec<_>>()
+            .join("\n");
+    };
+
+    let after = lines[start]
+        .split_once("panicked at ")
+        .map(|(_, rest)| rest)
+        .unwrap_or_default();
+    // Rust 1.73+: "panicked at src/lib.rs:12:9:" with the message below;
+    // older: "panicked at 'message', src/lib.rs:12:9"
+    let (message, location, rest) = if let Some(location) = after.strip_suffix(':') {
+        let message = lines.get(start + 1).map(|l| l.trim()).unwrap_or_default();
+        (message, location, start + 2)
+    } else if let Some((message, location)) = after.rsplit_once("', ") {
+        (message.trim_start_matches('\''), location, start + 1)
+    } else {
+        (after, "", start + 1)
+    };
+
+    let mut summary = if location.is_empty() {
+        message.to_string()
+    } else {
+        format!("{} ({})", message, location)
+    };
+    for line in lines
+        .iter()
+        .skip(rest)
+        .take_while(|l| {
+            !l.trim().is_empty() && !l.starts_with("note: ") && !l.starts_with("stack backtrace")
+        })
+        .take(3)
+    {
+        summary.push('\n');
+        summary.push_str(line.trim_end());
+    }
+    summary
+}
+
+/// Parser for `cargo nextest run`: libtest-json events, falling back to
+/// nextest's human-readable output.
+pub(crate) struct NextestParser;
+
+impl OutputParser for NextestParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        if let Some(result) = parse_nextest_json(input) {
+            return ParseResult::Full(result);
+        }
+        match parse_nextest_text(input) {
+            Some(result) => ParseResult::Degraded(
+                result,
+                vec!["libtest-json unavailable, parsed human output".to_string()],
+            ),
+            None => ParseResult::Passthrough(truncate_output(input, 2000)),
+        }
+    }
+}
+
+/// Splits "my-crate::integration$tests::bar" into test name and binary id.
+fn nextest_failure(name: &str, output: &str) -> TestFailure {
+    let (binary, test) = name.split_once('$').unwrap_or(("", name));
+    TestFailure {
+        test_name: test.to_string(),
+        file_path: binary.to_string(),
+        error_message: panic_summary(output),
+        stack_trace: None,
+    }
+}
+
+fn parse_nextest_json(input: &str) -> Option<TestResult> {
+    let mut result = TestResult {
+        total: 0,
+        passed: 0,
+        failed: 0,
+        skipped: 0,
+        duration_ms: None,
+        failures: Vec::new(),
+    };
+    let mut seconds = 0.0;
+    let mut found = false;
+
+    for line in input.lines().filter(|l| l.starts_with('{')) {
+        let Ok(event) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        let kind = event.get("type").and_then(Value::as_str);
+        let status = event.get("event").and_then(Value::as_str);
+        match (kind, status) {
+            (Some("test"), Some("ok")) => result.passed += 1,
+            (Some("test"), Some("ignored")) => result.skipped += 1,
+            (Some("test"), Some("failed")) => {
+                result.failed += 1;
+                let name = event.get("name").and_then(Value::as_str).unwrap_or("?");
+                let stdout = event.get("stdout").and_then(Value::as_str).unwrap_or("");
+                result.failures.push(nextest_failure(name, stdout));
+            }
+            (Some("suite"), Some("ok" | "failed")) => {
+                seconds += event
+                    .get("exec_time")
+                    .and_then(Value::as_f64)
+                    .unwrap_or(0.0);
+            }
+            _ => continue,
+        }
+        found = true;
+    }
+
+    if !found {
+        return None;
+    }
+    result.total = result.passed + result.failed + result.skipped;
+    if seconds > 0.0 {
+        result.duration_ms = Some((seconds * 1000.0) as u64);
+    }
+    Some(result)
+}
+
+fn parse_nextest_text(input: &str) -> Option<TestResult> {
+    static STATUS: OnceLock<regex::Regex> = OnceLock::new();
+    static SUMMARY: OnceLock<regex::Regex> = OnceLock::new();
+    static OUTPUT: OnceLock<regex::Regex> = OnceLock::new();
+    // "        FAIL [   0.005s] my-crate tests::bar", "  TRY 2 PASS [...] (1/3) ..."
+    let status_re = STATUS.get_or_init(|| {
+        regex::Regex::new(
+            r"^\s*([A-Z]+(?: \d+ [A-Z]+)?)\s+\[[^\]]*\]\s+(?:\(\s*\d+/\d+\)\s+)?(\S+)\s+(\S+)\s*$",
+        )
+        .unwrap()
+    });
+    // "     Summary [   0.010s] 3 tests run: 1 passed, 1 failed, 1 skipped"
+    let summary_re = SUMMARY.get_or_init(|| {
+        regex::Regex::new(r"Summary \[\s*([\d.]+)s\] (\d+) tests? run: (.*)").unwrap()
+    });
+    // "--- STDERR:              my-crate tests::bar ---" (older) or "  STDERR:  my-crate tests::bar"
+    let output_re = OUTPUT.get_or_init(|| {
+        regex::Regex::new(r"^\s*(?:--- )?STD(?:OUT|ERR):\s+(\S+)\s+(\S+?)(?:\s+---)?\s*$").unwrap()
+    });
+
+    let mut outputs: HashMap<String, String> = HashMap::new();
+    let mut failed: Vec<(String, String)> = Vec::new();
+    let mut current: Option<String> = None;
+    let mut counted = (0, 0);
+    let mut summary: Option<(f64, String)> = None;
+
+    for line in input.lines() {
+        if let Some(caps) = output_re.captures(line) {
+            current = Some(format!("{}${}", &caps[1], &caps[2]));
+            continue;
+        }
+        if let Some(caps) = summary_re.captures(line) {
+            summary = Some((caps[1].parse().unwrap_or(0.0), caps[3].to_string()));
+            current = None;
+            continue;
+        }
+        if let Some(caps) = status_re.captures(line) {
+            current = None;
+            let key = format!("{}${}", &caps[2], &caps[3]);
+            match &caps[1] {
+                "PASS" => counted.0 += 1,
+                "SKIP" => counted.1 += 1,
+                "START" | "SLOW" | "RETRY" => {}
+                status if status.starts_with("TRY") && status.ends_with("PASS") => {}
+                _ => {
+                    if !failed.iter().any(|(k, _)| *k == key) {
+                        failed.push((key, caps[1].to_string()));
+                    }
+                }
+            }
+            continue;
+        }
+        if line.starts_with("------------") || line.trim_start().starts_with("Starting ") {
+            current = None;
+            continue;
+        }
+        if let Some(key) = &current {
+            let entry = outputs.entry(key.clone()).or_default();
+            entry.push_str(line);
+            entry.push('\n');
+        }
+    }
+
+    if summary.is_none() && counted == (0, 0) && failed.is_empty() {
+        return None;
+    }
+
+    let count = |text: &str, label: &str| -> Option<usize> {
+        let idx = text.find(&format!(" {}", label))?;
+        text[..idx].rsplit([' ', ',']).next()?.parse().ok()
+    };
+    let failures: Vec<TestFailure> = failed
+        .iter()
+        .map(|(key, status)| {
+            let output = outputs.get(key).map(String::as_str).unwrap_or("");
+            let mut failure = nextest_failure(key, output);
+            if failure.error_message.is_empty() {
+                failure.error_message = status.clone();
+            }
+            failure
+        })
+        .collect();
+
+    let (passed, failed_count, skipped, duration_ms) = match &summary {
+        Some((seconds, text)) => (
+            count(text, "passed").unwrap_or(counted.0),
+            count(text, "failed").unwrap_or(0) + count(text, "timed out").unwrap_or(0),
+            count(text, "skipped").unwrap_or(counted.1),
+            Some((seconds * 1000.0) as u64),
+        ),
+        None => (counted.0, failures.len(), counted.1, None),
+    };
+
+    Some(TestResult {
+        total: passed + failed_count + skipped,
+        passed,
+        failed: failed_count,
+        skipped,
+        duration_ms,
+        failures,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BenchVerdict {
+    Regressed,
+    Improved,
+    Unchanged,
+    New,
+}
+
+#[derive(Debug)]
+struct BenchRow {
+    name: String,
+    time: String,
+    change: Option<f64>,
+    verdict: BenchVerdict,
+}
+
+/// Filter cargo bench output - criterion estimates and changes as a table,
+/// regressions first
+pub(crate) fn filter_cargo_bench(output: &str) -> String {
+    static TIME: OnceLock<regex::Regex> = OnceLock::new();
+    static CHANGE: OnceLock<regex::Regex> = OnceLock::new();
+    static LIBTEST: OnceLock<regex::Regex> = OnceLock::new();
+    // "fib 20                  time:   [26.029 µs 26.251 µs 26.505 µs]"
+    let time_re = TIME.get_or_init(|| {
+        regex::Regex::new(r"^(.*?)\s*time:\s+\[\S+ \S+ (\S+ \S+) \S+ \S+\]").unwrap()
+    });
+    // "                        change: [+12.034% +13.172% +14.326%] (p = 0.00 < 0.05)"
+    let change_re = CHANGE.get_or_init(|| {
+        regex::Regex::new(r"^\s*(?:change:\s+|time:\s+)\[\S+% ([+\-]?[\d.]+)% \S+%\]").unwrap()
+    });
+    // "test bench_parse ... bench:       1,234 ns/iter (+/- 56)"
+    let libtest_re = LIBTEST.get_or_init(|| {
+        regex::Regex::new(r"^test (\S+)\s+\.\.\. bench:\s+([\d,.]+ \S+/iter)").unwrap()
+    });
+
+    let mut rows: Vec<BenchRow> = Vec::new();
+    let mut pending_name: Option<String> = None;
+    let mut in_change = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || is_cargo_progress(line) {
+            continue;
+        }
+
+        if let Some(caps) = libtest_re.captures(trimmed) {
+            rows.push(BenchRow {
+                name: caps[1].to_string(),
+                time: caps[2].to_string(),
+                change: None,
+                verdict: BenchVerdict::New,
+            });
+            continue;
+        }
+        // Throughput benchmarks put "change:" on its own line, then "time:"
+        if trimmed == "change:" {
+            in_change = true;
+            continue;
+        }
+        if trimmed.starts_with("change:") || (in_change && trimmed.starts_with("time:")) {
+            in_change = false;
+            if let (Some(caps), Some(row)) = (change_re.captures(line), rows.last_mut()) {
+                row.change = caps[1].parse().ok();
+                row.verdict = BenchVerdict::Unchanged;
+            }
+            continue;
+        }
+        if let Some(caps) = time_re.captures(line) {
+            // Long names are printed on their own line before "time:"
+            let name = match caps[1].trim() {
+                "" => pending_name.take().unwrap_or_default(),
+                name => name.to_string(),
+            };
+            rows.push(BenchRow {
+                name,
+                time: caps[2].to_string(),
+                change: None,
+                verdict: BenchVerdict::New,
+            });
+            continue;
+        }
+        if let Some(row) = rows.last_mut() {
+            if trimmed.starts_with("Performance has regressed") {
+                row.verdict = BenchVerdict::Regressed;
+                continue;
+            }
+            if trimmed.starts_with("Performance has improved") {
+                row.verdict = BenchVerdict::Improved;
+                continue;
+            }
+        }
+        if !line.starts_with(char::is_whitespace) {
+            pending_name = Some(trimmed.to_string());
+        }
+    }
+
+    if rows.is_empty() {
+        let fallback = filter_unrecognized(output);
+        return if fallback.is_empty() {
+            "cargo bench: no benchmark results".to_string()
+        } else {
+            fallback
+        };
+    }
+
+    let count = |v: BenchVerdict| rows.iter().filter(|r| r.verdict == v).count();
+    let mut counts = Vec::new();
+    for (verdict, label) in [
+        (BenchVerdict::Regressed, "regressed"),
+        (BenchVerdict::Improved, "improved"),
+        (BenchVerdict::Unchanged, "unchanged"),
+        (BenchVerdict::New, "no baseline"),
+    ] {
+        let n = count(verdict);
+        if n > 0 {
+            counts.push(format!("{} {}", n, label));
+        }
+    }
+
+    // Regressions (worst first), then improvements, then the rest in run order
+    let change = |r: &BenchRow| r.change.unwrap_or(0.0);
+    rows.sort_by(|a, b| {
+        let rank = |r: &BenchRow| match r.verdict {
+            BenchVerdict::Regressed => 0,
+            BenchVerdict::Improved => 1,
+            _ => 2,
+        };
+        rank(a).cmp(&rank(b)).then_with(|| match a.verdict {
+            BenchVerdict::Regressed => change(b).total_cmp(&change(a)),
+            BenchVerdict::Improved => change(a).total_cmp(&change(b)),
+            _ => std::cmp::Ordering::Equal,
+        })
+    });
+
+    let name_width = rows
+        .iter()
+        .map(|r| r.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let time_width = rows
+        .iter()
+        .map(|r| r.time.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut result = format!(
+        "cargo bench: {} benchmarks ({})\n",
+        rows.len(),
+        counts.join(", ")
+    );
+    result.push_str("═══════════════════════════════════════\n");
+    for row in rows.iter().take(30) {
+        let change = match row.change {
+            Some(c) => format!("{:+.2}%", c),
+            None => "-".to_string(),
+        };
+        let marker = match row.verdict {
+            BenchVerdict::Regressed => "  ⚠ regressed",
+            BenchVerdict::Improved => "  improved",
+            _ => "",
+        };
+        result.push_str(&format!(
+            "  {:<nw$}  {:<tw$}  {:>8}{}\n",
+            row.name,
+            row.time,
+            change,
+            marker,
+            nw = name_width,
+            tw = time_width
+        ));
+    }
+    if rows.len() > 30 {
+        result.push_str(&format!("  ... +{} more\n", rows.len() - 30));
+    }
+
+    result.trim_end().to_string()
+}
+
+/// Filter cargo doc output - rustdoc warnings grouped by lint
+pub(crate) fn filter_cargo_doc(output: &str) -> String {
+    static LINT: OnceLock<regex::Regex> = OnceLock::new();
+    static CODE: OnceLock<regex::Regex> = OnceLock::new();
+    // "= note: `#[warn(rustdoc::broken_intra_doc_links)]` on by default"
+    let lint_re =
+        LINT.get_or_init(|| regex::Regex::new(r"#!?\[(?:warn|deny|forbid)\(([\w:]+)").unwrap());
+    let code_re = CODE.get_or_init(|| regex::Regex::new(r"`[^`]*`").unwrap());
+
+    struct Diagnostic {
+        error: bool,
+        message: String,
+        location: Option<String>,
+        lint: Option<String>,
+    }
+
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut generated: Option<String> = None;
+
+    for line in output.lines() {
+        let trimmed = line.trim_start();
+        if let Some(path) = trimmed.strip_prefix("Generated ") {
+            let cwd = std::env::current_dir().unwrap_or_default();
+            let cwd = format!("{}/", cwd.display());
+            generated = Some(path.strip_prefix(&cwd).unwrap_or(path).to_string());
+            continue;
+        }
+
+        if line.starts_with("warning") || line.starts_with("error") {
+            let Some((head, message)) = line.split_once(": ") else {
+                continue;
+            };
+            if (message.contains("generated") && message.contains("warning"))
+                || message.contains("aborting due to")
+                || message.starts_with("could not ")
+            {
+                continue;
+            }
+            // "error[E0425]: ..." carries its code in brackets
+            let lint = head
+                .split_once('[')
+                .map(|(_, code)| code.trim_end_matches(']').to_string());
+            diagnostics.push(Diagnostic {
+                error: head.starts_with("error"),
+                message: message.to_string(),
+                location: None,
+                lint,
+            });
+        } else if let Some(diag) = diagnostics.last_mut() {
+            if let Some(location) = trimmed.strip_prefix("--> ") {
+                diag.location.get_or_insert_with(|| location.to_string());
+            } else if diag.lint.is_none() {
+                if let Some(caps) = lint_re.captures(trimmed) {
+                    diag.lint = Some(caps[1].to_string());
+                }
+            }
+        }
+    }
+
+    // rustdoc names the lint only on its first occurrence; later ones with the
+    // same message shape inherit it
+    let template = |message: &str| code_re.replace_all(message, "`…`").to_string();
+    let mut template_lints: HashMap<String, String> = HashMap::new();
+    for diag in &diagnostics {
+        if let Some(lint) = &diag.lint {
+            template_lints
+                .entry(template(&diag.message))
+                .or_insert_with(|| lint.clone());
+        }
+    }
+
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    let mut seen: Vec<(String, Option<String>)> = Vec::new();
+    for diag in &diagnostics {
+        // The same warning shows up once per documented target
+        let id = (diag.message.clone(), diag.location.clone());
+        if seen.contains(&id) {
+            continue;
+        }
+        seen.push(id);
+
+        let key = diag
+            .lint
+            .clone()
+            .or_else(|| template_lints.get(&template(&diag.message)).cloned())
+            .unwrap_or_else(|| template(&diag.message));
+        let entry = match &diag.location {
+            Some(location) => format!("{} {}", location, diag.message),
+            None => diag.message.clone(),
+        };
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, entries)) => entries.push(entry),
+            None => groups.push((key, vec![entry])),
+        }
+    }
+
+    let error_count = diagnostics.iter().filter(|d| d.error).count();
+    let warning_count = seen.len() - error_count.min(seen.len());
+
+    if groups.is_empty() {
+        return match generated {
+            Some(path) => format!("✓ cargo doc: No warnings ({})", path),
+            None => "✓ cargo doc: No warnings".to_string(),
+        };
+    }
+
+    let mut result = String::new();
+    result.push_str(&format!(
+        "cargo doc: {} errors, {} warnings\n",
+        error_count, warning_count
+    ));
+    result.push_str("═══════════════════════════════════════\n");
+
+    groups.sort_by_key(|(_, entries)| std::cmp::Reverse(entries.len()));
+    for (lint, entries) in groups.iter().take(15) {
+        result.push_str(&format!("  {} ({}x)\n", lint, entries.len()));
+        for entry in entries.iter().take(3) {
+            result.push_str(&format!("    {}\n", truncate(entry, 120)));
+        }
+        if entries.len() > 3 {
+            result.push_str(&format!("    ... +{} more\n", entries.len() - 3));
+        }
+    }
+    if groups.len() > 15 {
+        result.push_str(&format!("\n... +{} more lints\n", groups.len() - 15));
+    }
+    if let Some(path) = generated {
+        result.push_str(&format!("✓ Generated {}\n", path));
+    }
+
+    result.trim().to_string()
+}
+
+/// Filter cargo tree output - drop resolution progress and blank lines
+pub(crate) fn filter_cargo_tree(output: &str) -> String {
+    output
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !is_cargo_progress(l))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Filter cargo tree -d output - one line per duplicated crate with the
+/// packages pulling in each version
+pub(crate) fn filter_cargo_tree_duplicates(output: &str) -> String {
+    // [(version, direct dependents)] per crate name
+    type Versions = Vec<(String, Vec<String>)>;
+    let mut crates: Vec<(String, Versions)> = Vec::new();
+
+    for line in output.lines() {
+        if line.trim().is_empty() || is_cargo_progress(line) {
+            continue;
+        }
+        if let Some(rest) = line
+            .strip_prefix("├── ")
+            .or_else(|| line.strip_prefix("└── "))
+        {
+            let parent = rest.split_whitespace().next().unwrap_or(rest);
+            if let Some((_, versions)) = crates.last_mut() {
+                if let Some((_, parents)) = versions.last_mut() {
+                    if !parents.iter().any(|p| p == parent) {
+                        parents.push(parent.to_string());
+                    }
+                }
+            }
+            continue;
+        }
+        if line.starts_with([' ', '│', '[']) {
+            continue;
+        }
+
+        // "bitflags v1.3.2" (possibly followed by "(proc-macro)" or a path)
+        let mut parts = line.split_whitespace();
+        let (Some(name), Some(version)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        if !version.starts_with('v') || !version[1..].starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        let version = (version.to_string(), Vec::new());
+        match crates.iter_mut().find(|(n, _)| n == name) {
+            Some((_, versions)) => versions.push(version),
+            None => crates.push((name.to_string(), vec![version])),
+        }
+    }
+
+    if crates.is_empty() {
+        return "✓ cargo tree -d: no duplicate versions".to_string();
+    }
+
+    let mut result = format!(
+        "cargo tree -d: {} crates with multiple versions\n",
+        crates.len()
+    );
+    for (name, versions) in &crates {
+        let versions: Vec<String> = versions
+            .iter()
+            .map(|(version, parents)| {
+                if parents.is_empty() {
+                    return version.clone();
+                }
+                let mut via = parents
+                    .iter()
+                    .take(3)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if parents.len() > 3 {
+                    via.push_str(&format!(" +{}", parents.len() - 3));
+                }
+                format!("{} (via {})", version, via)
+            })
+            .collect();
+        result.push_str(&format!("  {}: {}\n", name, versions.join(", ")));
+    }
+
+    result.trim_end().to_string()
+}
+
+/// Filter cargo udeps output - unused dependencies per crate and kind
+pub(crate) fn filter_cargo_udeps(output: &str) -> String {
+    static CRATE: OnceLock<regex::Regex> = OnceLock::new();
+    // "`cargo-udeps v0.1.0 (/path/to/cargo-udeps)`"
+    let crate_re = CRATE.get_or_init(|| regex::Regex::new(r"^`(\S+) v\S+[^`]*`$").unwrap());
+
+    if output.contains("All deps seem to have been used") {
+        return "✓ cargo udeps: all dependencies used".to_string();
+    }
+
+    // (crate, kind, unused deps)
+    let mut sections: Vec<(String, String, Vec<String>)> = Vec::new();
+    let mut current_crate = String::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim_matches(|c: char| c == '│' || c.is_whitespace());
+        if let Some(caps) = crate_re.captures(trimmed) {
+            current_crate = caps[1].to_string();
+        } else if let Some(kind) = trimmed
+            .strip_prefix("└─── ")
+            .or_else(|| trimmed.strip_prefix("├─── "))
+        {
+            match kind.strip_prefix('"').and_then(|d| d.strip_suffix('"')) {
+                Some(dep) => {
+                    if let Some((_, _, deps)) = sections.last_mut() {
+                        deps.push(dep.to_string());
+                    }
+                }
+                None => sections.push((current_crate.clone(), kind.to_string(), Vec::new())),
+            }
+        }
+    }
+
+    let total: usize = sections.iter().map(|(_, _, deps)| deps.len()).sum();
+    if total == 0 {
+        return filter_unrecognized(output);
+    }
+
+    let mut result = format!("cargo udeps: {} unused dependencies\n", total);
+    for (name, kind, deps) in sections.iter().filter(|(_, _, deps)| !deps.is_empty()) {
+        let label = match kind.as_str() {
+            "dependencies" => name.clone(),
+            other => format!("{} ({})", name, other.trim_end_matches("-dependencies")),
+        };
+        result.push_str(&format!("  {}: {}\n", label, deps.join(", ")));
+    }
+    result.push_str("  (may include false positives, e.g. crates used only in doc-tests)");
+
+    result
+}
+
 /// Runs an unsupported cargo subcommand by passing it through directly
 pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
@@ -1122,4 +2014,150 @@ error: aborting due to 2 previous errors
         assert_eq!(format_crate_info("", "", "package"), "package");
         assert_eq!(format_crate_info("", "v0.1.0", "fallback"), "fallback");
     }
+
+    #[test]
+    fn test_nextest_json_parser() {
+        let input = r#"{"type":"suite","event":"started","test_count":3}
+{"type":"test","event":"started","name":"calc$tests::adds"}
+{"type":"test","event":"ok","name":"calc$tests::adds","exec_time":0.004}
+{"type":"test","event":"failed","name":"calc$tests::divides","exec_time":0.006,"stdout":"\nrunning 1 test\nthread 'tests::divides' panicked at src/lib.rs:27:9:\nassertion `left == right` failed\n  left: 2\n right: 3\nnote: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n"}
+{"type":"test","event":"ignored","name":"calc$tests::slow_division"}
+{"type":"suite","event":"failed","passed":1,"failed":1,"ignored":1,"measured":0,"filtered_out":0,"exec_time":0.012}"#;
+        let result = match NextestParser::parse(input) {
+            ParseResult::Full(result) => result,
+            other => panic!("expected Full, got tier {}", other.tier()),
+        };
+        assert_eq!((result.passed, result.failed, result.skipped), (1, 1, 1));
+        assert_eq!(result.duration_ms, Some(12));
+        assert_eq!(result.failures[0].test_name, "tests::divides");
+        assert_eq!(
+            result.failures[0].error_message,
+            "assertion `left == right` failed (src/lib.rs:27:9)\n  left: 2\n right: 3"
+        );
+    }
+
+    #[test]
+    fn test_nextest_human_output_fallback() {
+        let input = include_str!("../tests/fixtures/cargo_nextest.txt");
+        let result = match NextestParser::parse(input) {
+            ParseResult::Degraded(result, _) => result,
+            other => panic!("expected Degraded, got tier {}", other.tier()),
+        };
+        assert_eq!((result.passed, result.failed, result.skipped), (2, 1, 1));
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].file_path, "calc");
+        assert!(result.failures[0]
+            .error_message
+            .starts_with("assertion `left == right` failed (src/lib.rs:27:9)"));
+    }
+
+    #[test]
+    fn test_nextest_junit_fallback() {
+        let xml = include_str!("../tests/fixtures/cargo_nextest_junit.xml");
+        let result = parse_nextest_junit(xml).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (3, 2, 1, 0)
+        );
+        assert_eq!(result.duration_ms, Some(12));
+        let failure = &result.failures[0];
+        assert_eq!(failure.test_name, "tests::divides");
+        assert_eq!(failure.file_path, "calc");
+        assert_eq!(
+            failure.error_message,
+            "assertion `left == right` failed (src/lib.rs:27:9)\n  left: 2\n right: 3"
+        );
+    }
+
+    #[test]
+    fn test_filter_cargo_bench() {
+        let input = include_str!("../tests/fixtures/cargo_bench.txt");
+        assert_eq!(
+            filter_cargo_bench(input),
+            "\
+cargo bench: 4 benchmarks (1 regressed, 1 improved, 1 unchanged, 1 no baseline)
+═══════════════════════════════════════
+  parse/large                             26.251 µs   +13.17%  ⚠ regressed
+  evaluate_deeply_nested_expression_tree  2.1190 ms    -8.00%  improved
+  parse/small                             815.02 ns    +0.13%
+  tokenize                                105.67 ns         -"
+        );
+    }
+
+    #[test]
+    fn test_filter_cargo_doc() {
+        let input = include_str!("../tests/fixtures/cargo_doc.txt");
+        assert_eq!(
+            filter_cargo_doc(input),
+            "\
+cargo doc: 0 errors, 3 warnings
+═══════════════════════════════════════
+  rustdoc::broken_intra_doc_links (2x)
+    src/lib.rs:3:10 unresolved link to `Parser`
+    src/eval.rs:12:22 unresolved link to `eval::Env`
+  rustdoc::bare_urls (1x)
+    src/lib.rs:8:5 this URL is not a hyperlink
+✓ Generated /work/calc/target/doc/calc/index.html"
+        );
+    }
+
+    #[test]
+    fn test_filter_cargo_tree_duplicates() {
+        let input = "\
+bitflags v1.3.2
+└── inotify v0.9.6
+    └── notify v6.1.1
+        └── app v0.1.0 (/work/app)
+
+bitflags v2.4.2
+├── nix v0.27.1
+│   └── app v0.1.0 (/work/app)
+└── rustix v0.38.31
+    └── tempfile v3.10.0
+
+syn v1.0.109
+└── serde_derive v1.0.130 (proc-macro)
+
+syn v2.0.48
+└── tokio-macros v2.2.0 (proc-macro)
+";
+        assert_eq!(
+            filter_cargo_tree_duplicates(input),
+            "\
+cargo tree -d: 2 crates with multiple versions
+  bitflags: v1.3.2 (via inotify), v2.4.2 (via nix, rustix)
+  syn: v1.0.109 (via serde_derive), v2.0.48 (via tokio-macros)"
+        );
+        assert_eq!(
+            filter_cargo_tree_duplicates("nothing to print.\n"),
+            "✓ cargo tree -d: no duplicate versions"
+        );
+    }
+
+    #[test]
+    fn test_filter_cargo_udeps() {
+        let input = "\
+    Checking calc v0.1.0 (/work/calc)
+unused dependencies:
+`calc v0.1.0 (/work/calc)`
+├─── dependencies
+│    ├─── \"itertools\"
+│    └─── \"once_cell\"
+└─── dev-dependencies
+     └─── \"pretty_assertions\"
+Note: They might be false-positive.
+";
+        assert_eq!(
+            filter_cargo_udeps(input),
+            "\
+cargo udeps: 3 unused dependencies
+  calc: itertools, once_cell
+  calc (dev): pretty_assertions
+  (may include false positives, e.g. crates used only in doc-tests)"
+        );
+        assert_eq!(
+            filter_cargo_udeps("All deps seem to have been used.\n"),
+            "✓ cargo udeps: all dependencies used"
+        );
+    }
 }
@@ -0,0 +1,332 @@
+//! GitHub Actions log filtering for `rtk gh run view --log-failed` and
+//! `rtk gh log-file`.
+//!
+//! Actions logs carry a timestamp on every line, ANSI colors and
+//! `##[group]`/`##[endgroup]` workflow markers. `gh run view --log` also
+//! prefixes each line with `job<TAB>step<TAB>`, while a log downloaded from
+//! the web UI only separates steps with `##[group]Run <command>` headers.
+//! Both layouts are split into steps; each failing step's output is run
+//! through the rtk filter matching the step's command.
+
+use crate::hook_cmd::{self, PostFilter};
+use crate::utils::strip_ansi;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Failing steps shown before collapsing.
+const MAX_FAILED_STEPS: usize = 5;
+/// Lines kept from the end of a step whose command has no dedicated filter.
+const TAIL_LINES: usize = 30;
+/// Error annotations listed per step.
+const MAX_ANNOTATIONS: usize = 5;
+
+lazy_static! {
+    static ref TIMESTAMP: Regex =
+        Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?Z ?").unwrap();
+    static ref EXIT_CODE: Regex =
+        Regex::new(r"^Process completed with exit code (\d+)\.?$").unwrap();
+}
+
+/// One workflow step reconstructed from the log.
+#[derive(Debug, Default)]
+struct Step {
+    job: String,
+    name: String,
+    /// Script lines from the `##[group]Run` header
+    script: Vec<String>,
+    output: Vec<String>,
+    /// `##[error]` annotations other than the exit code
+    errors: Vec<String>,
+    exit_code: Option<i32>,
+    in_header: bool,
+    in_script: bool,
+}
+
+impl Step {
+    fn failed(&self) -> bool {
+        self.exit_code.is_some() || !self.errors.is_empty()
+    }
+
+    /// The script line an rtk filter recognizes, else the first one.
+    fn command(&self) -> Option<&str> {
+        self.script
+            .iter()
+            .find(|line| hook_cmd::classify(line).is_some())
+            .or(self.script.first())
+            .map(String::as_str)
+    }
+
+    fn title(&self) -> String {
+        if self.job.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} › {}", self.job, self.name)
+        }
+    }
+}
+
+/// Split `job<TAB>step<TAB>timestamp content` lines; plain lines have no columns.
+fn split_columns(line: &str) -> (Option<(&str, &str)>, &str) {
+    let mut parts = line.splitn(3, '\t');
+    if let (Some(job), Some(step), Some(rest)) = (parts.next(), parts.next(), parts.next()) {
+        if TIMESTAMP.is_match(rest) {
+            return (Some((job, step)), rest);
+        }
+    }
+    (None, line)
+}
+
+fn parse_steps(log: &str) -> Vec<Step> {
+    let mut steps: Vec<Step> = Vec::new();
+
+    for raw in log.lines() {
+        let raw = raw.trim_start_matches('\u{feff}');
+        let (columns, rest) = split_columns(raw);
+        let content = strip_ansi(&TIMESTAMP.replace(rest, ""));
+        let content = content.trim_end();
+
+        if let Some((job, name)) = columns {
+            let is_new = steps.last().is_none_or(|s| s.job != job || s.name != name);
+            if is_new {
+                steps.push(Step {
+                    job: job.to_string(),
+                    name: name.to_string(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if let Some(title) = content.strip_prefix("##[group]") {
+            if let Some(script) = title.strip_prefix("Run ") {
+                // Downloaded logs have no step column: each Run header starts a step
+                if columns.is_none() {
+                    steps.push(Step {
+                        name: title.to_string(),
+                        ..Default::default()
+                    });
+                }
+                if let Some(step) = steps.last_mut() {
+                    if step.output.is_empty() && step.script.is_empty() {
+                        step.script.push(script.trim().to_string());
+                        step.in_header = true;
+                        step.in_script = true;
+                    }
+                }
+            }
+            continue;
+        }
+
+        let Some(step) = steps.last_mut() else {
+            continue;
+        };
+
+        if content.starts_with("##[endgroup]") {
+            step.in_header = false;
+            continue;
+        }
+        if step.in_header {
+            // Script lines come first, then `with:`, `shell:` and `env:` settings
+            if ["shell: ", "env:", "with:"]
+                .iter()
+                .any(|p| content.starts_with(p))
+            {
+                step.in_script = false;
+            }
+            if step.in_script && !content.trim().is_empty() {
+                let line = content.trim().to_string();
+                // The group title repeats the first script line
+                if step.script.len() > 1 || step.script.first() != Some(&line) {
+                    step.script.push(line);
+                }
+            }
+            continue;
+        }
+
+        if let Some(message) = content.strip_prefix("##[error]") {
+            match EXIT_CODE.captures(message) {
+                Some(caps) => step.exit_code = caps[1].parse().ok(),
+                None => step.errors.push(message.to_string()),
+            }
+            continue;
+        }
+        if content.starts_with("##[") {
+            // warning/notice/debug annotations and other workflow commands
+            continue;
+        }
+
+        step.output.push(content.to_string());
+    }
+
+    steps
+}
+
+/// Output of a step without a dedicated filter: log dedupe or the tail.
+fn fallback_filter(output: &str) -> String {
+    if hook_cmd::looks_like_log(output) {
+        return PostFilter::Log.apply(output, "", false);
+    }
+    let lines: Vec<&str> = output.lines().collect();
+    if lines.len() <= TAIL_LINES {
+        return output.to_string();
+    }
+    format!(
+        "... {} lines above\n{}",
+        lines.len() - TAIL_LINES,
+        lines[lines.len() - TAIL_LINES..].join("\n")
+    )
+}
+
+fn format_step(step: &Step) -> String {
+    let mut out = match step.exit_code {
+        Some(code) => format!("❌ {} (exit code {})", step.title(), code),
+        None => format!("❌ {}", step.title()),
+    };
+
+    let output = step.output.join("\n");
+    let output = output.trim_matches('\n');
+    let filtered = match step.command() {
+        Some(command) => {
+            out.push_str(&format!("\n$ {}", command));
+            match hook_cmd::classify(command) {
+                Some(filter) => filter.apply(output, command, false),
+                None => fallback_filter(output),
+            }
+        }
+        None => fallback_filter(output),
+    };
+    if !filtered.trim().is_empty() {
+        out.push('\n');
+        out.push_str(filtered.trim_end());
+    }
+
+    let annotations: Vec<&String> = step
+        .errors
+        .iter()
+        .filter(|e| !filtered.contains(e.as_str()))
+        .collect();
+    for error in annotations.iter().take(MAX_ANNOTATIONS) {
+        out.push_str(&format!("\n⚠️  {}", error));
+    }
+    if annotations.len() > MAX_ANNOTATIONS {
+        out.push_str(&format!(
+            "\n... +{} more errors",
+            annotations.len() - MAX_ANNOTATIONS
+        ));
+    }
+
+    out
+}
+
+/// Filter a GitHub Actions log down to its failing steps.
+pub(crate) fn filter_ci_log(log: &str) -> String {
+    let steps = parse_steps(log);
+    let failed: Vec<&Step> = steps.iter().filter(|s| s.failed()).collect();
+
+    if failed.is_empty() {
+        return format!("✓ No failed steps in log ({} steps)", steps.len());
+    }
+
+    let mut sections: Vec<String> = failed
+        .iter()
+        .take(MAX_FAILED_STEPS)
+        .map(|step| format_step(step))
+        .collect();
+    if failed.len() > MAX_FAILED_STEPS {
+        sections.push(format!(
+            "... +{} more failed steps",
+            failed.len() - MAX_FAILED_STEPS
+        ));
+    }
+    sections.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GH_LOG: &str = "\
+test\tRun cargo test\t2024-05-01T10:00:00.1234567Z ##[group]Run cargo test --all
+test\tRun cargo test\t2024-05-01T10:00:00.1234567Z \x1b[36;1mcargo test --all\x1b[0m
+test\tRun cargo test\t2024-05-01T10:00:00.1234567Z shell: /usr/bin/bash -e {0}
+test\tRun cargo test\t2024-05-01T10:00:00.1234567Z env:
+test\tRun cargo test\t2024-05-01T10:00:00.1234567Z   CARGO_TERM_COLOR: always
+test\tRun cargo test\t2024-05-01T10:00:00.1234567Z ##[endgroup]
+test\tRun cargo test\t2024-05-01T10:00:01.0000000Z    Compiling app v0.1.0
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z     Finished test [unoptimized] target(s) in 4.00s
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z      Running unittests src/main.rs
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z running 2 tests
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z test tests::ok ... ok
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z test tests::broken ... FAILED
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z failures:
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z ---- tests::broken stdout ----
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z assertion failed: 1 == 2
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z failures:
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z     tests::broken
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out
+test\tRun cargo test\t2024-05-01T10:00:05.0000000Z ##[error]Process completed with exit code 101.
+";
+
+    #[test]
+    fn test_parse_steps_gh_layout() {
+        let steps = parse_steps(GH_LOG);
+        assert_eq!(steps.len(), 1);
+        let step = &steps[0];
+        assert_eq!(step.job, "test");
+        assert_eq!(step.name, "Run cargo test");
+        assert_eq!(step.script, vec!["cargo test --all"]);
+        assert_eq!(step.exit_code, Some(101));
+        assert!(step.output[0].contains("Compiling app"));
+        assert!(step
+            .output
+            .iter()
+            .all(|l| !l.contains("2024-05-01") && !l.starts_with("##[")));
+    }
+
+    #[test]
+    fn test_filter_ci_log_uses_command_filter() {
+        let result = filter_ci_log(GH_LOG);
+        assert!(result.starts_with("❌ test › Run cargo test (exit code 101)\n$ cargo test --all"));
+        assert!(result.contains("tests::broken"));
+        assert!(!result.contains("Compiling app"));
+        assert!(!result.contains("CARGO_TERM_COLOR"));
+    }
+
+    #[test]
+    fn test_filter_downloaded_log() {
+        let log = "\u{feff}2024-05-01T10:00:00.0000000Z ##[group]Run actions/checkout@v4
+2024-05-01T10:00:00.0000000Z with:
+2024-05-01T10:00:00.0000000Z ##[endgroup]
+2024-05-01T10:00:01.0000000Z Syncing repository
+2024-05-01T10:00:02.0000000Z ##[group]Run npm ci
+2024-05-01T10:00:02.0000000Z npm ci
+2024-05-01T10:00:02.0000000Z make lint
+2024-05-01T10:00:02.0000000Z shell: /usr/bin/bash -e {0}
+2024-05-01T10:00:02.0000000Z ##[endgroup]
+2024-05-01T10:00:03.0000000Z added 10 packages
+2024-05-01T10:00:04.0000000Z lint: src/a.js:1 missing semicolon
+2024-05-01T10:00:04.0000000Z ##[error]src/a.js:1 missing semicolon
+2024-05-01T10:00:04.0000000Z ##[error]Unexpected failure
+2024-05-01T10:00:04.0000000Z ##[error]Process completed with exit code 2.
+";
+        let steps = parse_steps(log);
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[1].script, vec!["npm ci", "make lint"]);
+
+        let result = filter_ci_log(log);
+        assert_eq!(
+            result,
+            "❌ Run npm ci (exit code 2)\n$ npm ci\nadded 10 packages\nlint: src/a.js:1 missing semicolon\n⚠️  Unexpected failure"
+        );
+    }
+
+    #[test]
+    fn test_filter_ci_log_no_failures() {
+        let log = "2024-05-01T10:00:00.0000000Z ##[group]Run echo hi\n2024-05-01T10:00:00.0000000Z ##[endgroup]\n2024-05-01T10:00:00.0000000Z hi\n";
+        assert_eq!(filter_ci_log(log), "✓ No failed steps in log (1 steps)");
+    }
+}
@@ -0,0 +1,397 @@
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, Dependency,
+    DependencyState, FormatMode, OutputParser, ParseResult, TokenFormatter,
+};
+use crate::tracking;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+use std::process::Command;
+
+/// Package names listed per operation before collapsing.
+const MAX_PACKAGES_SHOWN: usize = 10;
+/// Lines kept from problems/warnings.
+const MAX_OTHER_LINES: usize = 30;
+
+lazy_static::lazy_static! {
+    // "Package operations: 3 installs, 1 update, 1 removal"
+    static ref OPERATIONS: Regex =
+        Regex::new(r"^(?:Package|Lock file) operations: (.+)$").unwrap();
+    // "  - Upgrading laravel/framework (v10.48.2 => v10.48.4): Extracting archive"
+    static ref OPERATION: Regex = Regex::new(
+        r"^- (Installing|Upgrading|Downgrading|Removing) (\S+) \(([^)]+)\)"
+    ).unwrap();
+    // "  laravel/tinker ........................ DONE"
+    static ref DISCOVERED: Regex = Regex::new(r"^\S+ \.{3,} DONE$").unwrap();
+    // "laravel/framework v10.48.4 ! v11.0.7 The Laravel Framework."
+    static ref OUTDATED_ROW: Regex =
+        Regex::new(r"^(\S+/\S+)\s+(\S+)\s+[!~=]\s+(\S+)").unwrap();
+}
+
+#[derive(Debug, Deserialize)]
+struct ComposerOutdated {
+    #[serde(default)]
+    installed: Vec<ComposerPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ComposerPackage {
+    name: String,
+    version: String,
+    latest: Option<String>,
+    #[serde(rename = "latest-status")]
+    latest_status: Option<String>,
+    #[serde(default)]
+    abandoned: serde_json::Value,
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    match args.first().map(|s| s.as_str()) {
+        Some("install") | Some("update") | Some("require") | Some("remove") | None => {
+            run_install(args, verbose)
+        }
+        Some("outdated") => run_outdated(&args[1..], verbose),
+        Some(_) => run_passthrough(args, verbose),
+    }
+}
+
+fn run_install(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: composer {}", args.join(" "));
+    }
+
+    let output = Command::new("composer")
+        .args(args)
+        .output()
+        .context("Failed to run composer. Is it installed?")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let subcommand = args.first().map(|s| s.as_str()).unwrap_or("install");
+    let filtered = filter_composer_install(subcommand, &raw, output.status.success());
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("composer {}", args.join(" ")),
+        &format!("rtk composer {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Keep package operations, warnings and problems; drop downloads, autoload
+/// generation, script output and funding notices.
+fn filter_composer_install(subcommand: &str, output: &str, success: bool) -> String {
+    let mut summary: Option<String> = None;
+    let mut installed: Vec<String> = Vec::new();
+    let mut upgraded: Vec<String> = Vec::new();
+    let mut removed: Vec<String> = Vec::new();
+    let mut other: Vec<&str> = Vec::new();
+    let mut nothing_to_do = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(caps) = OPERATIONS.captures(trimmed) {
+            // Package operations come after lock file operations and win
+            summary = Some(caps[1].to_string());
+            continue;
+        }
+        if let Some(caps) = OPERATION.captures(trimmed) {
+            let entry = format!("{} {}", &caps[2], caps[3].replace(" => ", " → "));
+            let list = match &caps[1] {
+                "Installing" => &mut installed,
+                "Removing" => &mut removed,
+                _ => &mut upgraded,
+            };
+            if !list.contains(&entry) {
+                list.push(entry);
+            }
+            continue;
+        }
+        if trimmed.starts_with("Nothing to install, update or remove") {
+            nothing_to_do = true;
+            continue;
+        }
+        if trimmed.starts_with("- Downloading ")
+            || trimmed.starts_with("- Locking ")
+            || trimmed.starts_with("Installing dependencies")
+            || trimmed.starts_with("Verifying lock file")
+            || trimmed.starts_with("Loading composer repositories")
+            || trimmed.starts_with("Updating dependencies")
+            || trimmed.starts_with("Writing lock file")
+            || trimmed.starts_with("Generating autoload files")
+            || trimmed.starts_with("Generating optimized autoload files")
+            || trimmed.starts_with("Generated ")
+            || trimmed.starts_with("> ")
+            || trimmed.starts_with("INFO ")
+            || trimmed.contains("packages you are using are looking for funding")
+            || trimmed.starts_with("Use the `composer fund` command")
+            || trimmed.starts_with("No security vulnerability advisories found")
+            || trimmed.starts_with("./composer.json has been updated")
+            || trimmed.starts_with("Running composer update")
+            || DISCOVERED.is_match(trimmed)
+        {
+            continue;
+        }
+        other.push(trimmed);
+    }
+
+    let mut lines = Vec::new();
+    let status = if success { "✓" } else { "❌" };
+    match (&summary, nothing_to_do) {
+        (Some(summary), _) => {
+            lines.push(format!("{} composer {}: {}", status, subcommand, summary))
+        }
+        (None, true) => lines.push(format!(
+            "{} composer {}: nothing to install, update or remove",
+            status, subcommand
+        )),
+        (None, false) => lines.push(format!(
+            "{} composer {}{}",
+            status,
+            subcommand,
+            if success { "" } else { " failed" }
+        )),
+    }
+
+    for (marker, list) in [("+", &installed), ("↑", &upgraded), ("-", &removed)] {
+        if list.is_empty() {
+            continue;
+        }
+        let shown: Vec<&str> = list
+            .iter()
+            .take(MAX_PACKAGES_SHOWN)
+            .map(|s| s.as_str())
+            .collect();
+        let mut entry = format!("  {} {}", marker, shown.join(", "));
+        if list.len() > MAX_PACKAGES_SHOWN {
+            entry.push_str(&format!(" ... +{} more", list.len() - MAX_PACKAGES_SHOWN));
+        }
+        lines.push(entry);
+    }
+
+    for line in other.iter().take(MAX_OTHER_LINES) {
+        lines.push(format!("  {}", line));
+    }
+    if other.len() > MAX_OTHER_LINES {
+        lines.push(format!(
+            "  ... +{} more lines",
+            other.len() - MAX_OTHER_LINES
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("composer");
+    cmd.arg("outdated");
+    if !args.iter().any(|a| a.starts_with("--format") || a == "-f") {
+        cmd.arg("--format=json");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!(
+            "Running: composer outdated --format=json {}",
+            args.join(" ")
+        );
+    }
+
+    let output = cmd.output().context("Failed to run composer outdated")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = match ComposerOutdatedParser::parse(&stdout) {
+        ParseResult::Full(data) => format_outdated(&data, &stdout, mode),
+        ParseResult::Degraded(data, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("composer outdated", &warnings.join(", "));
+            }
+            data.format(mode)
+        }
+        ParseResult::Passthrough(truncated) => {
+            emit_passthrough_warning("composer outdated", "All parsing tiers failed");
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("composer outdated {}", args.join(" ")),
+        &format!("rtk composer outdated {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Dependency summary plus abandoned packages, which JSON output flags separately.
+fn format_outdated(data: &DependencyState, json: &str, mode: FormatMode) -> String {
+    let mut out = data.format(mode);
+    let abandoned: Vec<String> = serde_json::from_str::<ComposerOutdated>(json)
+        .map(|o| o.installed)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|p| match p.abandoned {
+            serde_json::Value::String(replacement) => {
+                Some(format!("{} (use {})", p.name, replacement))
+            }
+            serde_json::Value::Bool(true) => Some(p.name),
+            _ => None,
+        })
+        .collect();
+    if !abandoned.is_empty() {
+        out.push_str(&format!("\n⚠ abandoned: {}", abandoned.join(", ")));
+    }
+    out
+}
+
+/// Parser for `composer outdated --format=json` (text table as fallback)
+pub struct ComposerOutdatedParser;
+
+impl OutputParser for ComposerOutdatedParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        match serde_json::from_str::<ComposerOutdated>(input.trim()) {
+            Ok(json) => {
+                let dependencies: Vec<Dependency> = json
+                    .installed
+                    .into_iter()
+                    .filter(|p| p.latest_status.as_deref() != Some("up-to-date"))
+                    .filter(|p| p.latest.as_deref().is_some_and(|l| l != p.version))
+                    .map(|p| Dependency {
+                        name: p.name,
+                        current_version: p.version,
+                        latest_version: p.latest,
+                        wanted_version: None,
+                        dev_dependency: false,
+                    })
+                    .collect();
+                ParseResult::Full(DependencyState {
+                    total_packages: dependencies.len(),
+                    outdated_count: dependencies.len(),
+                    dependencies,
+                })
+            }
+            Err(e) => {
+                let dependencies: Vec<Dependency> = input
+                    .lines()
+                    .filter_map(|l| OUTDATED_ROW.captures(l.trim()))
+                    .map(|caps| Dependency {
+                        name: caps[1].to_string(),
+                        current_version: caps[2].to_string(),
+                        latest_version: Some(caps[3].to_string()),
+                        wanted_version: None,
+                        dev_dependency: false,
+                    })
+                    .collect();
+                if dependencies.is_empty() {
+                    return ParseResult::Passthrough(truncate_output(input, 500));
+                }
+                ParseResult::Degraded(
+                    DependencyState {
+                        total_packages: dependencies.len(),
+                        outdated_count: dependencies.len(),
+                        dependencies,
+                    },
+                    vec![format!("JSON parse failed: {}", e)],
+                )
+            }
+        }
+    }
+}
+
+fn run_passthrough(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: composer {}", args.join(" "));
+    }
+
+    let status = Command::new("composer")
+        .args(args)
+        .status()
+        .context("Failed to run composer")?;
+
+    timer.track_passthrough(
+        &format!("composer {}", args.join(" ")),
+        &format!("rtk composer {} (passthrough)", args.join(" ")),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_composer_install() {
+        let input = include_str!("../tests/fixtures/composer_install.txt");
+        assert_eq!(
+            filter_composer_install("install", input, true),
+            "\
+✓ composer install: 3 installs, 1 update, 1 removal
+  + psr/log 3.0.0, symfony/console v6.4.3, monolog/monolog 3.5.0
+  ↑ laravel/framework v10.48.2 → v10.48.4
+  - fakerphp/faker v1.23.0
+  Package swiftmailer/swiftmailer is abandoned, you should avoid using it. Use symfony/mailer instead."
+        );
+    }
+
+    #[test]
+    fn test_filter_composer_install_problems() {
+        let input = "\
+Loading composer repositories with package information
+Updating dependencies
+Your requirements could not be resolved to an installable set of packages.
+
+  Problem 1
+    - Root composer.json requires laravel/framework ^12.0, found laravel/framework[v10.0.0, ..., v11.0.7] but it does not match the constraint.
+";
+        let result = filter_composer_install("update", input, false);
+        assert!(result.starts_with("❌ composer update failed\n"));
+        assert!(result.contains("Problem 1"));
+        assert!(result.contains("Root composer.json requires laravel/framework ^12.0"));
+        assert!(!result.contains("Loading composer repositories"));
+    }
+
+    #[test]
+    fn test_composer_outdated_json() {
+        let input = include_str!("../tests/fixtures/composer_outdated.json");
+        let data = ComposerOutdatedParser::parse(input).unwrap();
+        assert_eq!(
+            format_outdated(&data, input, FormatMode::Compact),
+            "\
+2 outdated packages (of 2)
+laravel/framework: v10.48.4 → v11.0.7
+monolog/monolog: 3.5.0 → 3.6.0
+⚠ abandoned: swiftmailer/swiftmailer (use symfony/mailer)"
+        );
+    }
+}
@@ -62,6 +62,14 @@ pub fn run(path: &Path, verbose: u8) -> Result<()> {
         rtk.push_str(&summarize_gomod_str(&gomod_path)?);
     }
 
+    let composer_path = dir.join("composer.json");
+    if composer_path.exists() {
+        found = true;
+        raw.push_str(&fs::read_to_string(&composer_path).unwrap_or_default());
+        rtk.push_str("📦 PHP (composer.json):\n");
+        rtk.push_str(&summarize_composer_json_str(&composer_path)?);
+    }
+
     if !found {
         rtk.push_str(&format!("No dependency files found in {}", dir.display()));
     }
@@ -266,3 +274,50 @@ fn summarize_gomod_str(path: &Path) -> Result<String> {
     }
     Ok(out)
 }
+
+fn summarize_composer_json_str(path: &Path) -> Result<String> {
+    let content = fs::read_to_string(path)?;
+    let json: serde_json::Value = serde_json::from_str(&content)?;
+    let mut out = String::new();
+
+    if let Some(name) = json.get("name").and_then(|v| v.as_str()) {
+        out.push_str(&format!("  {}\n", name));
+    }
+    if let Some(require) = json.get("require").and_then(|v| v.as_object()) {
+        // php and ext-* are platform requirements, not packages
+        let (platform, deps): (Vec<_>, Vec<_>) = require
+            .iter()
+            .partition(|(name, _)| *name == "php" || name.starts_with("ext-"));
+        if !platform.is_empty() {
+            let platform: Vec<String> = platform
+                .iter()
+                .map(|(name, version)| format!("{} {}", name, version.as_str().unwrap_or("*")))
+                .collect();
+            out.push_str(&format!("  Platform: {}\n", platform.join(", ")));
+        }
+        if !deps.is_empty() {
+            out.push_str(&format!("  Dependencies ({}):\n", deps.len()));
+            for (name, version) in deps.iter().take(10) {
+                out.push_str(&format!(
+                    "    {} ({})\n",
+                    name,
+                    version.as_str().unwrap_or("*")
+                ));
+            }
+            if deps.len() > 10 {
+                out.push_str(&format!("    ... +{} more\n", deps.len() - 10));
+            }
+        }
+    }
+    if let Some(dev_deps) = json.get("require-dev").and_then(|v| v.as_object()) {
+        out.push_str(&format!("  Dev Dependencies ({}):\n", dev_deps.len()));
+        for (i, (name, _)) in dev_deps.iter().enumerate() {
+            if i >= 5 {
+                out.push_str(&format!("    ... +{} more\n", dev_deps.len() - 5));
+                break;
+            }
+            out.push_str(&format!("    {}\n", name));
+        }
+    }
+    Ok(out)
+}
@@ -1,3 +1,4 @@
+use crate::lockfile_diff::{self, SpecialFile};
 use crate::tracking;
 use crate::utils::truncate;
 use anyhow::Result;
@@ -21,7 +22,7 @@ pub fn run(file1: &Path, file2: &Path, verbose: u8) -> Result<()> {
     let diff = compute_diff(&lines1, &lines2);
     let mut rtk = String::new();
 
-    if diff.added == 0 && diff.removed == 0 {
+    if diff.is_identical() {
         rtk.push_str("✅ Files are identical");
         println!("{}", rtk);
         timer.track(
@@ -33,6 +34,23 @@ pub fn run(file1: &Path, file2: &Path, verbose: u8) -> Result<()> {
         return Ok(());
     }
 
+    let special = lockfile_diff::classify_path(&file2.to_string_lossy())
+        .or_else(|| lockfile_diff::classify_path(&file1.to_string_lossy()));
+    if let Some(kind) = special {
+        rtk.push_str(&format!("📊 {} → {}\n", file1.display(), file2.display()));
+        for line in lockfile_diff::summarize_contents(kind, &content1, &content2) {
+            rtk.push_str(&format!("   {}\n", line));
+        }
+        print!("{}", rtk);
+        timer.track(
+            &format!("diff {} {}", file1.display(), file2.display()),
+            "rtk diff",
+            &raw,
+            &rtk,
+        );
+        return Ok(());
+    }
+
     rtk.push_str(&format!("📊 {} → {}\n", file1.display(), file2.display()));
     rtk.push_str(&format!(
         "   +{} added, -{} removed, ~{} modified\n\n",
@@ -96,6 +114,13 @@ struct DiffResult {
     changes: Vec<DiffChange>,
 }
 
+impl DiffResult {
+    /// Lines changed in place count as modified, not added/removed
+    fn is_identical(&self) -> bool {
+        self.added == 0 && self.removed == 0 && self.modified == 0
+    }
+}
+
 fn compute_diff(lines1: &[&str], lines2: &[&str]) -> DiffResult {
     let mut changes = Vec::new();
     let mut added = 0;
@@ -162,55 +187,89 @@ fn condense_unified_diff(diff: &str) -> String {
     let mut added = 0;
     let mut removed = 0;
     let mut changes = Vec::new();
+    let mut special: Option<SpecialFile> = None;
+    let mut special_lines: Vec<&str> = Vec::new();
 
     for line in diff.lines() {
         if line.starts_with("diff --git") || line.starts_with("--- ") || line.starts_with("+++ ") {
             // File header
             if line.starts_with("+++ ") {
-                if !current_file.is_empty() && (added > 0 || removed > 0) {
-                    result.push(format!("📄 {} (+{} -{})", current_file, added, removed));
-                    for c in changes.iter().take(10) {
-                        result.push(format!("  {}", c));
-                    }
-                    if changes.len() > 10 {
-                        result.push(format!("  ... +{} more", changes.len() - 10));
-                    }
-                }
+                flush_file(
+                    &mut result,
+                    &current_file,
+                    added,
+                    removed,
+                    &changes,
+                    special.map(|k| (k, special_lines.as_slice())),
+                );
                 current_file = line
                     .trim_start_matches("+++ ")
                     .trim_start_matches("b/")
                     .to_string();
+                special = lockfile_diff::classify_path(&current_file);
+                special_lines.clear();
                 added = 0;
                 removed = 0;
                 changes.clear();
             }
         } else if line.starts_with('+') && !line.starts_with("+++") {
             added += 1;
-            if changes.len() < 15 {
+            if special.is_some() {
+                special_lines.push(line);
+            } else if changes.len() < 15 {
                 changes.push(truncate(line, 70));
             }
         } else if line.starts_with('-') && !line.starts_with("---") {
             removed += 1;
-            if changes.len() < 15 {
+            if special.is_some() {
+                special_lines.push(line);
+            } else if changes.len() < 15 {
                 changes.push(truncate(line, 70));
             }
+        } else if line.starts_with(' ') && special.is_some() {
+            special_lines.push(line);
         }
     }
 
     // Last file
-    if !current_file.is_empty() && (added > 0 || removed > 0) {
-        result.push(format!("📄 {} (+{} -{})", current_file, added, removed));
-        for c in changes.iter().take(10) {
-            result.push(format!("  {}", c));
-        }
-        if changes.len() > 10 {
-            result.push(format!("  ... +{} more", changes.len() - 10));
-        }
-    }
+    flush_file(
+        &mut result,
+        &current_file,
+        added,
+        removed,
+        &changes,
+        special.map(|k| (k, special_lines.as_slice())),
+    );
 
     result.join("\n")
 }
 
+fn flush_file(
+    result: &mut Vec<String>,
+    file: &str,
+    added: usize,
+    removed: usize,
+    changes: &[String],
+    special: Option<(SpecialFile, &[&str])>,
+) {
+    if file.is_empty() || (added == 0 && removed == 0) {
+        return;
+    }
+    result.push(format!("📄 {} (+{} -{})", file, added, removed));
+    if let Some((kind, lines)) = special {
+        for l in lockfile_diff::summarize_hunks(kind, lines) {
+            result.push(format!("  {}", l));
+        }
+        return;
+    }
+    for c in changes.iter().take(10) {
+        result.push(format!("  {}", c));
+    }
+    if changes.len() > 10 {
+        result.push(format!("  ... +{} more", changes.len() - 10));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,6 +307,14 @@ mod tests {
 
     // --- truncate ---
 
+    #[test]
+    fn test_modified_only_is_not_identical() {
+        let diff = compute_diff(&["let x = 1;"], &["let x = 2;"]);
+        assert_eq!((diff.added, diff.removed, diff.modified), (0, 0, 1));
+        assert!(!diff.is_identical());
+        assert!(compute_diff(&["a", "b"], &["a", "b"]).is_identical());
+    }
+
     #[test]
     fn test_truncate_short_string() {
         assert_eq!(truncate("hello", 10), "hello");
@@ -343,6 +410,22 @@ mod tests {
         assert!(result.contains("println"));
     }
 
+    #[test]
+    fn test_condense_unified_diff_lockfile() {
+        let diff = r#"diff --git a/go.sum b/go.sum
+--- a/go.sum
++++ b/go.sum
+@@ -1,2 +1,2 @@
+-github.com/pkg/errors v0.9.0 h1:aaa=
++github.com/pkg/errors v0.9.1 h1:bbb=
+ golang.org/x/text v0.3.0 h1:ccc=
+"#;
+        let result = condense_unified_diff(diff);
+        assert!(result.contains("📄 go.sum (+1 -1)"));
+        assert!(result.contains("~ github.com/pkg/errors v0.9.0 → v0.9.1"));
+        assert!(!result.contains("h1:"));
+    }
+
     #[test]
     fn test_condense_unified_diff_multiple_files() {
         let diff = r#"diff --git a/a.rs b/a.rs
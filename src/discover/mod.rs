@@ -1,6 +1,7 @@
 pub mod provider;
 pub mod registry;
 mod report;
+mod timeline;
 
 use anyhow::Result;
 use std::collections::HashMap;
@@ -26,12 +27,15 @@ struct UnsupportedBucket {
     example: String,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     project: Option<&str>,
     all: bool,
     since_days: u64,
     limit: usize,
     format: &str,
+    session: Option<&str>,
+    timeline: bool,
     verbose: u8,
 ) -> Result<()> {
     let provider = ClaudeProvider;
@@ -49,7 +53,22 @@ pub fn run(
         Some(encoded)
     };
 
-    let sessions = provider.discover_sessions(project_filter.as_deref(), Some(since_days))?;
+    let sessions = match session {
+        Some(id) => {
+            // An explicit id is unique across projects; only `latest` is scoped to the project
+            let filter = if id == "latest" {
+                project_filter.as_deref()
+            } else {
+                project
+            };
+            vec![provider.find_session(filter, id)?]
+        }
+        None => provider.discover_sessions(project_filter.as_deref(), Some(since_days))?,
+    };
+
+    if timeline {
+        return run_timeline(&provider, &sessions[0], format, verbose);
+    }
 
     if verbose > 0 {
         eprintln!("Scanning {} session files...", sessions.len());
@@ -215,6 +234,32 @@ pub fn run(
     Ok(())
 }
 
+/// Print the per-call token timeline of a single session.
+fn run_timeline(
+    provider: &ClaudeProvider,
+    session_path: &std::path::Path,
+    format: &str,
+    verbose: u8,
+) -> Result<()> {
+    if verbose > 0 {
+        eprintln!("Session: {}", session_path.display());
+    }
+
+    let session_id = session_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown");
+    let commands = provider.extract_commands(session_path)?;
+    let timeline = timeline::build_timeline(session_id, commands);
+
+    match format {
+        "json" => println!("{}", timeline::format_json(&timeline)),
+        _ => print!("{}", timeline::format_text(&timeline)),
+    }
+
+    Ok(())
+}
+
 /// Extract the subcommand from a command string (second word).
 fn extract_subcmd(cmd: &str) -> &str {
     let parts: Vec<&str> = cmd.trim().splitn(3, char::is_whitespace).collect();
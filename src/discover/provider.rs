@@ -29,6 +29,38 @@ pub trait SessionProvider {
         since_days: Option<u64>,
     ) -> Result<Vec<PathBuf>>;
     fn extract_commands(&self, path: &Path) -> Result<Vec<ExtractedCommand>>;
+
+    /// Resolve a single session file by id (file stem, prefix match allowed) or `latest`.
+    fn find_session(&self, project_filter: Option<&str>, id: &str) -> Result<PathBuf> {
+        let sessions = self.discover_sessions(project_filter, None)?;
+
+        if id == "latest" {
+            return sessions
+                .into_iter()
+                .filter_map(|p| {
+                    let mtime = fs::metadata(&p).and_then(|m| m.modified()).ok()?;
+                    Some((mtime, p))
+                })
+                .max_by_key(|(mtime, _)| *mtime)
+                .map(|(_, p)| p)
+                .context("no session files found");
+        }
+
+        let mut matches: Vec<PathBuf> = sessions
+            .into_iter()
+            .filter(|p| {
+                p.file_stem()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|stem| stem.starts_with(id))
+            })
+            .collect();
+
+        match matches.len() {
+            0 => anyhow::bail!("session not found: {}", id),
+            1 => Ok(matches.remove(0)),
+            n => anyhow::bail!("session id '{}' is ambiguous ({} matches)", id, n),
+        }
+    }
 }
 
 pub struct ClaudeProvider;
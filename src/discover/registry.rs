@@ -51,9 +51,14 @@ pub fn category_avg_tokens(category: &str, subcmd: &str) -> usize {
 const PATTERNS: &[&str] = &[
     r"^git\s+(status|log|diff|show|add|commit|push|pull|branch|fetch|stash|worktree)",
     r"^gh\s+(pr|issue|run|repo|api)",
-    r"^cargo\s+(build|test|clippy|check|fmt)",
+    r"^cargo\s+(build|test|clippy|check|fmt|nextest|bench|doc|tree|udeps)",
     r"^pnpm\s+(list|ls|outdated|install)",
     r"^npm\s+(run|exec)",
+    r"^yarn\s+(install|add|outdated|why|list|run)",
+    r"^bun\s+(install|add|outdated|why|run|test|pm\s+ls)",
+    r"^uv\s+(sync|lock|add|run|tree)",
+    r"^poetry\s+(install|lock|show|run)",
+    r"^((npm|pnpm|cargo)\s+audit|pip-audit)(\s|$)",
     r"^npx\s+",
     r"^(cat|head|tail)\s+",
     r"^(rg|grep)\s+",
@@ -68,6 +73,21 @@ const PATTERNS: &[&str] = &[
     r"^(npx\s+|pnpm\s+)?prisma",
     r"^docker\s+(ps|images|logs)",
     r"^kubectl\s+(get|logs)",
+    r"^terraform\s+(plan|apply|validate|init)",
+    r"^tofu\s+(plan|apply|validate|init)",
+    r"^make(\s|$)",
+    r"^cmake\s+--build",
+    r"^ninja(\s|$)",
+    r"^(mvn|\./mvnw)(\s|$)",
+    r"^(gradle|\./gradlew)(\s|$)",
+    r"^dotnet\s+(build|test|restore)",
+    r"^(bundle\s+exec\s+)?rspec(\s|$)",
+    r"^(bundle\s+exec\s+)?rake\s+test",
+    r"^(bundle\s+exec\s+)?rubocop(\s|$)",
+    r"^bundle\s+(install|update|outdated)",
+    r"^(vendor/bin/)?phpunit(\s|$)",
+    r"^(vendor/bin/)?phpstan(\s|$)",
+    r"^composer\s+(install|update|outdated|require|remove)",
     r"^curl\s+",
     r"^wget\s+",
 ];
@@ -96,7 +116,12 @@ const RULES: &[RtkRule] = &[
         rtk_cmd: "rtk cargo",
         category: "Cargo",
         savings_pct: 80.0,
-        subcmd_savings: &[("test", 90.0), ("check", 80.0)],
+        subcmd_savings: &[
+            ("test", 90.0),
+            ("nextest", 90.0),
+            ("check", 80.0),
+            ("tree", 70.0),
+        ],
         subcmd_status: &[("fmt", super::report::RtkStatus::Passthrough)],
     },
     RtkRule {
@@ -113,6 +138,41 @@ const RULES: &[RtkRule] = &[
         subcmd_savings: &[],
         subcmd_status: &[],
     },
+    RtkRule {
+        rtk_cmd: "rtk yarn",
+        category: "PackageManager",
+        savings_pct: 80.0,
+        subcmd_savings: &[("run", 70.0)],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk bun",
+        category: "PackageManager",
+        savings_pct: 80.0,
+        subcmd_savings: &[("test", 90.0), ("run", 70.0)],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk uv",
+        category: "PackageManager",
+        savings_pct: 80.0,
+        subcmd_savings: &[("run", 70.0)],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk poetry",
+        category: "PackageManager",
+        savings_pct: 80.0,
+        subcmd_savings: &[("run", 70.0)],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk audit",
+        category: "PackageManager",
+        savings_pct: 85.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
     RtkRule {
         rtk_cmd: "rtk npx",
         category: "PackageManager",
@@ -211,6 +271,111 @@ const RULES: &[RtkRule] = &[
         subcmd_savings: &[],
         subcmd_status: &[],
     },
+    RtkRule {
+        rtk_cmd: "rtk terraform",
+        category: "Infra",
+        savings_pct: 80.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk tofu",
+        category: "Infra",
+        savings_pct: 80.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk make",
+        category: "Build",
+        savings_pct: 75.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk cmake",
+        category: "Build",
+        savings_pct: 75.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk ninja",
+        category: "Build",
+        savings_pct: 75.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk mvn",
+        category: "Build",
+        savings_pct: 80.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk gradle",
+        category: "Build",
+        savings_pct: 80.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk dotnet",
+        category: "Build",
+        savings_pct: 80.0,
+        subcmd_savings: &[("test", 90.0)],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk rspec",
+        category: "Tests",
+        savings_pct: 90.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk rake",
+        category: "Tests",
+        savings_pct: 85.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk rubocop",
+        category: "Build",
+        savings_pct: 80.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk bundle",
+        category: "PackageManager",
+        savings_pct: 75.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk phpunit",
+        category: "Tests",
+        savings_pct: 90.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk phpstan",
+        category: "Build",
+        savings_pct: 80.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
+    RtkRule {
+        rtk_cmd: "rtk composer",
+        category: "PackageManager",
+        savings_pct: 75.0,
+        subcmd_savings: &[],
+        subcmd_status: &[],
+    },
     RtkRule {
         rtk_cmd: "rtk curl",
         category: "Network",
@@ -575,15 +740,139 @@ mod tests {
     }
 
     #[test]
-    fn test_classify_terraform_unsupported() {
-        match classify_command("terraform plan -var-file=prod.tfvars") {
+    fn test_classify_helm_unsupported() {
+        match classify_command("helm upgrade api ./chart -f prod.yaml") {
             Classification::Unsupported { base_command } => {
-                assert_eq!(base_command, "terraform plan");
+                assert_eq!(base_command, "helm upgrade");
             }
             other => panic!("expected Unsupported, got {:?}", other),
         }
     }
 
+    #[test]
+    fn test_classify_terraform() {
+        assert_eq!(
+            classify_command("terraform plan -var-file=prod.tfvars"),
+            Classification::Supported {
+                rtk_equivalent: "rtk terraform",
+                category: "Infra",
+                estimated_savings_pct: 80.0,
+                status: RtkStatus::Existing,
+            }
+        );
+        assert_eq!(
+            classify_command("tofu apply -auto-approve"),
+            Classification::Supported {
+                rtk_equivalent: "rtk tofu",
+                category: "Infra",
+                estimated_savings_pct: 80.0,
+                status: RtkStatus::Existing,
+            }
+        );
+    }
+
+    #[test]
+    fn test_classify_build_tools() {
+        for (cmd, rtk) in [
+            ("make -j8", "rtk make"),
+            ("cmake --build build", "rtk cmake"),
+            ("ninja -C out", "rtk ninja"),
+            ("mvn -q test", "rtk mvn"),
+            ("./gradlew build", "rtk gradle"),
+            ("dotnet test --no-build", "rtk dotnet"),
+        ] {
+            match classify_command(cmd) {
+                Classification::Supported { rtk_equivalent, .. } => assert_eq!(rtk_equivalent, rtk),
+                other => panic!("expected Supported for {}, got {:?}", cmd, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_classify_js_package_managers() {
+        for (cmd, rtk) in [
+            ("yarn install --frozen-lockfile", "rtk yarn"),
+            ("yarn outdated", "rtk yarn"),
+            ("yarn why lodash", "rtk yarn"),
+            ("bun install", "rtk bun"),
+            ("bun test src/", "rtk bun"),
+            ("bun pm ls", "rtk bun"),
+        ] {
+            match classify_command(cmd) {
+                Classification::Supported { rtk_equivalent, .. } => assert_eq!(rtk_equivalent, rtk),
+                other => panic!("expected Supported for {}, got {:?}", cmd, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_classify_python_package_managers() {
+        for (cmd, rtk) in [
+            ("uv sync --frozen", "rtk uv"),
+            ("uv lock --upgrade-package requests", "rtk uv"),
+            ("uv run pytest -x", "rtk uv"),
+            ("uv tree", "rtk uv"),
+            ("poetry install", "rtk poetry"),
+            ("poetry show --tree", "rtk poetry"),
+            ("poetry run mypy src", "rtk poetry"),
+        ] {
+            match classify_command(cmd) {
+                Classification::Supported { rtk_equivalent, .. } => assert_eq!(rtk_equivalent, rtk),
+                other => panic!("expected Supported for {}, got {:?}", cmd, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_classify_audits() {
+        for cmd in [
+            "npm audit --omit=dev",
+            "pnpm audit",
+            "cargo audit",
+            "pip-audit -r requirements.txt",
+        ] {
+            match classify_command(cmd) {
+                Classification::Supported { rtk_equivalent, .. } => {
+                    assert_eq!(rtk_equivalent, "rtk audit")
+                }
+                other => panic!("expected Supported for {}, got {:?}", cmd, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_classify_ruby_tools() {
+        for (cmd, rtk) in [
+            ("bundle exec rspec spec/models", "rtk rspec"),
+            ("rspec", "rtk rspec"),
+            ("bundle exec rake test", "rtk rake"),
+            ("rubocop -a app", "rtk rubocop"),
+            ("bundle install", "rtk bundle"),
+            ("bundle outdated", "rtk bundle"),
+        ] {
+            match classify_command(cmd) {
+                Classification::Supported { rtk_equivalent, .. } => assert_eq!(rtk_equivalent, rtk),
+                other => panic!("expected Supported for {}, got {:?}", cmd, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_classify_php_tools() {
+        for (cmd, rtk) in [
+            ("vendor/bin/phpunit --filter UserTest", "rtk phpunit"),
+            ("phpunit", "rtk phpunit"),
+            ("vendor/bin/phpstan analyse src", "rtk phpstan"),
+            ("composer install", "rtk composer"),
+            ("composer outdated --direct", "rtk composer"),
+        ] {
+            match classify_command(cmd) {
+                Classification::Supported { rtk_equivalent, .. } => assert_eq!(rtk_equivalent, rtk),
+                other => panic!("expected Supported for {}, got {:?}", cmd, other),
+            }
+        }
+    }
+
     #[test]
     fn test_classify_env_prefix_stripped() {
         assert_eq!(
@@ -673,9 +962,11 @@ mod tests {
 
     #[test]
     fn test_registry_covers_all_cargo_subcommands() {
-        // Verify that every CargoCommand variant (Build, Test, Clippy, Check, Fmt)
-        // except Other has a matching pattern in the registry
-        for subcmd in ["build", "test", "clippy", "check", "fmt"] {
+        // Verify that every CargoCommand variant except Other has a matching
+        // pattern in the registry
+        for subcmd in [
+            "build", "test", "clippy", "check", "fmt", "nextest", "bench", "doc", "tree", "udeps",
+        ] {
             let cmd = format!("cargo {subcmd}");
             match classify_command(&cmd) {
                 Classification::Supported { .. } => {}
@@ -0,0 +1,288 @@
+use serde::Serialize;
+
+use super::provider::ExtractedCommand;
+use super::registry::{classify_command, split_command_chain, Classification};
+
+/// Number of heaviest tool calls flagged as top offenders.
+const TOP_OFFENDERS: usize = 5;
+
+/// One Bash tool call in a session, in chronological order.
+#[derive(Debug, Serialize)]
+pub struct TimelineEntry {
+    pub sequence_index: usize,
+    pub command: String,
+    /// Estimated tokens of the tool_result (None if no result was recorded)
+    pub output_tokens: Option<usize>,
+    pub rtk_used: bool,
+    pub rtk_equivalent: Option<&'static str>,
+    /// Estimated tokens had the rtk equivalent been used
+    pub estimated_rtk_tokens: usize,
+    pub cumulative_tokens: usize,
+    pub cumulative_rtk_tokens: usize,
+    pub top_offender: bool,
+}
+
+/// Per-session token timeline.
+#[derive(Debug, Serialize)]
+pub struct SessionTimeline {
+    pub session_id: String,
+    pub entries: Vec<TimelineEntry>,
+    pub total_tokens: usize,
+    pub total_rtk_tokens: usize,
+    /// Sequence indices of the heaviest calls, largest first
+    pub top_offenders: Vec<usize>,
+}
+
+/// Build a timeline from the commands of a single session.
+pub fn build_timeline(session_id: &str, mut commands: Vec<ExtractedCommand>) -> SessionTimeline {
+    commands.sort_by_key(|c| c.sequence_index);
+
+    let mut entries = Vec::with_capacity(commands.len());
+    let mut cumulative = 0;
+    let mut cumulative_rtk = 0;
+
+    for cmd in commands {
+        let output_tokens = cmd.output_len.map(|len| len.div_ceil(4));
+        let tokens = output_tokens.unwrap_or(0);
+
+        let mut rtk_used = false;
+        let mut supported: Option<(&'static str, f64)> = None;
+        for part in split_command_chain(&cmd.command) {
+            if part.trim().starts_with("rtk ") {
+                rtk_used = true;
+            }
+            if let Classification::Supported {
+                rtk_equivalent,
+                estimated_savings_pct,
+                ..
+            } = classify_command(part)
+            {
+                supported.get_or_insert((rtk_equivalent, estimated_savings_pct));
+            }
+        }
+
+        let estimated_rtk_tokens = match supported {
+            Some((_, pct)) if !rtk_used => (tokens as f64 * (100.0 - pct) / 100.0) as usize,
+            _ => tokens,
+        };
+
+        cumulative += tokens;
+        cumulative_rtk += estimated_rtk_tokens;
+
+        entries.push(TimelineEntry {
+            sequence_index: cmd.sequence_index,
+            command: cmd.command,
+            output_tokens,
+            rtk_used,
+            rtk_equivalent: supported.map(|(eq, _)| eq),
+            estimated_rtk_tokens,
+            cumulative_tokens: cumulative,
+            cumulative_rtk_tokens: cumulative_rtk,
+            top_offender: false,
+        });
+    }
+
+    let mut ranked: Vec<(usize, usize)> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| e.output_tokens.filter(|t| *t > 0).map(|t| (i, t)))
+        .collect();
+    ranked.sort_by_key(|(_, t)| std::cmp::Reverse(*t));
+    ranked.truncate(TOP_OFFENDERS);
+
+    let top_offenders = ranked
+        .iter()
+        .map(|(i, _)| {
+            entries[*i].top_offender = true;
+            entries[*i].sequence_index
+        })
+        .collect();
+
+    SessionTimeline {
+        session_id: session_id.to_string(),
+        entries,
+        total_tokens: cumulative,
+        total_rtk_tokens: cumulative_rtk,
+        top_offenders,
+    }
+}
+
+/// Format timeline as a text table.
+pub fn format_text(timeline: &SessionTimeline) -> String {
+    let mut out = String::with_capacity(4096);
+
+    out.push_str(&format!(
+        "RTK Session Timeline -- {}\n",
+        timeline.session_id
+    ));
+    out.push_str(&"=".repeat(88));
+    out.push('\n');
+
+    if timeline.entries.is_empty() {
+        out.push_str("No Bash tool calls in this session.\n");
+        return out;
+    }
+
+    out.push_str(&format!(
+        "{:>4}  {:<36} {:>8} {:>4} {:>8} {:>10} {:>10}\n",
+        "#", "Command", "Output", "rtk", "w/ rtk", "Cumul.", "Cumul.rtk"
+    ));
+    out.push_str(&"-".repeat(88));
+    out.push('\n');
+
+    for entry in &timeline.entries {
+        let output = entry
+            .output_tokens
+            .map(|t| t.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        out.push_str(&format!(
+            "{:>4}  {:<36} {:>8} {:>4} {:>8} {:>10} {:>10}{}\n",
+            entry.sequence_index,
+            truncate_command(&entry.command, 36),
+            output,
+            if entry.rtk_used { "yes" } else { "no" },
+            entry.estimated_rtk_tokens,
+            entry.cumulative_tokens,
+            entry.cumulative_rtk_tokens,
+            if entry.top_offender { "  <-- top" } else { "" },
+        ));
+    }
+
+    out.push_str(&"-".repeat(88));
+    out.push('\n');
+    let saved = timeline
+        .total_tokens
+        .saturating_sub(timeline.total_rtk_tokens);
+    out.push_str(&format!(
+        "Total: {} calls, ~{} tokens (~{} with rtk, {} saveable)\n",
+        timeline.entries.len(),
+        timeline.total_tokens,
+        timeline.total_rtk_tokens,
+        saved,
+    ));
+
+    if !timeline.top_offenders.is_empty() {
+        out.push_str("\nTOP OFFENDERS\n");
+        for seq in &timeline.top_offenders {
+            if let Some(entry) = timeline.entries.iter().find(|e| e.sequence_index == *seq) {
+                let pct = (entry.output_tokens.unwrap_or(0) * 100)
+                    .checked_div(timeline.total_tokens)
+                    .unwrap_or(0);
+                let hint = match (entry.rtk_used, entry.rtk_equivalent) {
+                    (false, Some(eq)) => format!(" -> {}", eq),
+                    _ => String::new(),
+                };
+                out.push_str(&format!(
+                    "  #{:<4} ~{} tokens ({}%)  {}{}\n",
+                    entry.sequence_index,
+                    entry.output_tokens.unwrap_or(0),
+                    pct,
+                    truncate_command(&entry.command, 50),
+                    hint,
+                ));
+            }
+        }
+    }
+
+    out.push_str("\n~estimated from tool_result output sizes\n");
+    out
+}
+
+/// Format timeline as JSON.
+pub fn format_json(timeline: &SessionTimeline) -> String {
+    serde_json::to_string_pretty(timeline).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// First line of a command, truncated for table display.
+fn truncate_command(cmd: &str, max: usize) -> String {
+    let first_line = cmd.trim().lines().next().unwrap_or("");
+    if first_line.chars().count() <= max {
+        first_line.to_string()
+    } else {
+        let truncated: String = first_line.chars().take(max.saturating_sub(2)).collect();
+        format!("{}..", truncated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmd(seq: usize, command: &str, output_len: Option<usize>) -> ExtractedCommand {
+        ExtractedCommand {
+            command: command.to_string(),
+            output_len,
+            session_id: "sess".to_string(),
+            output_content: None,
+            is_error: false,
+            sequence_index: seq,
+        }
+    }
+
+    #[test]
+    fn test_timeline_sorted_and_cumulative() {
+        let timeline = build_timeline(
+            "sess",
+            vec![
+                cmd(1, "ls -la", Some(400)),
+                cmd(0, "echo hi", Some(8)),
+                cmd(2, "helm upgrade api ./chart", Some(4000)),
+            ],
+        );
+
+        let seqs: Vec<usize> = timeline.entries.iter().map(|e| e.sequence_index).collect();
+        assert_eq!(seqs, vec![0, 1, 2]);
+        assert_eq!(timeline.entries[0].cumulative_tokens, 2);
+        assert_eq!(timeline.entries[1].cumulative_tokens, 102);
+        assert_eq!(timeline.entries[2].cumulative_tokens, 1102);
+        assert_eq!(timeline.total_tokens, 1102);
+    }
+
+    #[test]
+    fn test_timeline_rtk_estimate() {
+        let timeline = build_timeline(
+            "sess",
+            vec![
+                cmd(0, "git diff", Some(4000)),
+                cmd(1, "rtk git diff", Some(800)),
+                cmd(2, "helm upgrade api ./chart", Some(400)),
+            ],
+        );
+
+        // git diff: 1000 tokens, 80% savings → 200
+        assert!(!timeline.entries[0].rtk_used);
+        assert_eq!(timeline.entries[0].rtk_equivalent, Some("rtk git"));
+        assert_eq!(timeline.entries[0].estimated_rtk_tokens, 200);
+        // Already rtk: unchanged
+        assert!(timeline.entries[1].rtk_used);
+        assert_eq!(timeline.entries[1].estimated_rtk_tokens, 200);
+        // Unsupported: unchanged
+        assert_eq!(timeline.entries[2].rtk_equivalent, None);
+        assert_eq!(timeline.entries[2].estimated_rtk_tokens, 100);
+        assert_eq!(timeline.total_rtk_tokens, 500);
+    }
+
+    #[test]
+    fn test_timeline_top_offenders() {
+        let commands = (0..8)
+            .map(|i| cmd(i, "cat file", Some((i + 1) * 100)))
+            .collect();
+        let timeline = build_timeline("sess", commands);
+
+        assert_eq!(timeline.top_offenders, vec![7, 6, 5, 4, 3]);
+        assert!(timeline.entries[7].top_offender);
+        assert!(!timeline.entries[0].top_offender);
+
+        let text = format_text(&timeline);
+        assert!(text.contains("TOP OFFENDERS"));
+        assert!(text.contains("-> rtk read"));
+    }
+
+    #[test]
+    fn test_timeline_missing_result() {
+        let timeline = build_timeline("sess", vec![cmd(0, "cargo build", None)]);
+        assert_eq!(timeline.entries[0].output_tokens, None);
+        assert_eq!(timeline.total_tokens, 0);
+        assert!(timeline.top_offenders.is_empty());
+    }
+}
@@ -0,0 +1,717 @@
+use crate::{config, init, tracking};
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// External tools each rtk filter shells out to: (binary, rtk commands using it)
+const FILTER_TOOLS: &[(&str, &str)] = &[
+    ("git", "rtk git"),
+    ("rg", "rtk grep"),
+    ("gh", "rtk gh"),
+    ("docker", "rtk docker"),
+    ("kubectl", "rtk kubectl"),
+    ("cargo", "rtk cargo"),
+    ("pnpm", "rtk pnpm"),
+    ("npm", "rtk npm, rtk npx"),
+    ("go", "rtk go"),
+    ("golangci-lint", "rtk golangci-lint"),
+    ("ruff", "rtk ruff"),
+    ("pytest", "rtk pytest"),
+    ("pip", "rtk pip"),
+    ("tree", "rtk tree"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Status {
+    fn icon(self) -> &'static str {
+        match self {
+            Status::Pass => "✅",
+            Status::Warn => "⚠️ ",
+            Status::Fail => "❌",
+        }
+    }
+}
+
+/// Outcome of a single diagnostic.
+#[derive(Debug)]
+struct Check {
+    name: &'static str,
+    status: Status,
+    detail: String,
+    /// Actionable next step shown for warnings and failures
+    hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// State of the installed hook script relative to the embedded version.
+#[derive(Debug, PartialEq)]
+enum HookState {
+    Missing,
+    Current,
+    Outdated,
+    NotExecutable,
+}
+
+/// State of the RTK hook entry in settings.json.
+#[derive(Debug, PartialEq)]
+enum SettingsState {
+    Missing,
+    Registered(String),
+    /// Entry points at a hook file that no longer exists
+    Dangling(String),
+}
+
+/// Run all diagnostics, optionally repairing what `rtk init` installed.
+pub fn run(fix: bool, verbose: u8) -> Result<()> {
+    let mut checks = run_checks(verbose);
+
+    if fix
+        && checks
+            .iter()
+            .any(|c| c.status != Status::Pass && is_install_check(c))
+    {
+        let claude_dir = init::resolve_claude_dir()?;
+        let settings_path = claude_dir.join("settings.json");
+        let hook_only = is_hook_only_install(&claude_dir);
+        let mode = if hook_only { " --hook-only" } else { "" };
+
+        // Rewriting a file we can't parse would lose the user's settings
+        let patch_mode = if read_settings(&settings_path).is_err() {
+            println!(
+                "Skipping settings.json repair: {} is not valid JSON, fix it by hand first",
+                settings_path.display()
+            );
+            init::PatchMode::Skip
+        } else {
+            if checks
+                .iter()
+                .any(|c| c.name == "settings.json" && c.status == Status::Fail)
+            {
+                // Drop the stale entry so init registers the current hook path
+                init::remove_hook_from_settings(verbose)?;
+            }
+            init::PatchMode::Auto
+        };
+
+        let patch_flag = if patch_mode == init::PatchMode::Auto {
+            " --auto-patch"
+        } else {
+            " --no-patch"
+        };
+        println!("Fixing installation: rtk init -g{}{}\n", mode, patch_flag);
+        init::run(true, false, hook_only, patch_mode, verbose)?;
+        println!();
+        checks = run_checks(verbose);
+    }
+
+    print!("{}", format_report(&checks));
+
+    if checks.iter().any(|c| c.status == Status::Fail) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn is_install_check(check: &Check) -> bool {
+    matches!(
+        check.name,
+        "hook" | "settings.json" | "RTK.md" | "CLAUDE.md"
+    )
+}
+
+fn run_checks(verbose: u8) -> Vec<Check> {
+    let mut checks = vec![check_binary()];
+    checks.extend(check_installation(verbose));
+    checks.push(check_config());
+    checks.push(check_database());
+    checks.extend(check_tools());
+    checks
+}
+
+/// The `rtk` found on PATH must be this binary (not Rust Type Kit).
+fn check_binary() -> Check {
+    let version = env!("CARGO_PKG_VERSION");
+    let on_path = match which("rtk") {
+        Some(p) => p,
+        None => {
+            return Check::fail(
+                "binary",
+                "rtk not found on PATH",
+                "Add the install directory (e.g. ~/.cargo/bin) to PATH; the hook skips silently without it",
+            )
+        }
+    };
+
+    let current = std::env::current_exe()
+        .ok()
+        .and_then(|p| fs::canonicalize(p).ok());
+    let resolved = fs::canonicalize(&on_path).unwrap_or_else(|_| on_path.clone());
+    if current.as_ref() == Some(&resolved) {
+        return Check::pass("binary", format!("{} (rtk {})", on_path.display(), version));
+    }
+
+    // Different file: make sure it is Rust Token Killer, not Rust Type Kit
+    let is_token_killer = Command::new(&on_path)
+        .args(["gain", "--help"])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+
+    if is_token_killer {
+        let path_version = Command::new(&on_path)
+            .arg("--version")
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+            .unwrap_or_default();
+        if path_version.ends_with(version) {
+            Check::pass(
+                "binary",
+                format!("{} ({})", on_path.display(), path_version),
+            )
+        } else {
+            Check::warn(
+                "binary",
+                format!(
+                    "{} is {} but this is rtk {}",
+                    on_path.display(),
+                    path_version,
+                    version
+                ),
+                "Reinstall so the hook and this binary agree: cargo install --path . --force",
+            )
+        }
+    } else {
+        Check::fail(
+            "binary",
+            format!(
+                "{} is not Rust Token Killer (name collision with Rust Type Kit?)",
+                on_path.display()
+            ),
+            "Uninstall the other rtk or put Rust Token Killer first on PATH",
+        )
+    }
+}
+
+/// Verify every artifact `rtk init -g` installs.
+fn check_installation(verbose: u8) -> Vec<Check> {
+    let claude_dir = match init::resolve_claude_dir() {
+        Ok(dir) => dir,
+        Err(e) => return vec![Check::fail("hook", e.to_string(), "Set $HOME")],
+    };
+    let hook_path = claude_dir.join("hooks").join("rtk-rewrite.sh");
+    let settings_path = claude_dir.join("settings.json");
+    let mut checks = Vec::new();
+
+    let hook = hook_state(&hook_path);
+    let settings = match read_settings(&settings_path) {
+        Ok(Some(root)) => settings_state(&root),
+        Ok(None) => SettingsState::Missing,
+        Err(e) => {
+            checks.push(Check::fail(
+                "settings.json",
+                format!("{} is not valid JSON: {}", settings_path.display(), e),
+                format!(
+                    "Fix the JSON by hand (backup: {}.bak)",
+                    settings_path.display()
+                ),
+            ));
+            SettingsState::Missing
+        }
+    };
+
+    if verbose > 0 {
+        eprintln!("hook: {:?}, settings: {:?}", hook, settings);
+    }
+
+    // Nothing installed at all: one actionable warning instead of four failures
+    if hook == HookState::Missing && settings == SettingsState::Missing && checks.is_empty() {
+        checks.push(Check::warn(
+            "hook",
+            "not installed",
+            "rtk init -g (or rtk doctor --fix)",
+        ));
+        return checks;
+    }
+
+    checks.push(match hook {
+        HookState::Current => Check::pass("hook", format!("{} (up to date)", hook_path.display())),
+        HookState::Missing => Check::fail(
+            "hook",
+            format!("{} missing", hook_path.display()),
+            "rtk doctor --fix",
+        ),
+        HookState::Outdated => Check::fail(
+            "hook",
+            format!(
+                "{} differs from the version embedded in rtk {}",
+                hook_path.display(),
+                env!("CARGO_PKG_VERSION")
+            ),
+            "rtk doctor --fix",
+        ),
+        HookState::NotExecutable => Check::fail(
+            "hook",
+            format!("{} is not executable", hook_path.display()),
+            "rtk doctor --fix",
+        ),
+    });
+
+    if !checks.iter().any(|c| c.name == "settings.json") {
+        checks.push(match settings {
+            SettingsState::Registered(cmd) => Check::pass("settings.json", cmd),
+            SettingsState::Missing => Check::fail(
+                "settings.json",
+                "RTK hook not registered in PreToolUse",
+                "rtk doctor --fix",
+            ),
+            SettingsState::Dangling(cmd) => Check::fail(
+                "settings.json",
+                format!("hook entry points at missing file: {}", cmd),
+                "rtk doctor --fix",
+            ),
+        });
+    }
+
+    // `rtk init -g --hook-only` installs neither RTK.md nor the @RTK.md reference
+    if is_hook_only_install(&claude_dir) {
+        checks.push(Check::pass("instructions", "hook-only install (no RTK.md)"));
+        if which("jq").is_none() {
+            checks.push(jq_missing());
+        }
+        return checks;
+    }
+
+    let rtk_md_path = claude_dir.join("RTK.md");
+    checks.push(match fs::read_to_string(&rtk_md_path) {
+        Ok(content) if content == init::RTK_SLIM => {
+            Check::pass("RTK.md", rtk_md_path.display().to_string())
+        }
+        Ok(_) => Check::fail(
+            "RTK.md",
+            format!("{} is outdated", rtk_md_path.display()),
+            "rtk doctor --fix",
+        ),
+        Err(_) => Check::fail(
+            "RTK.md",
+            format!("{} missing", rtk_md_path.display()),
+            "rtk doctor --fix",
+        ),
+    });
+
+    let claude_md_path = claude_dir.join("CLAUDE.md");
+    let claude_md = fs::read_to_string(&claude_md_path).unwrap_or_default();
+    checks.push(if claude_md.contains("<!-- rtk-instructions") {
+        Check::fail(
+            "CLAUDE.md",
+            "legacy 137-line rtk block still present",
+            "rtk doctor --fix (migrates to @RTK.md)",
+        )
+    } else if claude_md.contains("@RTK.md") {
+        Check::pass("CLAUDE.md", "@RTK.md reference")
+    } else {
+        Check::fail(
+            "CLAUDE.md",
+            format!("{} has no @RTK.md reference", claude_md_path.display()),
+            "rtk doctor --fix",
+        )
+    });
+
+    if which("jq").is_none() {
+        checks.push(jq_missing());
+    }
+
+    checks
+}
+
+/// The hook silently does nothing without jq.
+fn jq_missing() -> Check {
+    Check::fail(
+        "jq",
+        "jq not found, the hook skips every rewrite",
+        "Install jq (brew install jq / apt install jq)",
+    )
+}
+
+/// Parsed settings.json; `None` when absent or empty.
+fn read_settings(path: &Path) -> Result<Option<serde_json::Value>> {
+    match fs::read_to_string(path) {
+        Ok(content) if !content.trim().is_empty() => Ok(Some(serde_json::from_str(&content)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Hook installed without RTK.md or any CLAUDE.md reference to it.
+fn is_hook_only_install(claude_dir: &Path) -> bool {
+    let claude_md = fs::read_to_string(claude_dir.join("CLAUDE.md")).unwrap_or_default();
+    claude_dir.join("hooks").join("rtk-rewrite.sh").exists()
+        && !claude_dir.join("RTK.md").exists()
+        && !claude_md.contains("@RTK.md")
+        && !claude_md.contains("<!-- rtk-instructions")
+}
+
+fn hook_state(path: &Path) -> HookState {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return HookState::Missing,
+    };
+    if content != init::REWRITE_HOOK {
+        return HookState::Outdated;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let executable = fs::metadata(path)
+            .map(|m| m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false);
+        if !executable {
+            return HookState::NotExecutable;
+        }
+    }
+
+    HookState::Current
+}
+
+fn settings_state(root: &serde_json::Value) -> SettingsState {
+    let command = root
+        .pointer("/hooks/PreToolUse")
+        .and_then(|p| p.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("hooks")?.as_array())
+        .flatten()
+        .filter_map(|hook| hook.get("command")?.as_str())
+        .find(|cmd| cmd.contains("rtk-rewrite.sh"));
+
+    match command {
+        None => SettingsState::Missing,
+        Some(cmd) if expand_home(cmd).exists() => SettingsState::Registered(cmd.to_string()),
+        Some(cmd) => SettingsState::Dangling(cmd.to_string()),
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn check_config() -> Check {
+    let path = match config::get_config_path() {
+        Ok(p) => p,
+        Err(e) => return Check::fail("config", e.to_string(), "Set $HOME"),
+    };
+    if !path.exists() {
+        return Check::pass("config", "defaults (no config file)");
+    }
+    match config::Config::load() {
+        Ok(_) => Check::pass("config", path.display().to_string()),
+        Err(e) => Check::fail(
+            "config",
+            format!("{} does not parse: {}", path.display(), e),
+            format!(
+                "Fix or delete {} (rtk config --create writes defaults)",
+                path.display()
+            ),
+        ),
+    }
+}
+
+/// Read-only: a diagnostic must not create the database or its directory.
+fn check_database() -> Check {
+    match tracking::get_db_path() {
+        Ok(path) => check_database_at(&path),
+        Err(e) => Check::fail("database", e.to_string(), "Set RTK_DB_PATH"),
+    }
+}
+
+fn check_database_at(path: &Path) -> Check {
+    const HINT: &str = "Make the directory writable or set RTK_DB_PATH / tracking.database_path";
+
+    if let Some(blocker) = unwritable_db_path(path) {
+        return Check::fail(
+            "database",
+            format!("{}: {} is not writable", path.display(), blocker.display()),
+            HINT,
+        );
+    }
+    if !path.exists() {
+        return Check::pass("database", format!("{} (not created yet)", path.display()));
+    }
+    match count_tracked_commands(path) {
+        Ok(count) => Check::pass(
+            "database",
+            format!("{} ({} commands tracked)", path.display(), count),
+        ),
+        Err(e) => Check::fail("database", format!("{}: {}", path.display(), e), HINT),
+    }
+}
+
+/// First path that would stop tracking from writing the DB, without creating
+/// anything: the file itself if read-only, else its nearest existing parent
+/// directory (SQLite also writes its journal next to the file).
+fn unwritable_db_path(path: &Path) -> Option<&Path> {
+    let readonly = |p: &Path| {
+        fs::metadata(p)
+            .map(|m| m.permissions().readonly())
+            .unwrap_or(false)
+    };
+    if path.exists() && readonly(path) {
+        return Some(path);
+    }
+    let dir = path.ancestors().skip(1).find(|p| p.exists())?;
+    readonly(dir).then_some(dir)
+}
+
+fn count_tracked_commands(path: &Path) -> Result<i64> {
+    let conn = rusqlite::Connection::open_with_flags(
+        path,
+        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    let count = conn.query_row("SELECT COUNT(*) FROM commands", [], |row| row.get(0))?;
+    Ok(count)
+}
+
+/// Missing tools only disable the matching filter, so they are warnings.
+fn check_tools() -> Vec<Check> {
+    let (found, missing): (Vec<_>, Vec<_>) = FILTER_TOOLS
+        .iter()
+        .partition(|(tool, _)| which(tool).is_some());
+
+    let names = |tools: &[&(&str, &str)]| {
+        tools
+            .iter()
+            .map(|(tool, _)| *tool)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut checks = vec![Check::pass("tools", names(&found))];
+    if !missing.is_empty() {
+        let used_by = missing
+            .iter()
+            .map(|(_, cmd)| *cmd)
+            .collect::<Vec<_>>()
+            .join(", ");
+        checks.push(Check::warn(
+            "tools",
+            format!("not found: {}", names(&missing)),
+            format!("only needed for {}", used_by),
+        ));
+    }
+    checks
+}
+
+fn which(tool: &str) -> Option<PathBuf> {
+    let output = Command::new("which").arg(tool).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!path.is_empty()).then(|| PathBuf::from(path))
+}
+
+fn format_report(checks: &[Check]) -> String {
+    let mut out = String::from("rtk doctor\n\n");
+
+    for check in checks {
+        out.push_str(&format!(
+            "{} {:<14} {}\n",
+            check.status.icon(),
+            check.name,
+            check.detail
+        ));
+        if let Some(hint) = &check.hint {
+            out.push_str(&format!("   {:<14} → {}\n", "", hint));
+        }
+    }
+
+    let count = |s: Status| checks.iter().filter(|c| c.status == s).count();
+    out.push_str(&format!(
+        "\n{} passed, {} warnings, {} failed\n",
+        count(Status::Pass),
+        count(Status::Warn),
+        count(Status::Fail)
+    ));
+    if checks
+        .iter()
+        .any(|c| c.status != Status::Pass && is_install_check(c))
+    {
+        out.push_str("Run `rtk doctor --fix` to repair the installation.\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hook_state() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("rtk-rewrite.sh");
+        assert_eq!(hook_state(&path), HookState::Missing);
+
+        fs::write(&path, "#!/bin/bash\n# old hook\n").unwrap();
+        assert_eq!(hook_state(&path), HookState::Outdated);
+
+        fs::write(&path, init::REWRITE_HOOK).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+            assert_eq!(hook_state(&path), HookState::NotExecutable);
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        assert_eq!(hook_state(&path), HookState::Current);
+    }
+
+    #[test]
+    fn test_settings_state() {
+        let temp = TempDir::new().unwrap();
+        let hook = temp.path().join("rtk-rewrite.sh");
+        fs::write(&hook, "").unwrap();
+        let entry = |cmd: &str| {
+            serde_json::json!({
+                "hooks": { "PreToolUse": [{ "matcher": "Bash", "hooks": [{ "type": "command", "command": cmd }] }] }
+            })
+        };
+
+        assert_eq!(
+            settings_state(&serde_json::json!({})),
+            SettingsState::Missing
+        );
+        assert_eq!(
+            settings_state(&entry("/other/hook.sh")),
+            SettingsState::Missing
+        );
+
+        let live = hook.to_string_lossy().to_string();
+        assert_eq!(
+            settings_state(&entry(&live)),
+            SettingsState::Registered(live.clone())
+        );
+
+        let dead = "/deleted/path/rtk-rewrite.sh";
+        assert_eq!(
+            settings_state(&entry(dead)),
+            SettingsState::Dangling(dead.to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_report_counts_and_fix_hint() {
+        let checks = vec![
+            Check::pass("binary", "/usr/bin/rtk"),
+            Check::warn("tools", "not found: gh", "only needed for rtk gh"),
+            Check::fail("hook", "outdated", "rtk doctor --fix"),
+        ];
+        let report = format_report(&checks);
+        assert!(report.contains("1 passed, 1 warnings, 1 failed"));
+        assert!(report.contains("→ only needed for rtk gh"));
+        assert!(report.contains("rtk doctor --fix"));
+    }
+
+    #[test]
+    fn test_format_report_no_fix_hint_for_tools() {
+        let checks = vec![Check::warn(
+            "tools",
+            "not found: gh",
+            "only needed for rtk gh",
+        )];
+        assert!(!format_report(&checks).contains("Run `rtk doctor --fix`"));
+    }
+
+    #[test]
+    fn test_is_hook_only_install() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        assert!(!is_hook_only_install(dir));
+
+        fs::create_dir_all(dir.join("hooks")).unwrap();
+        fs::write(dir.join("hooks").join("rtk-rewrite.sh"), init::REWRITE_HOOK).unwrap();
+        fs::write(dir.join("CLAUDE.md"), "# My notes\n").unwrap();
+        assert!(is_hook_only_install(dir));
+
+        fs::write(dir.join("CLAUDE.md"), "# My notes\n@RTK.md\n").unwrap();
+        assert!(!is_hook_only_install(dir));
+    }
+
+    #[test]
+    fn test_read_settings() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("settings.json");
+        assert!(read_settings(&path).unwrap().is_none());
+
+        fs::write(&path, "{ \"hooks\": ").unwrap();
+        assert!(read_settings(&path).is_err());
+
+        fs::write(&path, "{}").unwrap();
+        assert!(read_settings(&path).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_count_tracked_commands_is_read_only() {
+        let temp = TempDir::new().unwrap();
+        let missing = temp.path().join("nested").join("history.db");
+        assert!(count_tracked_commands(&missing).is_err());
+        assert!(!temp.path().join("nested").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_database_unwritable_dir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("ro");
+        fs::create_dir(&dir).unwrap();
+        let path = dir.join("nested").join("history.db");
+        assert_eq!(check_database_at(&path).status, Status::Pass);
+
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o555)).unwrap();
+        let check = check_database_at(&path);
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert_eq!(check.status, Status::Fail);
+        assert!(check.detail.contains("is not writable"));
+        assert!(!dir.join("nested").exists());
+    }
+}
@@ -0,0 +1,656 @@
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, unescape_xml, xml_attr,
+    FormatMode, OutputParser, ParseResult, TestFailure, TestResult, TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::{relative_path, truncate};
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::process::Command;
+
+/// Locations listed per diagnostic code before collapsing.
+const MAX_LOCATIONS_PER_CODE: usize = 6;
+/// Application stack frames kept per failed test.
+const MAX_STACK_FRAMES: usize = 3;
+
+/// Frames from the runtime and test frameworks.
+const FRAMEWORK_FRAMES: &[&str] = &[
+    "System.",
+    "Microsoft.",
+    "Xunit.",
+    "NUnit.",
+    "Castle.",
+    "Moq.",
+    "FluentAssertions.",
+];
+
+lazy_static::lazy_static! {
+    // /work/src/App/Program.cs(12,17): warning CS8602: Dereference ... [/work/src/App/App.csproj]
+    // /work/src/App/App.csproj : warning NU1603: ... [/work/src/App/App.csproj]
+    // MSBUILD : error MSB1009: Project file does not exist.
+    static ref DIAGNOSTIC: Regex = Regex::new(
+        r"^\s*(.+?)(?:\((\d+),(\d+)(?:,\d+,\d+)?\))?\s*:\s*(error|warning)\s+([A-Z]+\d+)\s*:\s*(.+?)(?:\s+\[[^\]]+\])?$"
+    ).unwrap();
+    // App.Core -> /work/src/App.Core/bin/Debug/net8.0/App.Core.dll
+    static ref PROJECT_OUTPUT: Regex = Regex::new(r"^\s+\S+ -> \S+").unwrap();
+    static ref TEST_SUMMARY: Regex = Regex::new(
+        r"^(?:Passed|Failed)!\s+-\s+Failed:\s+(\d+),\s+Passed:\s+(\d+),\s+Skipped:\s+(\d+),\s+Total:\s+(\d+)(?:,\s+Duration:\s+(\d+)\s*(ms|s|m))?"
+    ).unwrap();
+    static ref TEST_FAILED: Regex = Regex::new(r"^\s+Failed (\S+) \[").unwrap();
+    static ref TRX_RESULT: Regex = Regex::new(
+        r#"(?s)<UnitTestResult\b([^>]*?)(?:/>|>(.*?)</UnitTestResult>)"#
+    ).unwrap();
+    static ref TRX_MESSAGE: Regex = Regex::new(r"(?s)<Message>(.*?)</Message>").unwrap();
+    static ref TRX_STACK: Regex = Regex::new(r"(?s)<StackTrace>(.*?)</StackTrace>").unwrap();
+    // "at App.Calc.Divide(Int32 a) in /work/src/Calc.cs:line 14"
+    static ref FRAME_LOCATION: Regex = Regex::new(r" in (.+?):line (\d+)$").unwrap();
+}
+
+pub fn run_build(args: &[String], verbose: u8) -> Result<()> {
+    run_dotnet("build", args, verbose, filter_build_output)
+}
+
+pub fn run_restore(args: &[String], verbose: u8) -> Result<()> {
+    run_dotnet("restore", args, verbose, filter_restore_output)
+}
+
+fn run_dotnet(
+    subcommand: &str,
+    args: &[String],
+    verbose: u8,
+    filter: fn(&str) -> String,
+) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("dotnet");
+    cmd.arg(subcommand).args(args);
+    // Classic console logger: the terminal logger redraws lines
+    cmd.env("MSBUILDTERMINALLOGGER", "off")
+        .env("DOTNET_NOLOGO", "1")
+        .env("DOTNET_CLI_TELEMETRY_OPTOUT", "1");
+
+    if verbose > 0 {
+        eprintln!("Running: dotnet {} {}", subcommand, args.join(" "));
+    }
+
+    let output = cmd.output().with_context(|| {
+        format!(
+            "Failed to run dotnet {}. Is the .NET SDK installed?",
+            subcommand
+        )
+    })?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("dotnet {} {}", subcommand, args.join(" ")),
+        &format!("rtk dotnet {} {}", subcommand, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+pub fn run_test(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // Ask for TRX reports in a scratch directory unless the caller set up logging
+    let results_dir = tempfile::Builder::new()
+        .prefix("rtk-dotnet-test-")
+        .tempdir()
+        .context("Failed to create results directory")?;
+    let user_logging = args
+        .iter()
+        .any(|a| a.starts_with("--logger") || a == "-l" || a.starts_with("--results-directory"));
+
+    let mut cmd = Command::new("dotnet");
+    cmd.arg("test");
+    if !user_logging {
+        cmd.arg("--logger")
+            .arg("trx")
+            .arg("--results-directory")
+            .arg(results_dir.path());
+    }
+    cmd.args(args)
+        .env("MSBUILDTERMINALLOGGER", "off")
+        .env("DOTNET_NOLOGO", "1")
+        .env("DOTNET_CLI_TELEMETRY_OPTOUT", "1");
+
+    if verbose > 0 {
+        eprintln!("Running: dotnet test {}", args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run dotnet test. Is the .NET SDK installed?")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let trx: String = std::fs::read_dir(results_dir.path())
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "trx"))
+        .filter_map(|e| std::fs::read_to_string(e.path()).ok())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = format_test_run(&raw, &trx, mode, verbose);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("dotnet test {}", args.join(" ")),
+        &format!("rtk dotnet test {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Test results from TRX when available, else from the console; build
+/// errors take precedence since no tests ran.
+fn format_test_run(console: &str, trx: &str, mode: FormatMode, verbose: u8) -> String {
+    let diagnostics = parse_diagnostics(console);
+    if diagnostics.iter().any(|d| d.error) {
+        return filter_build_output(console);
+    }
+
+    let parsed = match TrxParser::parse(trx) {
+        ParseResult::Full(result) => ParseResult::Full(result),
+        _ => DotnetTestParser::parse(console),
+    };
+    match parsed {
+        ParseResult::Full(result) => result.format(mode),
+        ParseResult::Degraded(result, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("dotnet test", &warnings.join(", "));
+            }
+            result.format(mode)
+        }
+        ParseResult::Passthrough(raw) => {
+            emit_passthrough_warning("dotnet test", "No test results found");
+            raw
+        }
+    }
+}
+
+/// One compiler/MSBuild/NuGet diagnostic.
+#[derive(Debug, PartialEq)]
+struct Diagnostic {
+    file: String,
+    line: usize,
+    column: usize,
+    error: bool,
+    code: String,
+    message: String,
+}
+
+/// Diagnostics in first-seen order; MSBuild repeats them in its summary and
+/// once per target framework, so exact repeats are dropped.
+fn parse_diagnostics(output: &str) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    for line in output.lines() {
+        let Some(caps) = DIAGNOSTIC.captures(line) else {
+            continue;
+        };
+        let diag = Diagnostic {
+            file: relative_path(caps[1].trim()),
+            line: caps
+                .get(2)
+                .and_then(|m| m.as_str().parse().ok())
+                .unwrap_or(0),
+            column: caps
+                .get(3)
+                .and_then(|m| m.as_str().parse().ok())
+                .unwrap_or(0),
+            error: &caps[4] == "error",
+            code: caps[5].to_string(),
+            message: caps[6].to_string(),
+        };
+        if !diagnostics.contains(&diag) {
+            diagnostics.push(diag);
+        }
+    }
+    diagnostics
+}
+
+fn location(diag: &Diagnostic) -> String {
+    match (diag.line, diag.column) {
+        (0, _) => diag.file.clone(),
+        (line, 0) => format!("{}:{}", diag.file, line),
+        (line, column) => format!("{}:{}:{}", diag.file, line, column),
+    }
+}
+
+/// Errors one per line; warnings grouped by code with their locations by file.
+fn format_diagnostics(diagnostics: &[Diagnostic]) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    let errors: Vec<&Diagnostic> = diagnostics.iter().filter(|d| d.error).collect();
+    if !errors.is_empty() {
+        lines.push("Errors:".to_string());
+        for diag in &errors {
+            lines.push(format!(
+                "  {} {}: {}",
+                diag.code,
+                location(diag),
+                truncate(&diag.message, 160)
+            ));
+        }
+    }
+
+    let mut codes: Vec<&str> = Vec::new();
+    let mut by_code: HashMap<&str, Vec<&Diagnostic>> = HashMap::new();
+    for diag in diagnostics.iter().filter(|d| !d.error) {
+        if !by_code.contains_key(diag.code.as_str()) {
+            codes.push(&diag.code);
+        }
+        by_code.entry(&diag.code).or_default().push(diag);
+    }
+    if !codes.is_empty() {
+        lines.push("Warnings:".to_string());
+        // Most frequent codes first
+        codes.sort_by_key(|code| std::cmp::Reverse(by_code[code].len()));
+        for code in codes {
+            let group = &by_code[code];
+            lines.push(format!(
+                "  {} ×{}: {}",
+                code,
+                group.len(),
+                truncate(&group[0].message, 120)
+            ));
+
+            // Locations grouped by file: "Reader.cs:12:17, 30:9"
+            let mut files: Vec<&str> = Vec::new();
+            let mut by_file: HashMap<&str, Vec<String>> = HashMap::new();
+            for diag in group.iter().take(MAX_LOCATIONS_PER_CODE) {
+                if !by_file.contains_key(diag.file.as_str()) {
+                    files.push(&diag.file);
+                }
+                let position = match (diag.line, diag.column) {
+                    (0, _) => String::new(),
+                    (line, 0) => line.to_string(),
+                    (line, column) => format!("{}:{}", line, column),
+                };
+                by_file.entry(&diag.file).or_default().push(position);
+            }
+            for file in files {
+                let positions: Vec<&String> =
+                    by_file[file].iter().filter(|p| !p.is_empty()).collect();
+                if positions.is_empty() {
+                    lines.push(format!("    {}", file));
+                } else {
+                    let positions: Vec<&str> = positions.iter().map(|p| p.as_str()).collect();
+                    lines.push(format!("    {}:{}", file, positions.join(", ")));
+                }
+            }
+            if group.len() > MAX_LOCATIONS_PER_CODE {
+                lines.push(format!(
+                    "    ... +{} more",
+                    group.len() - MAX_LOCATIONS_PER_CODE
+                ));
+            }
+        }
+    }
+    lines
+}
+
+fn filter_build_output(output: &str) -> String {
+    let diagnostics = parse_diagnostics(output);
+    let projects = output
+        .lines()
+        .filter(|l| PROJECT_OUTPUT.is_match(l))
+        .count();
+    let elapsed = output
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("Time Elapsed "))
+        .map(|t| t.trim_start_matches("00:").to_string());
+
+    let errors = diagnostics.iter().filter(|d| d.error).count();
+    let warnings = diagnostics.len() - errors;
+    let failed = errors > 0 || output.contains("Build FAILED.");
+
+    let mut header = format!(
+        "{} dotnet build: {} errors, {} warnings, {} projects built",
+        if failed { "❌" } else { "✓" },
+        errors,
+        warnings,
+        projects
+    );
+    if let Some(elapsed) = elapsed {
+        header.push_str(&format!(" ({})", elapsed));
+    }
+
+    let mut lines = vec![header];
+    lines.extend(format_diagnostics(&diagnostics));
+    lines.join("\n")
+}
+
+fn filter_restore_output(output: &str) -> String {
+    let diagnostics = parse_diagnostics(output);
+    let restored = output
+        .lines()
+        .filter(|l| l.trim_start().starts_with("Restored "))
+        .count();
+    let up_to_date = output.contains("All projects are up-to-date for restore.");
+    let errors = diagnostics.iter().filter(|d| d.error).count();
+
+    let header = if errors > 0 {
+        format!("❌ dotnet restore: {} errors", errors)
+    } else if restored == 0 && up_to_date {
+        "✓ dotnet restore: all projects up-to-date".to_string()
+    } else {
+        format!("✓ dotnet restore: {} projects restored", restored)
+    };
+
+    let mut lines = vec![header];
+    lines.extend(format_diagnostics(&diagnostics));
+    lines.join("\n")
+}
+
+/// Exception message on one line, located at the first application frame.
+fn test_failure(test_name: &str, message: &str, stack: &str) -> TestFailure {
+    let frames: Vec<&str> = stack
+        .lines()
+        .map(str::trim)
+        .filter_map(|l| l.strip_prefix("at "))
+        .filter(|frame| !FRAMEWORK_FRAMES.iter().any(|p| frame.starts_with(p)))
+        .take(MAX_STACK_FRAMES)
+        .collect();
+
+    let message: Vec<&str> = message
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+    let mut error_message = truncate(&message.join(" "), 200);
+    let mut file_path = String::new();
+    if let Some(caps) = frames.first().and_then(|f| FRAME_LOCATION.captures(f)) {
+        let file = relative_path(&caps[1]);
+        let name = file.rsplit('/').next().unwrap_or(&file).to_string();
+        error_message.push_str(&format!(" ({}:{})", name, &caps[2]));
+        file_path = file;
+    }
+
+    TestFailure {
+        test_name: test_name.to_string(),
+        file_path,
+        error_message,
+        stack_trace: if frames.is_empty() {
+            None
+        } else {
+            Some(
+                frames
+                    .iter()
+                    .map(|f| format!("at {}", f))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        },
+    }
+}
+
+/// Parses one or more concatenated TRX (Visual Studio test results) files.
+struct TrxParser;
+
+impl OutputParser for TrxParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let mut result = TestResult {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+
+        for caps in TRX_RESULT.captures_iter(input) {
+            let attrs = &caps[1];
+            result.total += 1;
+            match xml_attr(attrs, "outcome").as_deref() {
+                Some("Passed") => result.passed += 1,
+                Some("NotExecuted") | Some("Inconclusive") => result.skipped += 1,
+                _ => {
+                    result.failed += 1;
+                    let body = caps.get(2).map(|m| m.as_str()).unwrap_or("");
+                    let message = TRX_MESSAGE
+                        .captures(body)
+                        .map(|c| unescape_xml(&c[1]))
+                        .unwrap_or_default();
+                    let stack = TRX_STACK
+                        .captures(body)
+                        .map(|c| unescape_xml(&c[1]))
+                        .unwrap_or_default();
+                    let name = xml_attr(attrs, "testName").unwrap_or_default();
+                    result.failures.push(test_failure(&name, &message, &stack));
+                }
+            }
+        }
+
+        if result.total == 0 {
+            return ParseResult::Passthrough(String::new());
+        }
+        ParseResult::Full(result)
+    }
+}
+
+/// Parses `dotnet test` console output (VSTest logger).
+struct DotnetTestParser;
+
+impl OutputParser for DotnetTestParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let mut result = TestResult {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+        let mut found_summary = false;
+
+        let lines: Vec<&str> = input.lines().collect();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            i += 1;
+
+            if let Some(caps) = TEST_SUMMARY.captures(line) {
+                found_summary = true;
+                let n = |idx: usize| caps[idx].parse::<usize>().unwrap_or(0);
+                result.failed += n(1);
+                result.passed += n(2);
+                result.skipped += n(3);
+                result.total += n(4);
+                if let (Some(value), Some(unit)) = (caps.get(5), caps.get(6)) {
+                    let value: u64 = value.as_str().parse().unwrap_or(0);
+                    let ms = match unit.as_str() {
+                        "ms" => value,
+                        "s" => value * 1000,
+                        _ => value * 60_000,
+                    };
+                    result.duration_ms = Some(result.duration_ms.unwrap_or(0) + ms);
+                }
+                continue;
+            }
+
+            if let Some(caps) = TEST_FAILED.captures(line) {
+                let name = caps[1].to_string();
+                let mut message = Vec::new();
+                let mut stack = Vec::new();
+                let mut in_stack = false;
+                while let Some(next) = lines.get(i) {
+                    let trimmed = next.trim();
+                    if TEST_FAILED.is_match(next)
+                        || TEST_SUMMARY.is_match(next)
+                        || trimmed.is_empty()
+                        || next.starts_with("  Passed ")
+                        || next.starts_with("  Skipped ")
+                    {
+                        break;
+                    }
+                    i += 1;
+                    match trimmed {
+                        "Error Message:" => {}
+                        "Stack Trace:" => in_stack = true,
+                        _ if in_stack => stack.push(trimmed),
+                        _ => message.push(trimmed),
+                    }
+                }
+                result
+                    .failures
+                    .push(test_failure(&name, &message.join("\n"), &stack.join("\n")));
+            }
+        }
+
+        if found_summary {
+            ParseResult::Full(result)
+        } else if !result.failures.is_empty() {
+            result.failed = result.failures.len();
+            result.total = result.failed;
+            ParseResult::Degraded(result, vec!["no test summary line".to_string()])
+        } else {
+            ParseResult::Passthrough(truncate_output(input.trim(), 2000))
+        }
+    }
+}
+
+pub fn run_other(args: &[OsString], verbose: u8) -> Result<()> {
+    if args.is_empty() {
+        anyhow::bail!("dotnet: no subcommand specified");
+    }
+
+    let timer = tracking::TimedExecution::start();
+
+    let subcommand = args[0].to_string_lossy();
+    if verbose > 0 {
+        eprintln!("Running: dotnet {} ...", subcommand);
+    }
+
+    let status = Command::new("dotnet")
+        .args(args)
+        .status()
+        .with_context(|| format!("Failed to run dotnet {}", subcommand))?;
+
+    let args_str = tracking::args_display(args);
+    timer.track_passthrough(
+        &format!("dotnet {}", args_str),
+        &format!("rtk dotnet {} (passthrough)", args_str),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUILD_OUTPUT: &str = include_str!("../tests/fixtures/dotnet_build.txt");
+    const TEST_OUTPUT: &str = include_str!("../tests/fixtures/dotnet_test.txt");
+    const TRX: &str = include_str!("../tests/fixtures/dotnet_test.trx");
+
+    #[test]
+    fn test_parse_diagnostics_dedupes_summary() {
+        let diagnostics = parse_diagnostics(BUILD_OUTPUT);
+        // 6 unique diagnostics, each printed twice by MSBuild
+        assert_eq!(diagnostics.len(), 6);
+        let nuget = diagnostics.iter().find(|d| d.code == "NU1603").unwrap();
+        assert_eq!(nuget.file, "/work/src/App.Api/App.Api.csproj");
+        assert_eq!(nuget.line, 0);
+    }
+
+    #[test]
+    fn test_filter_build_output() {
+        let result = filter_build_output(BUILD_OUTPUT);
+        assert_eq!(
+            result,
+            "\
+❌ dotnet build: 2 errors, 4 warnings, 1 projects built (04.87)
+Errors:
+  CS0103 /work/src/App.Api/Controllers/UsersController.cs:41:24: The name 'userServce' does not exist in the current context
+  CS1061 /work/src/App.Api/Controllers/UsersController.cs:57:13: 'User' does not contain a definition for 'Emial' and no accessible extension method 'Emial' accepting a first argument of type 'User' could be found (are you...
+Warnings:
+  CS8602 ×2: Dereference of a possibly null reference.
+    /work/src/App.Core/Parsing/Reader.cs:12:17, 30:9
+  CS8618 ×1: Non-nullable property 'Name' must contain a non-null value when exiting constructor. Consider declaring the property ...
+    /work/src/App.Core/Models/User.cs:8:19
+  NU1603 ×1: App.Api depends on Serilog (>= 3.0.0) but Serilog 3.0.0 was not found. An approximate best match of Serilog 3.0.1 was...
+    /work/src/App.Api/App.Api.csproj"
+        );
+    }
+
+    #[test]
+    fn test_console_test_parser() {
+        let result = DotnetTestParser::parse(TEST_OUTPUT).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (40, 37, 2, 1)
+        );
+        assert_eq!(result.duration_ms, Some(212));
+        let first = &result.failures[0];
+        assert_eq!(first.test_name, "App.Tests.CalculatorTests.Divide_ByTwo");
+        assert_eq!(
+            first.error_message,
+            "Assert.Equal() Failure Expected: 2 Actual:   3 (Calculator.cs:14)"
+        );
+        assert_eq!(
+            first.stack_trace.as_deref(),
+            Some("at App.Core.Calculator.Divide(Int32 a, Int32 b) in /work/src/App.Core/Calculator.cs:line 14\nat App.Tests.CalculatorTests.Divide_ByTwo() in /work/tests/App.Tests/CalculatorTests.cs:line 21")
+        );
+        assert_eq!(
+            result.failures[1].error_message,
+            "System.ArgumentException : Name is required (Parameter 'name') (User.cs:11)"
+        );
+    }
+
+    #[test]
+    fn test_trx_parser() {
+        let result = TrxParser::parse(TRX).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (3, 1, 1, 1)
+        );
+        assert_eq!(
+            result.failures[0].error_message,
+            "Assert.Equal() Failure Expected: 2 Actual:   3 (Calculator.cs:14)"
+        );
+    }
+
+    #[test]
+    fn test_format_test_run_prefers_trx_and_shows_failures_only() {
+        let result = format_test_run(TEST_OUTPUT, TRX, FormatMode::Compact, 0);
+        assert!(result.starts_with("PASS (1) FAIL (1)"));
+        assert!(result.contains("1. App.Tests.CalculatorTests.Divide_ByTwo"));
+        assert!(!result.contains("Adds"));
+
+        let console_only = format_test_run(TEST_OUTPUT, "", FormatMode::Compact, 0);
+        assert!(console_only.starts_with("PASS (37) FAIL (2)"));
+
+        // Build errors win over (absent) test results
+        let broken = format_test_run(BUILD_OUTPUT, "", FormatMode::Compact, 0);
+        assert!(broken.starts_with("❌ dotnet build: 2 errors"));
+    }
+}
@@ -49,6 +49,7 @@ pub enum Language {
     Cpp,
     Java,
     Ruby,
+    Php,
     Shell,
     Unknown,
 }
@@ -65,6 +66,7 @@ impl Language {
             "cpp" | "cc" | "cxx" | "hpp" | "hh" => Language::Cpp,
             "java" => Language::Java,
             "rb" => Language::Ruby,
+            "php" => Language::Php,
             "sh" | "bash" | "zsh" => Language::Shell,
             _ => Language::Unknown,
         }
@@ -91,7 +93,8 @@ impl Language {
             | Language::Go
             | Language::C
             | Language::Cpp
-            | Language::Java => CommentPatterns {
+            | Language::Java
+            | Language::Php => CommentPatterns {
                 line: Some("//"),
                 block_start: Some("/*"),
                 block_end: Some("*/"),
@@ -123,6 +126,52 @@ impl Language {
     }
 }
 
+lazy_static! {
+    static ref RUST_SCOPE: Regex = Regex::new(
+        r#"^(pub(\([^)]*\))?\s+)?((default|async|const|unsafe|extern(\s+"[^"]*")?)\s+)*(fn|struct|enum|trait|impl|mod|union|macro_rules!)[\s<!]"#
+    )
+    .unwrap();
+    static ref PYTHON_SCOPE: Regex = Regex::new(r"^(async\s+)?(def|class)\s+\w+").unwrap();
+    static ref JS_SCOPE: Regex = Regex::new(
+        r"^(export\s+)?(default\s+)?(declare\s+)?(abstract\s+)?(async\s+)?(function\*?|class|interface|type|enum|namespace)\s*\w*|^(export\s+)?(const|let|var)\s+\w+\s*(:[^=]+)?=\s*(async\s*)?(\([^)]*\)|\w+)\s*(:[^=]+)?=>"
+    )
+    .unwrap();
+    static ref GO_SCOPE: Regex = Regex::new(r"^(func\s|type\s+\w+\s+(struct|interface)\b)").unwrap();
+    static ref C_LIKE_SCOPE: Regex = Regex::new(
+        r"^((class|struct|namespace|interface|enum|record)\s+\w+|([\w:<>,\*&~\[\]]+\s+)+\**[\w:~]+\s*\([^;]*$)"
+    )
+    .unwrap();
+    static ref RUBY_SCOPE: Regex = Regex::new(r"^(def|class|module)\s+\S+").unwrap();
+    static ref PHP_SCOPE: Regex = Regex::new(
+        r"^((abstract|final|readonly|public|protected|private|static)\s+)*(function\s+&?\w+|(class|interface|trait|enum)\s+\w+)"
+    )
+    .unwrap();
+    static ref SHELL_SCOPE: Regex =
+        Regex::new(r"^(function\s+[\w-]+|[\w-]+\s*\(\s*\))").unwrap();
+}
+
+impl Language {
+    /// Whether a line opens a function/type scope (used to label diff hunks).
+    pub fn is_scope_header(&self, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        match self {
+            Language::Rust => RUST_SCOPE.is_match(trimmed),
+            Language::Python => PYTHON_SCOPE.is_match(trimmed),
+            Language::JavaScript | Language::TypeScript => JS_SCOPE.is_match(trimmed),
+            Language::Go => GO_SCOPE.is_match(trimmed),
+            Language::C | Language::Cpp | Language::Java => {
+                const CONTROL: &[&str] = &["if", "for", "while", "switch", "return", "else"];
+                let first = trimmed.split(|c: char| !c.is_alphanumeric()).next();
+                C_LIKE_SCOPE.is_match(trimmed) && !first.is_some_and(|w| CONTROL.contains(&w))
+            }
+            Language::Ruby => RUBY_SCOPE.is_match(trimmed),
+            Language::Php => PHP_SCOPE.is_match(trimmed),
+            Language::Shell => SHELL_SCOPE.is_match(trimmed),
+            Language::Unknown => FUNC_SIGNATURE.is_match(trimmed),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CommentPatterns {
     pub line: Option<&'static str>,
@@ -231,7 +280,7 @@ lazy_static! {
     static ref IMPORT_PATTERN: Regex =
         Regex::new(r"^(use |import |from |require\(|#include)").unwrap();
     static ref FUNC_SIGNATURE: Regex = Regex::new(
-        r"^(pub\s+)?(async\s+)?(fn|def|function|func|class|struct|enum|trait|interface|type)\s+\w+"
+        r"^(pub\s+)?((public|protected|private|static|abstract|final)\s+)*(async\s+)?(fn|def|function|func|class|struct|enum|trait|interface|type)\s+\w+"
     )
     .unwrap();
 }
@@ -382,11 +431,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_scope_header() {
+        assert!(Language::Rust.is_scope_header("    pub(crate) async fn run() {"));
+        assert!(Language::Rust.is_scope_header("impl<T> Foo for Bar<T> {"));
+        assert!(!Language::Rust.is_scope_header("    let fn_name = 1;"));
+        assert!(Language::Python.is_scope_header("    async def handler(req):"));
+        assert!(Language::TypeScript.is_scope_header("export const load = async (id: string) => {"));
+        assert!(Language::Go.is_scope_header("func (s *Server) Start() error {"));
+        assert!(Language::Java.is_scope_header("    public static void main(String[] args) {"));
+        assert!(!Language::Java.is_scope_header("    if (x) {"));
+        assert!(!Language::C.is_scope_header("    return foo(x);"));
+        assert!(
+            Language::Php.is_scope_header("    public static function create(array $attrs): self")
+        );
+        assert!(Language::Php.is_scope_header("final class UserController extends Controller"));
+        assert!(!Language::Php.is_scope_header("    $function = fn($x) => $x;"));
+    }
+
     #[test]
     fn test_language_detection() {
         assert_eq!(Language::from_extension("rs"), Language::Rust);
         assert_eq!(Language::from_extension("py"), Language::Python);
         assert_eq!(Language::from_extension("js"), Language::JavaScript);
+        assert_eq!(Language::from_extension("php"), Language::Php);
+    }
+
+    #[test]
+    fn test_aggressive_filter_keeps_php_signatures() {
+        let code = r#"<?php
+namespace App\Models;
+
+use Illuminate\Database\Eloquent\Model;
+
+// Eloquent model
+class User extends Model
+{
+    public function posts(): HasMany
+    {
+        return $this->hasMany(Post::class);
+    }
+}
+"#;
+        let result = AggressiveFilter.filter(code, &Language::Php);
+        assert!(result.contains("use Illuminate\\Database\\Eloquent\\Model;"));
+        assert!(result.contains("class User extends Model"));
+        assert!(result.contains("public function posts(): HasMany"));
+        assert!(!result.contains("hasMany(Post::class)"));
+        assert!(!result.contains("// Eloquent model"));
     }
 
     #[test]
@@ -3,6 +3,7 @@
 //! Provides token-optimized alternatives to verbose `gh` commands.
 //! Focuses on extracting essential information from JSON outputs.
 
+use crate::ci_log;
 use crate::git;
 use crate::json_cmd;
 use crate::tracking;
@@ -19,6 +20,7 @@ pub fn run(subcommand: &str, args: &[String], verbose: u8, ultra_compact: bool)
         "run" => run_workflow(args, verbose, ultra_compact),
         "repo" => run_repo(args, verbose, ultra_compact),
         "api" => run_api(args, verbose),
+        "log-file" => run_log_file(args, verbose),
         _ => {
             // Unknown subcommand, pass through
             run_passthrough("gh", subcommand, args)
@@ -35,6 +37,7 @@ fn run_pr(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
         "list" => list_prs(&args[1..], verbose, ultra_compact),
         "view" => view_pr(&args[1..], verbose, ultra_compact),
         "checks" => pr_checks(&args[1..], verbose, ultra_compact),
+        "comments" => pr_comments(&args[1..], verbose),
         "status" => pr_status(verbose, ultra_compact),
         "create" => pr_create(&args[1..], verbose),
         "merge" => pr_merge(&args[1..], verbose),
@@ -302,6 +305,487 @@ fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     Ok(())
 }
 
+/// Review threads and reviews of a PR. Threads are paged with `$cursor`;
+/// each thread only needs its first and latest comment plus the count.
+const PR_COMMENTS_QUERY: &str = r#"query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
+  repository(owner: $owner, name: $repo) {
+    pullRequest(number: $number) {
+      number
+      title
+      author { login }
+      comments(last: 30) { nodes { author { login __typename } body } }
+      reviews(last: 50) { nodes { author { login } state body submittedAt } }
+      reviewThreads(first: 100, after: $cursor) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          isResolved
+          isOutdated
+          path
+          line
+          originalLine
+          comments(first: 1) {
+            totalCount
+            nodes { author { login } body createdAt diffHunk }
+          }
+          latestComment: comments(last: 1) {
+            nodes { author { login } body }
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+/// Review thread pages (of 100) fetched before giving up with a note.
+const MAX_THREAD_PAGES: usize = 10;
+
+/// Diff lines shown above each thread.
+const THREAD_SNIPPET_LINES: usize = 2;
+
+/// One comment of a review thread.
+#[derive(Debug, Clone, PartialEq)]
+struct ThreadComment {
+    author: String,
+    body: String,
+}
+
+/// A review thread anchored to a file line.
+#[derive(Debug)]
+struct ReviewThread {
+    path: String,
+    line: Option<u64>,
+    resolved: bool,
+    outdated: bool,
+    total: usize,
+    first: ThreadComment,
+    latest: ThreadComment,
+    /// Last lines of the diff hunk the thread is attached to
+    snippet: Vec<String>,
+}
+
+/// A reviewer's most recent review state.
+#[derive(Debug, PartialEq)]
+struct ReviewState {
+    author: String,
+    state: String,
+    body: String,
+}
+
+/// Parsed `gh api graphql` response for `rtk gh pr comments`.
+#[derive(Debug)]
+struct PrReviewDigest {
+    number: i64,
+    title: String,
+    author: String,
+    threads: Vec<ReviewThread>,
+    reviews: Vec<ReviewState>,
+    /// Top-level conversation comments by people (bots dropped)
+    comments: Vec<ThreadComment>,
+    /// More review threads exist than were fetched
+    truncated: bool,
+}
+
+fn parse_comment(node: &Value) -> ThreadComment {
+    ThreadComment {
+        author: node["author"]["login"]
+            .as_str()
+            .unwrap_or("ghost")
+            .to_string(),
+        body: node["body"].as_str().unwrap_or("").to_string(),
+    }
+}
+
+fn parse_review_digest(json: &Value) -> Option<PrReviewDigest> {
+    let pr = &json["data"]["repository"]["pullRequest"];
+    if !pr.is_object() {
+        return None;
+    }
+
+    let threads = pr["reviewThreads"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .filter_map(|t| {
+                    let comments = t["comments"]["nodes"].as_array()?;
+                    let first = parse_comment(comments.first()?);
+                    let latest = t["latestComment"]["nodes"]
+                        .as_array()
+                        .and_then(|nodes| nodes.last())
+                        .or(comments.last())
+                        .map(parse_comment)?;
+                    let hunk = comments[0]["diffHunk"].as_str().unwrap_or("");
+                    let hunk_lines: Vec<&str> =
+                        hunk.lines().filter(|l| !l.starts_with("@@")).collect();
+                    let snippet = hunk_lines
+                        [hunk_lines.len().saturating_sub(THREAD_SNIPPET_LINES)..]
+                        .iter()
+                        .map(|l| l.to_string())
+                        .collect();
+                    Some(ReviewThread {
+                        path: t["path"].as_str().unwrap_or("?").to_string(),
+                        line: t["line"].as_u64().or(t["originalLine"].as_u64()),
+                        resolved: t["isResolved"].as_bool().unwrap_or(false),
+                        outdated: t["isOutdated"].as_bool().unwrap_or(false),
+                        total: t["comments"]["totalCount"]
+                            .as_u64()
+                            .map(|n| n as usize)
+                            .unwrap_or(comments.len()),
+                        first,
+                        latest,
+                        snippet,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Keep each reviewer's latest review (nodes are oldest first)
+    let mut reviews: Vec<ReviewState> = Vec::new();
+    for node in pr["reviews"]["nodes"].as_array().into_iter().flatten() {
+        let review = ReviewState {
+            author: node["author"]["login"]
+                .as_str()
+                .unwrap_or("ghost")
+                .to_string(),
+            state: node["state"].as_str().unwrap_or("").to_string(),
+            body: node["body"].as_str().unwrap_or("").to_string(),
+        };
+        // Plain comments don't change a reviewer's verdict
+        if review.state == "COMMENTED" && reviews.iter().any(|r| r.author == review.author) {
+            continue;
+        }
+        reviews.retain(|r| r.author != review.author);
+        reviews.push(review);
+    }
+
+    let comments = pr["comments"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|c| c["author"]["__typename"].as_str() != Some("Bot"))
+        .map(parse_comment)
+        .collect();
+
+    Some(PrReviewDigest {
+        number: pr["number"].as_i64().unwrap_or(0),
+        title: pr["title"].as_str().unwrap_or("").to_string(),
+        author: pr["author"]["login"].as_str().unwrap_or("").to_string(),
+        threads,
+        reviews,
+        comments,
+        truncated: pr["reviewThreads"]["pageInfo"]["hasNextPage"]
+            .as_bool()
+            .unwrap_or(false),
+    })
+}
+
+/// Append a later page's review threads to the first response, keeping the
+/// last page's `pageInfo` so a remaining next page shows up as truncation.
+fn merge_thread_page(merged: &mut Value, page: &Value) {
+    let path = "/data/repository/pullRequest/reviewThreads";
+    let Some(next) = page.pointer(path) else {
+        return;
+    };
+    if let Some(threads) = merged.pointer_mut(path) {
+        if let (Some(nodes), Some(new_nodes)) =
+            (threads["nodes"].as_array_mut(), next["nodes"].as_array())
+        {
+            nodes.extend(new_nodes.iter().cloned());
+        }
+        threads["pageInfo"] = next["pageInfo"].clone();
+    }
+}
+
+/// First meaningful line of a comment body; suggestion blocks are labelled.
+fn comment_summary(body: &str, max_len: usize) -> String {
+    if body.contains("```suggestion") {
+        let code = body
+            .lines()
+            .skip_while(|l| !l.starts_with("```suggestion"))
+            .nth(1)
+            .unwrap_or("")
+            .trim();
+        return truncate(&format!("(suggestion) {}", code), max_len);
+    }
+    let line = body
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty() && !l.starts_with('>'))
+        .unwrap_or("");
+    truncate(line, max_len)
+}
+
+fn format_review_digest(digest: &PrReviewDigest, include_all: bool) -> String {
+    let visible: Vec<&ReviewThread> = digest
+        .threads
+        .iter()
+        .filter(|t| include_all || (!t.resolved && !t.outdated))
+        .collect();
+    let resolved = digest.threads.iter().filter(|t| t.resolved).count();
+    let outdated = digest
+        .threads
+        .iter()
+        .filter(|t| t.outdated && !t.resolved)
+        .count();
+
+    let mut out = vec![format!(
+        "💬 PR #{}: {}",
+        digest.number,
+        truncate(&digest.title, 60)
+    )];
+    out.push(if include_all {
+        format!(
+            "  {} threads ({} resolved, {} outdated)",
+            visible.len(),
+            resolved,
+            outdated
+        )
+    } else if resolved + outdated > 0 {
+        format!(
+            "  {} open threads ({} resolved, {} outdated hidden; --all to show)",
+            visible.len(),
+            resolved,
+            outdated
+        )
+    } else {
+        format!("  {} open threads", visible.len())
+    });
+    if digest.truncated {
+        out.push(format!(
+            "  ⚠️ truncated: only the first {} threads were fetched",
+            digest.threads.len()
+        ));
+    }
+
+    // Group by file, in first-seen order, then by line
+    let mut files: Vec<(&str, Vec<&ReviewThread>)> = Vec::new();
+    for thread in &visible {
+        match files.iter_mut().find(|(p, _)| *p == thread.path) {
+            Some((_, threads)) => threads.push(thread),
+            None => files.push((&thread.path, vec![thread])),
+        }
+    }
+    for (path, threads) in files.iter_mut() {
+        threads.sort_by_key(|t| t.line.unwrap_or(0));
+        out.push(String::new());
+        out.push(format!("📄 {}", path));
+        for thread in threads.iter() {
+            let line = thread
+                .line
+                .map(|l| format!("L{}", l))
+                .unwrap_or_else(|| "L?".to_string());
+            let mut tags = String::new();
+            if thread.resolved {
+                tags.push_str(" [resolved]");
+            }
+            if thread.outdated {
+                tags.push_str(" [outdated]");
+            }
+            out.push(format!(
+                "  {}{} @{}: {}",
+                line,
+                tags,
+                thread.first.author,
+                comment_summary(&thread.first.body, 90)
+            ));
+            for code in &thread.snippet {
+                out.push(format!("     │ {}", truncate(code, 80)));
+            }
+            if thread.total > 1 {
+                out.push(format!(
+                    "     └ @{} (latest of {}): {}",
+                    thread.latest.author,
+                    thread.total,
+                    comment_summary(&thread.latest.body, 80)
+                ));
+            }
+        }
+    }
+
+    let verdicts: Vec<String> = digest
+        .reviews
+        .iter()
+        .filter(|r| r.author != digest.author)
+        .filter_map(|r| match r.state.as_str() {
+            "APPROVED" => Some(format!("✓ @{}", r.author)),
+            "CHANGES_REQUESTED" => Some(format!("✗ @{} (changes requested)", r.author)),
+            _ => None,
+        })
+        .collect();
+    if !verdicts.is_empty() {
+        out.push(String::new());
+        out.push(format!("Reviews: {}", verdicts.join(", ")));
+    }
+
+    // Threads whose last word is not the PR author's still need a response
+    let mut todo: Vec<String> = visible
+        .iter()
+        .filter(|t| !t.resolved && t.latest.author != digest.author)
+        .map(|t| {
+            format!(
+                "  [ ] {}:{} {} (@{})",
+                t.path,
+                t.line.map(|l| l.to_string()).unwrap_or_else(|| "?".into()),
+                comment_summary(&t.latest.body, 70),
+                t.latest.author
+            )
+        })
+        .collect();
+    todo.extend(
+        digest
+            .reviews
+            .iter()
+            .filter(|r| r.state == "CHANGES_REQUESTED" && !r.body.trim().is_empty())
+            .map(|r| {
+                format!(
+                    "  [ ] {} (@{} review)",
+                    comment_summary(&r.body, 70),
+                    r.author
+                )
+            }),
+    );
+    // Requests left as plain PR comments rather than on a line
+    todo.extend(
+        digest
+            .comments
+            .iter()
+            .filter(|c| c.author != digest.author && !c.body.trim().is_empty())
+            .map(|c| {
+                format!(
+                    "  [ ] {} (@{} comment)",
+                    comment_summary(&c.body, 70),
+                    c.author
+                )
+            }),
+    );
+    let waiting = visible
+        .iter()
+        .filter(|t| !t.resolved && t.latest.author == digest.author)
+        .count();
+
+    out.push(String::new());
+    if todo.is_empty() {
+        out.push("✓ Nothing left to address".to_string());
+    } else {
+        out.push(format!("To address ({}):", todo.len()));
+        out.extend(todo);
+    }
+    if waiting > 0 {
+        out.push(format!(
+            "  ({} threads answered by @{}, awaiting reviewer)",
+            waiting, digest.author
+        ));
+    }
+
+    out.join("\n")
+}
+
+/// `gh pr` options that take a separate value, e.g. `-R owner/repo`.
+const VALUE_OPTIONS: &[&str] = &["-R", "--repo"];
+
+/// Arguments that are neither options nor the value of a preceding option.
+fn positional_args(args: &[String]) -> impl Iterator<Item = &String> {
+    args.iter().enumerate().filter_map(move |(i, arg)| {
+        let is_value = i > 0 && VALUE_OPTIONS.contains(&args[i - 1].as_str());
+        (!arg.starts_with('-') && !is_value).then_some(arg)
+    })
+}
+
+/// Value of the first of `names` given as `-R value` or `--repo=value`.
+fn option_value<'a>(args: &'a [String], names: &[&str]) -> Option<&'a str> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if names.contains(&arg.as_str()) {
+            return args.get(i + 1).map(String::as_str);
+        }
+        let (name, value) = arg.split_once('=')?;
+        names.contains(&name).then_some(value)
+    })
+}
+
+fn pr_comments(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let include_all = args.iter().any(|a| a == "--all");
+    let repo = option_value(args, &["-R", "--repo"]);
+    let pr_number = positional_args(args)
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("PR number required"))?;
+
+    if verbose > 0 {
+        eprintln!("gh api graphql (review threads of #{})", pr_number);
+    }
+
+    let mut raw = String::new();
+    let mut json: Option<Value> = None;
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_THREAD_PAGES {
+        let mut cmd = Command::new("gh");
+        // `gh api` fills {owner}/{repo} from GH_REPO instead of -R
+        if let Some(repo) = repo {
+            cmd.env("GH_REPO", repo);
+        }
+        cmd.args([
+            "api",
+            "graphql",
+            "-F",
+            "owner={owner}",
+            "-F",
+            "repo={repo}",
+            "-F",
+            &format!("number={}", pr_number),
+            "-f",
+            &format!("query={}", PR_COMMENTS_QUERY),
+        ]);
+        if let Some(cursor) = &cursor {
+            cmd.args(["-f", &format!("cursor={}", cursor)]);
+        }
+        let output = cmd.output().context("Failed to run gh api graphql")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            eprintln!("{}", stderr.trim());
+            std::process::exit(output.status.code().unwrap_or(1));
+        }
+
+        let page_raw = String::from_utf8_lossy(&output.stdout);
+        raw.push_str(&page_raw);
+        let page: Value =
+            serde_json::from_str(&page_raw).context("Failed to parse gh api output")?;
+        let page_info =
+            page["data"]["repository"]["pullRequest"]["reviewThreads"]["pageInfo"].clone();
+        json = Some(match json {
+            None => page,
+            Some(mut merged) => {
+                merge_thread_page(&mut merged, &page);
+                merged
+            }
+        });
+
+        match page_info["endCursor"].as_str() {
+            Some(end) if page_info["hasNextPage"].as_bool() == Some(true) => {
+                cursor = Some(end.to_string())
+            }
+            _ => break,
+        }
+    }
+
+    let json = json.unwrap_or_default();
+    let digest =
+        parse_review_digest(&json).ok_or_else(|| anyhow::anyhow!("PR #{} not found", pr_number))?;
+
+    let filtered = format_review_digest(&digest, include_all);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("gh api graphql (pr {} review threads)", pr_number),
+        &format!("rtk gh pr comments {}", pr_number),
+        &raw,
+        &filtered,
+    );
+    Ok(())
+}
+
 fn pr_checks(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -692,7 +1176,17 @@ fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     Ok(())
 }
 
-fn view_run(args: &[String], _verbose: u8) -> Result<()> {
+fn view_run(args: &[String], verbose: u8) -> Result<()> {
+    if args.iter().any(|a| a == "--log-failed") {
+        return view_run_log(args, verbose);
+    }
+    // Full logs were asked for explicitly; the failed-steps filter would drop them
+    if args.iter().any(|a| a == "--log") {
+        let mut passthrough = vec!["view".to_string()];
+        passthrough.extend(args.iter().cloned());
+        return run_passthrough("gh", "run", &passthrough);
+    }
+
     let timer = tracking::TimedExecution::start();
 
     if args.is_empty() {
@@ -760,6 +1254,64 @@ fn view_run(args: &[String], _verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// `gh run view --log-failed`: failing steps only, through the matching rtk filter
+fn view_run_log(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("gh run view {}", args.join(" "));
+    }
+
+    let output = Command::new("gh")
+        .args(["run", "view"])
+        .args(args)
+        .output()
+        .context("Failed to run gh run view")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+
+    let raw = String::from_utf8_lossy(&output.stdout);
+    let filtered = ci_log::filter_ci_log(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("gh run view {}", args.join(" ")),
+        &format!("rtk gh run view {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+    Ok(())
+}
+
+/// Filter a downloaded GitHub Actions log file offline
+fn run_log_file(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let path = args
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("Log file path required"))?;
+    if verbose > 0 {
+        eprintln!("Filtering CI log: {}", path);
+    }
+
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read log file {}", path))?;
+    let filtered = ci_log::filter_ci_log(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("cat {}", path),
+        &format!("rtk gh log-file {}", path),
+        &raw,
+        &filtered,
+    );
+    Ok(())
+}
+
 fn run_repo(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
     // Parse subcommand (default to "view")
     let (subcommand, rest_args) = if args.is_empty() {
@@ -1135,4 +1687,134 @@ mod tests {
         let result = ok_confirmation("edited", "#42");
         assert_eq!(result, "ok edited #42");
     }
+
+    const REVIEW_THREADS_FIXTURE: &str =
+        include_str!("../tests/fixtures/gh_pr_review_threads.json");
+
+    #[test]
+    fn test_parse_review_digest() {
+        let json: Value = serde_json::from_str(REVIEW_THREADS_FIXTURE).unwrap();
+        let digest = parse_review_digest(&json).unwrap();
+        assert_eq!(digest.number, 42);
+        assert_eq!(digest.author, "dev");
+        assert_eq!(digest.threads.len(), 5);
+        // Bot conversation comments are dropped
+        assert_eq!(digest.comments.len(), 2);
+
+        let first = &digest.threads[0];
+        assert_eq!(first.path, "src/git.rs");
+        assert_eq!(first.line, Some(120));
+        assert_eq!(first.total, 3);
+        assert_eq!(first.latest.body, "Also please cover it with a test.");
+        assert_eq!(
+            first.snippet,
+            vec![
+                "+    let lines: Vec<&str> = stdout.lines().collect();",
+                "+    let first = lines[0];"
+            ]
+        );
+        // Outdated thread falls back to its original line
+        assert_eq!(digest.threads[4].line, Some(300));
+
+        // Latest verdict per reviewer
+        assert_eq!(digest.reviews.len(), 2);
+        assert!(digest
+            .reviews
+            .iter()
+            .any(|r| r.author == "alice" && r.state == "CHANGES_REQUESTED"));
+    }
+
+    #[test]
+    fn test_format_review_digest() {
+        let json: Value = serde_json::from_str(REVIEW_THREADS_FIXTURE).unwrap();
+        let digest = parse_review_digest(&json).unwrap();
+        let result = format_review_digest(&digest, false);
+
+        assert!(result.starts_with("💬 PR #42: Add structured log modes\n  3 open threads (1 resolved, 1 outdated hidden; --all to show)"));
+        // Grouped by file and sorted by line
+        let git_rs = result.find("📄 src/git.rs").unwrap();
+        let l45 = result
+            .find("  L45 @bob: (suggestion) GitCommand::Log")
+            .unwrap();
+        let l120 = result.find("  L120 @alice: This panics").unwrap();
+        assert!(git_rs < l45 && l45 < l120);
+        assert!(result.contains("     │ +    let first = lines[0];"));
+        assert!(result.contains("     └ @alice (latest of 3): Also please cover it with a test."));
+        assert!(!result.contains("Good catch"));
+        assert!(!result.contains("Typo in the doc comment"));
+        assert!(!result.contains("Rename this helper"));
+
+        assert!(result.contains("Reviews: ✓ @bob, ✗ @alice (changes requested)"));
+        assert!(result.contains("To address (4):"));
+        assert!(result.contains("  [ ] Could you also update the CHANGELOG? (@carol comment)"));
+        assert!(!result.contains("addressing this today"));
+        assert!(!result.contains("Codecov"));
+        assert!(result.contains("  [ ] src/git.rs:120 Also please cover it with a test. (@alice)"));
+        assert!(
+            result.contains("  [ ] README.md:12 Document the --summary flag here too. (@alice)")
+        );
+        assert!(result.contains(
+            "  [ ] Please add tests for the empty-log case before merging. (@alice review)"
+        ));
+        assert!(result.contains("(1 threads answered by @dev, awaiting reviewer)"));
+
+        let all = format_review_digest(&digest, true);
+        assert!(all.contains("  5 threads (1 resolved, 1 outdated)"));
+        assert!(all.contains("L30 [resolved] @bob: Typo in the doc comment."));
+        assert!(all.contains("L300 [outdated] @alice: Rename this helper."));
+    }
+
+    #[test]
+    fn test_pr_comments_positional_args() {
+        let args: Vec<String> = ["-R", "owner/repo", "12", "--all"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            positional_args(&args).next().map(String::as_str),
+            Some("12")
+        );
+        assert_eq!(option_value(&args, &["-R", "--repo"]), Some("owner/repo"));
+
+        let args = vec!["--repo=owner/repo".to_string(), "7".to_string()];
+        assert_eq!(positional_args(&args).next().map(String::as_str), Some("7"));
+        assert_eq!(option_value(&args, &["-R", "--repo"]), Some("owner/repo"));
+    }
+
+    #[test]
+    fn test_review_thread_pages_merge_and_truncate() {
+        let mut json: Value = serde_json::from_str(REVIEW_THREADS_FIXTURE).unwrap();
+        assert!(!parse_review_digest(&json).unwrap().truncated);
+
+        let mut page: Value = serde_json::from_str(REVIEW_THREADS_FIXTURE).unwrap();
+        let threads = &mut page["data"]["repository"]["pullRequest"]["reviewThreads"];
+        threads["nodes"].as_array_mut().unwrap().truncate(1);
+        threads["pageInfo"] = serde_json::json!({ "hasNextPage": true, "endCursor": "Y3Vyc29y" });
+        merge_thread_page(&mut json, &page);
+
+        let digest = parse_review_digest(&json).unwrap();
+        assert_eq!(digest.threads.len(), 6);
+        assert!(digest.truncated);
+        assert!(format_review_digest(&digest, false)
+            .contains("⚠️ truncated: only the first 6 threads were fetched"));
+    }
+
+    #[test]
+    fn test_review_thread_latest_comment_alias() {
+        let json = serde_json::json!({ "data": { "repository": { "pullRequest": {
+            "number": 7, "title": "t", "author": { "login": "dev" },
+            "reviews": { "nodes": [] },
+            "reviewThreads": { "nodes": [{
+                "path": "a.rs", "line": 3, "isResolved": false, "isOutdated": false,
+                "comments": { "totalCount": 80, "nodes": [
+                    { "author": { "login": "bob" }, "body": "first", "diffHunk": "" }
+                ] },
+                "latestComment": { "nodes": [{ "author": { "login": "dev" }, "body": "fixed" }] }
+            }] }
+        } } } });
+        let thread = &parse_review_digest(&json).unwrap().threads[0];
+        assert_eq!(thread.total, 80);
+        assert_eq!(thread.first.body, "first");
+        assert_eq!(thread.latest.author, "dev");
+    }
 }
@@ -1,5 +1,9 @@
+use crate::filter::Language;
+use crate::lockfile_diff::{self, SpecialFile};
 use crate::tracking;
+use crate::utils::truncate;
 use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsString;
 use std::process::Command;
 
@@ -7,7 +11,7 @@ use std::process::Command;
 pub enum GitCommand {
     Diff,
     Log,
-    Status,
+    Status { ultra_compact: bool },
     Show,
     Add,
     Commit { message: String },
@@ -17,13 +21,16 @@ pub enum GitCommand {
     Fetch,
     Stash { subcommand: Option<String> },
     Worktree,
+    Conflicts,
+    Blame,
+    Compare { base: String, head: Option<String> },
 }
 
 pub fn run(cmd: GitCommand, args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
     match cmd {
         GitCommand::Diff => run_diff(args, max_lines, verbose),
         GitCommand::Log => run_log(args, max_lines, verbose),
-        GitCommand::Status => run_status(args, verbose),
+        GitCommand::Status { ultra_compact } => run_status(args, ultra_compact, verbose),
         GitCommand::Show => run_show(args, max_lines, verbose),
         GitCommand::Add => run_add(args, verbose),
         GitCommand::Commit { message } => run_commit(&message, verbose),
@@ -33,37 +40,45 @@ pub fn run(cmd: GitCommand, args: &[String], max_lines: Option<usize>, verbose:
         GitCommand::Fetch => run_fetch(args, verbose),
         GitCommand::Stash { subcommand } => run_stash(subcommand.as_deref(), args, verbose),
         GitCommand::Worktree => run_worktree(args, verbose),
+        GitCommand::Conflicts => run_conflicts(verbose),
+        GitCommand::Blame => run_blame(args, verbose),
+        GitCommand::Compare { base, head } => run_compare(&base, head.as_deref(), verbose),
     }
 }
 
 fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
-    // Check if user wants stat output
-    let wants_stat = args
-        .iter()
-        .any(|arg| arg == "--stat" || arg == "--numstat" || arg == "--shortstat");
+    // Check if user wants stat (or other non-patch) output
+    let wants_stat = args.iter().any(|arg| {
+        arg == "--stat"
+            || arg == "--numstat"
+            || arg == "--shortstat"
+            || arg == "--name-only"
+            || arg == "--name-status"
+    });
 
     // Check if user wants compact diff (default RTK behavior)
     let wants_compact = !args.iter().any(|arg| arg == "--no-compact");
 
-    if wants_stat || !wants_compact {
-        // User wants stat or explicitly no compacting - pass through directly
-        let mut cmd = Command::new("git");
-        cmd.arg("diff");
-        for arg in args {
-            cmd.arg(arg);
-        }
+    let mut cmd = Command::new("git");
+    cmd.arg("diff");
+    for arg in args.iter().filter(|a| *a != "--no-compact") {
+        cmd.arg(arg);
+    }
 
-        let output = cmd.output().context("Failed to run git diff")?;
+    let output = cmd.output().context("Failed to run git diff")?;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            eprintln!("{}", stderr);
-            std::process::exit(output.status.code().unwrap_or(1));
-        }
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("{}", stderr);
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if wants_stat || !wants_compact {
+        // User wants stat or explicitly no compacting - pass through directly
         println!("{}", stdout.trim());
 
         timer.track(
@@ -76,38 +91,18 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
         return Ok(());
     }
 
-    // Default RTK behavior: stat first, then compacted diff
-    let mut cmd = Command::new("git");
-    cmd.arg("diff").arg("--stat");
-
-    for arg in args {
-        cmd.arg(arg);
-    }
-
-    let output = cmd.output().context("Failed to run git diff")?;
-    let stat_stdout = String::from_utf8_lossy(&output.stdout);
-
     if verbose > 0 {
         eprintln!("Git diff summary:");
     }
 
-    // Print stat summary first
-    println!("{}", stat_stdout.trim());
-
-    // Now get actual diff but compact it
-    let mut diff_cmd = Command::new("git");
-    diff_cmd.arg("diff");
-    for arg in args {
-        diff_cmd.arg(arg);
-    }
-
-    let diff_output = diff_cmd.output().context("Failed to run git diff")?;
-    let diff_stdout = String::from_utf8_lossy(&diff_output.stdout);
+    // Default RTK behavior: stat first, then compacted diff (both from the same patch)
+    let stat = format_diff_stat(&stdout);
+    println!("{}", stat.trim());
 
-    let mut final_output = stat_stdout.to_string();
-    if !diff_stdout.is_empty() {
+    let mut final_output = stat;
+    if !stdout.is_empty() {
         println!("\n--- Changes ---");
-        let compacted = compact_diff(&diff_stdout, max_lines.unwrap_or(100));
+        let compacted = compact_diff(&stdout, max_lines.unwrap_or(100));
         println!("{}", compacted);
         final_output.push_str("\n--- Changes ---\n");
         final_output.push_str(&compacted);
@@ -116,7 +111,7 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     timer.track(
         &format!("git diff {}", args.join(" ")),
         &format!("rtk git diff {}", args.join(" ")),
-        &format!("{}\n{}", stat_stdout, diff_stdout),
+        &stdout,
         &final_output,
     );
 
@@ -160,56 +155,38 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
         return Ok(());
     }
 
-    // Get raw output for tracking
-    let mut raw_cmd = Command::new("git");
-    raw_cmd.arg("show");
-    for arg in args {
-        raw_cmd.arg(arg);
-    }
-    let raw_output = raw_cmd
-        .output()
-        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
-        .unwrap_or_default();
-
-    // Step 1: one-line commit summary
-    let mut summary_cmd = Command::new("git");
-    summary_cmd.args(["show", "--no-patch", "--pretty=format:%h %s (%ar) <%an>"]);
+    // Single call: one-line commit summary followed by the patch
+    let mut cmd = Command::new("git");
+    cmd.args(["show", "--pretty=format:%h %s (%ar) <%an>"]);
     for arg in args {
-        summary_cmd.arg(arg);
+        cmd.arg(arg);
     }
-    let summary_output = summary_cmd.output().context("Failed to run git show")?;
-    if !summary_output.status.success() {
-        let stderr = String::from_utf8_lossy(&summary_output.stderr);
+    let output = cmd.output().context("Failed to run git show")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
         eprintln!("{}", stderr);
-        std::process::exit(summary_output.status.code().unwrap_or(1));
+        std::process::exit(output.status.code().unwrap_or(1));
     }
-    let summary = String::from_utf8_lossy(&summary_output.stdout);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (summary, patch) = split_show_output(&stdout);
+
+    // Step 1: one-line commit summary
     println!("{}", summary.trim());
 
     // Step 2: --stat summary
-    let mut stat_cmd = Command::new("git");
-    stat_cmd.args(["show", "--stat", "--pretty=format:"]);
-    for arg in args {
-        stat_cmd.arg(arg);
-    }
-    let stat_output = stat_cmd.output().context("Failed to run git show --stat")?;
-    let stat_stdout = String::from_utf8_lossy(&stat_output.stdout);
-    let stat_text = stat_stdout.trim();
+    let stat = format_diff_stat(patch);
+    let stat_text = stat.trim();
     if !stat_text.is_empty() {
         println!("{}", stat_text);
     }
 
     // Step 3: compacted diff
-    let mut diff_cmd = Command::new("git");
-    diff_cmd.args(["show", "--pretty=format:"]);
-    for arg in args {
-        diff_cmd.arg(arg);
-    }
-    let diff_output = diff_cmd.output().context("Failed to run git show (diff)")?;
-    let diff_stdout = String::from_utf8_lossy(&diff_output.stdout);
-    let diff_text = diff_stdout.trim();
+    let diff_text = patch.trim();
 
     let mut final_output = summary.to_string();
+    if !stat_text.is_empty() {
+        final_output.push_str(&format!("\n{}", stat_text));
+    }
     if !diff_text.is_empty() {
         if verbose > 0 {
             println!("\n--- Changes ---");
@@ -222,80 +199,661 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     timer.track(
         &format!("git show {}", args.join(" ")),
         &format!("rtk git show {}", args.join(" ")),
-        &raw_output,
+        &stdout,
         &final_output,
     );
 
     Ok(())
 }
 
-pub(crate) fn compact_diff(diff: &str, max_lines: usize) -> String {
-    let mut result = Vec::new();
-    let mut current_file = String::new();
-    let mut added = 0;
-    let mut removed = 0;
+/// Split `git show` output into the commit header and the patch.
+fn split_show_output(output: &str) -> (&str, &str) {
+    let mut offset = 0;
+    for line in output.split_inclusive('\n') {
+        if line.starts_with("diff --git") || line.starts_with("diff --cc") {
+            return (&output[..offset], &output[offset..]);
+        }
+        offset += line.len();
+    }
+    (output, "")
+}
+
+/// Line counts for one file of a unified diff.
+#[derive(Debug, PartialEq)]
+struct FileStat {
+    name: String,
+    added: usize,
+    removed: usize,
+    binary: bool,
+}
+
+/// Per-file stats from a unified diff, in diff order.
+fn parse_diff_stats(diff: &str) -> Vec<FileStat> {
+    #[derive(Default)]
+    struct Pending {
+        header_path: String,
+        old_path: Option<String>,
+        new_path: Option<String>,
+        rename_from: Option<String>,
+        rename_to: Option<String>,
+        added: usize,
+        removed: usize,
+        binary: bool,
+    }
+
+    fn finish(p: Pending) -> FileStat {
+        let name = match (p.rename_from, p.rename_to) {
+            (Some(from), Some(to)) => pprint_rename(&from, &to),
+            _ => p
+                .new_path
+                .filter(|n| n != "/dev/null")
+                .or(p.old_path.filter(|n| n != "/dev/null"))
+                .unwrap_or(p.header_path),
+        };
+        FileStat {
+            name,
+            added: p.added,
+            removed: p.removed,
+            binary: p.binary,
+        }
+    }
+
+    let mut stats = Vec::new();
+    let mut current: Option<Pending> = None;
     let mut in_hunk = false;
-    let mut hunk_lines = 0;
-    let max_hunk_lines = 10;
 
     for line in diff.lines() {
-        if line.starts_with("diff --git") {
-            // New file
-            if !current_file.is_empty() && (added > 0 || removed > 0) {
-                result.push(format!("  +{} -{}", added, removed));
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            if let Some(p) = current.take() {
+                stats.push(finish(p));
+            }
+            current = Some(Pending {
+                header_path: header_path(rest),
+                ..Default::default()
+            });
+            in_hunk = false;
+            continue;
+        }
+        if line.starts_with("diff --cc ") {
+            // Combined (merge) diffs have no per-file stat
+            if let Some(p) = current.take() {
+                stats.push(finish(p));
             }
-            current_file = line.split(" b/").nth(1).unwrap_or("unknown").to_string();
-            result.push(format!("\n📄 {}", current_file));
-            added = 0;
-            removed = 0;
             in_hunk = false;
-        } else if line.starts_with("@@") {
-            // New hunk
+            continue;
+        }
+        let Some(p) = current.as_mut() else {
+            continue;
+        };
+
+        if line.starts_with("@@") {
             in_hunk = true;
-            hunk_lines = 0;
-            let hunk_info = line.split("@@").nth(1).unwrap_or("").trim();
-            result.push(format!("  @@ {} @@", hunk_info));
         } else if in_hunk {
-            if line.starts_with('+') && !line.starts_with("+++") {
-                added += 1;
-                if hunk_lines < max_hunk_lines {
-                    result.push(format!("  {}", line));
-                    hunk_lines += 1;
-                }
-            } else if line.starts_with('-') && !line.starts_with("---") {
-                removed += 1;
-                if hunk_lines < max_hunk_lines {
-                    result.push(format!("  {}", line));
-                    hunk_lines += 1;
-                }
-            } else if hunk_lines < max_hunk_lines && !line.starts_with("\\") {
-                // Context line
-                if hunk_lines > 0 {
-                    result.push(format!("  {}", line));
-                    hunk_lines += 1;
-                }
+            if line.starts_with('+') {
+                p.added += 1;
+            } else if line.starts_with('-') {
+                p.removed += 1;
+            }
+        } else if let Some(path) = line.strip_prefix("--- ") {
+            p.old_path = Some(strip_diff_prefix(path, "a/"));
+        } else if let Some(path) = line.strip_prefix("+++ ") {
+            p.new_path = Some(strip_diff_prefix(path, "b/"));
+        } else if let Some(path) = line
+            .strip_prefix("rename from ")
+            .or_else(|| line.strip_prefix("copy from "))
+        {
+            p.rename_from = Some(path.to_string());
+        } else if let Some(path) = line
+            .strip_prefix("rename to ")
+            .or_else(|| line.strip_prefix("copy to "))
+        {
+            p.rename_to = Some(path.to_string());
+        } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+            p.binary = true;
+        }
+    }
+
+    if let Some(p) = current.take() {
+        stats.push(finish(p));
+    }
+
+    stats
+}
+
+/// Path from a `diff --git a/x b/x` header (the `b/` side).
+fn header_path(rest: &str) -> String {
+    // Same path on both sides: split exactly in the middle
+    if rest.len() % 2 == 1 {
+        let half = rest.len() / 2;
+        if rest.is_char_boundary(half) {
+            let (a, b) = (&rest[..half], &rest[half + 1..]);
+            if a.strip_prefix("a/").is_some() && a.get(2..) == b.get(2..) {
+                return b[2..].to_string();
             }
+        }
+    }
+    rest.split(" b/").nth(1).unwrap_or(rest).to_string()
+}
+
+fn strip_diff_prefix(path: &str, prefix: &str) -> String {
+    let path = path.trim_end_matches('\t');
+    path.strip_prefix(prefix).unwrap_or(path).to_string()
+}
+
+/// Rename display as git prints it in `--stat`: `src/{old => new}/file.rs`.
+fn pprint_rename(a: &str, b: &str) -> String {
+    let (ab, bb) = (a.as_bytes(), b.as_bytes());
+
+    // Common prefix, ending in a slash
+    let mut pfx = 0;
+    for (i, (x, y)) in ab.iter().zip(bb.iter()).enumerate() {
+        if x != y {
+            break;
+        }
+        if *x == b'/' {
+            pfx = i + 1;
+        }
+    }
+
+    // Common suffix, starting at a slash (may reuse the prefix's slash)
+    let floor = pfx.saturating_sub(1);
+    let mut sfx = 0;
+    let (mut i, mut j) = (ab.len(), bb.len());
+    while i > floor && j > floor && ab[i - 1] == bb[j - 1] {
+        i -= 1;
+        j -= 1;
+        if ab[i] == b'/' {
+            sfx = ab.len() - i;
+        }
+    }
+
+    let a_mid = &a[pfx..a.len().saturating_sub(sfx).max(pfx)];
+    let b_mid = &b[pfx..b.len().saturating_sub(sfx).max(pfx)];
+
+    if pfx + sfx > 0 {
+        format!(
+            "{}{{{} => {}}}{}",
+            &a[..pfx],
+            a_mid,
+            b_mid,
+            &a[a.len() - sfx..]
+        )
+    } else {
+        format!("{} => {}", a, b)
+    }
+}
+
+/// Width git assumes for `--stat` when stdout is not a terminal.
+const STAT_WIDTH: usize = 80;
+
+/// Render `git diff --stat` output from a unified diff, without calling git again.
+///
+/// Binary files show as `Bin` since byte sizes are not part of the patch.
+pub(crate) fn format_diff_stat(diff: &str) -> String {
+    let stats = parse_diff_stats(diff);
+    if stats.is_empty() {
+        return String::new();
+    }
+
+    let max_change = stats.iter().map(|s| s.added + s.removed).max().unwrap_or(0);
+    let max_len = stats
+        .iter()
+        .map(|s| s.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut number_width = max_change.to_string().len();
+    if stats.iter().any(|s| s.binary) {
+        number_width = number_width.max(3);
+    }
+
+    // Same width distribution as git's show_stats()
+    let width = STAT_WIDTH.max(16 + 6 + number_width);
+    let mut graph_width = max_change;
+    let mut name_width = max_len;
+    if name_width + number_width + 6 + graph_width > width {
+        let graph_cap = (width * 3 / 8).saturating_sub(number_width + 6);
+        if graph_width > graph_cap {
+            graph_width = graph_cap.max(6);
+        }
+        if name_width > width - number_width - 6 - graph_width {
+            name_width = width - number_width - 6 - graph_width;
+        } else {
+            graph_width = width - number_width - 6 - name_width;
+        }
+    }
+
+    let scale = |n: usize| {
+        if n == 0 {
+            0
+        } else {
+            1 + n * (graph_width - 1) / max_change
+        }
+    };
+
+    let mut out = String::new();
+    let (mut insertions, mut deletions) = (0, 0);
+
+    for stat in &stats {
+        insertions += stat.added;
+        deletions += stat.removed;
 
-            if hunk_lines == max_hunk_lines {
-                result.push("  ... (truncated)".to_string());
-                hunk_lines += 1;
+        let (prefix, name) = fit_name(&stat.name, name_width);
+        let padding = name_width.saturating_sub(prefix.len() + name.chars().count());
+        out.push_str(&format!(" {}{}{} |", prefix, name, " ".repeat(padding)));
+
+        if stat.binary {
+            out.push_str(&format!(" {:>w$}\n", "Bin", w = number_width));
+            continue;
+        }
+
+        let total = stat.added + stat.removed;
+        let (mut add, mut del) = (stat.added, stat.removed);
+        if graph_width <= max_change {
+            let mut scaled = scale(add + del);
+            if scaled < 2 && add > 0 && del > 0 {
+                scaled = 2;
+            }
+            if add < del {
+                add = scale(add);
+                del = scaled - add;
+            } else {
+                del = scale(del);
+                add = scaled - del;
             }
         }
+        out.push_str(&format!(
+            " {:>w$}{}{}{}\n",
+            total,
+            if total > 0 { " " } else { "" },
+            "+".repeat(add),
+            "-".repeat(del),
+            w = number_width
+        ));
+    }
+
+    let files = stats.len();
+    out.push_str(&format!(
+        " {} file{} changed",
+        files,
+        if files == 1 { "" } else { "s" }
+    ));
+    if insertions > 0 || deletions == 0 {
+        out.push_str(&format!(
+            ", {} insertion{}(+)",
+            insertions,
+            if insertions == 1 { "" } else { "s" }
+        ));
+    }
+    if deletions > 0 || insertions == 0 {
+        out.push_str(&format!(
+            ", {} deletion{}(-)",
+            deletions,
+            if deletions == 1 { "" } else { "s" }
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+/// Shorten a stat name to `width` columns the way git does (`.../tail`).
+fn fit_name(name: &str, width: usize) -> (&'static str, &str) {
+    let len = name.chars().count();
+    if len <= width {
+        return ("", name);
+    }
+    let keep = width.saturating_sub(3);
+    let start = name
+        .char_indices()
+        .nth(len - keep)
+        .map(|(i, _)| i)
+        .unwrap_or(name.len());
+    let tail = &name[start..];
+    let tail = tail.find('/').map(|i| &tail[i..]).unwrap_or(tail);
+    ("...", tail)
+}
+
+/// One file of a parsed unified diff.
+struct DiffFile<'a> {
+    name: String,
+    special: Option<SpecialFile>,
+    hunks: Vec<DiffHunk<'a>>,
+    added: usize,
+    removed: usize,
+}
+
+/// One `@@` hunk: its range, enclosing scope and body lines.
+struct DiffHunk<'a> {
+    range: String,
+    scope: Option<String>,
+    lines: Vec<&'a str>,
+}
+
+impl DiffHunk<'_> {
+    fn changed(&self) -> usize {
+        self.lines.iter().filter(|l| is_changed_line(l)).count()
+    }
+
+    fn context(&self) -> usize {
+        self.lines.len() - self.changed()
+    }
+}
+
+fn is_changed_line(line: &str) -> bool {
+    line.starts_with('+') || line.starts_with('-')
+}
+
+/// Compact a unified diff to roughly `max_lines` lines.
+///
+/// The line budget is shared fairly across files, then across each file's
+/// hunks, so one large file cannot starve the others. Changed lines are
+/// budgeted before context, and long hunks keep their first and last changes
+/// around an explicit "N lines elided in hunk" marker. Hunk headers always
+/// carry the enclosing function/type name when one can be found.
+pub(crate) fn compact_diff(diff: &str, max_lines: usize) -> String {
+    let files = parse_diff_files(diff);
+
+    // Fixed cost per file: header, stats line, and hunk headers or summary
+    let overhead: Vec<usize> = files
+        .iter()
+        .map(|f| {
+            let body = match f.special {
+                Some(kind) => special_summary(kind, f).len(),
+                None => f.hunks.len(),
+            };
+            2 + body
+        })
+        .collect();
 
-        if result.len() >= max_lines {
-            result.push("\n... (more changes truncated)".to_string());
+    // Not even the headers fit: show as many files as possible, bodies elided
+    let mut shown = files.len();
+    let mut used = 0;
+    for (i, cost) in overhead.iter().enumerate() {
+        if used + cost > max_lines {
+            shown = i.max(1);
             break;
         }
+        used += cost;
+    }
+    let body_budget = max_lines.saturating_sub(used);
+
+    let regular: Vec<&DiffFile> = files[..shown]
+        .iter()
+        .filter(|f| f.special.is_none())
+        .collect();
+
+    // Tier 1: changed lines; tier 2: context, only for fully shown files
+    let changed: Vec<usize> = regular
+        .iter()
+        .map(|f| f.hunks.iter().map(|h| h.changed()).sum())
+        .collect();
+    let changed_alloc = fair_share(&changed, body_budget);
+    let leftover = body_budget - changed_alloc.iter().sum::<usize>();
+    let context: Vec<usize> = regular
+        .iter()
+        .zip(changed.iter().zip(&changed_alloc))
+        .map(|(f, (want, got))| {
+            if got >= want {
+                f.hunks.iter().map(|h| h.context()).sum()
+            } else {
+                0
+            }
+        })
+        .collect();
+    let context_alloc = fair_share(&context, leftover);
+
+    let mut result = Vec::new();
+    let mut regular_idx = 0;
+    for file in &files[..shown] {
+        result.push(format!("\n📄 {}", file.name));
+
+        if let Some(kind) = file.special {
+            result.extend(
+                special_summary(kind, file)
+                    .into_iter()
+                    .map(|l| format!("  {}", l)),
+            );
+        } else {
+            let (file_changed, file_context) =
+                (changed_alloc[regular_idx], context_alloc[regular_idx]);
+            regular_idx += 1;
+
+            let hunk_changed: Vec<usize> = file.hunks.iter().map(|h| h.changed()).collect();
+            let hunk_changed_alloc = fair_share(&hunk_changed, file_changed);
+            let hunk_context: Vec<usize> = file
+                .hunks
+                .iter()
+                .zip(hunk_changed.iter().zip(&hunk_changed_alloc))
+                .map(|(h, (want, got))| if got >= want { h.context() } else { 0 })
+                .collect();
+            let hunk_context_alloc = fair_share(&hunk_context, file_context);
+
+            for (i, hunk) in file.hunks.iter().enumerate() {
+                render_hunk(
+                    &mut result,
+                    hunk,
+                    hunk_changed_alloc[i],
+                    hunk_context_alloc[i],
+                );
+            }
+        }
+
+        if file.added > 0 || file.removed > 0 {
+            result.push(format!("  +{} -{}", file.added, file.removed));
+        }
     }
 
-    if !current_file.is_empty() && (added > 0 || removed > 0) {
-        result.push(format!("  +{} -{}", added, removed));
+    if shown < files.len() {
+        result.push(format!(
+            "\n... +{} more files (truncated)",
+            files.len() - shown
+        ));
     }
 
     result.join("\n")
 }
 
+fn parse_diff_files(diff: &str) -> Vec<DiffFile<'_>> {
+    let mut files: Vec<DiffFile> = Vec::new();
+    let mut in_hunk = false;
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git") {
+            let name = line.split(" b/").nth(1).unwrap_or("unknown").to_string();
+            files.push(DiffFile {
+                special: lockfile_diff::classify_path(&name),
+                name,
+                hunks: Vec::new(),
+                added: 0,
+                removed: 0,
+            });
+            in_hunk = false;
+            continue;
+        }
+        let Some(file) = files.last_mut() else {
+            continue;
+        };
+
+        if line.starts_with("@@") {
+            in_hunk = true;
+            let mut parts = line.splitn(3, "@@");
+            let range = parts.nth(1).unwrap_or("").trim().to_string();
+            let section = parts.next().unwrap_or("").trim();
+            file.hunks.push(DiffHunk {
+                range,
+                scope: (!section.is_empty()).then(|| section.to_string()),
+                lines: Vec::new(),
+            });
+        } else if in_hunk && !line.starts_with('\\') {
+            if line.starts_with('+') {
+                file.added += 1;
+            } else if line.starts_with('-') {
+                file.removed += 1;
+            }
+            if let Some(hunk) = file.hunks.last_mut() {
+                hunk.lines.push(line);
+            }
+        }
+    }
+
+    // A scope opened in the leading context is closer than git's section
+    // header (which names the scope above the hunk start), so prefer it.
+    for file in &mut files {
+        let ext = file.name.rsplit('.').next().unwrap_or("");
+        let lang = Language::from_extension(ext);
+        for hunk in &mut file.hunks {
+            let scanned = hunk
+                .lines
+                .iter()
+                .take_while(|l| !is_changed_line(l))
+                .map(|l| l.get(1..).unwrap_or(""))
+                .filter(|l| lang.is_scope_header(l))
+                .last();
+            if let Some(scope) = scanned {
+                hunk.scope = Some(scope.trim().to_string());
+            }
+        }
+    }
+
+    files
+}
+
+fn special_summary(kind: SpecialFile, file: &DiffFile) -> Vec<String> {
+    let lines: Vec<&str> = file
+        .hunks
+        .iter()
+        .flat_map(|h| h.lines.iter().copied())
+        .collect();
+    lockfile_diff::summarize_hunks(kind, &lines)
+}
+
+fn render_hunk(
+    result: &mut Vec<String>,
+    hunk: &DiffHunk,
+    changed_budget: usize,
+    context_budget: usize,
+) {
+    match &hunk.scope {
+        Some(scope) => result.push(format!(
+            "  @@ {} @@ {}",
+            hunk.range,
+            crate::utils::truncate(scope, 60)
+        )),
+        None => result.push(format!("  @@ {} @@", hunk.range)),
+    }
+
+    let changed = hunk.changed();
+    if changed_budget < changed {
+        // Keep the first and last changes, one budget line goes to the marker
+        let shown = changed_budget.saturating_sub(1);
+        let head = shown.div_ceil(2);
+        let tail = shown - head;
+        let changed_lines: Vec<&str> = hunk
+            .lines
+            .iter()
+            .copied()
+            .filter(|l| is_changed_line(l))
+            .collect();
+        for line in &changed_lines[..head] {
+            result.push(format!("  {}", line));
+        }
+        result.push(format!("  ... {} lines elided in hunk", changed - shown));
+        for line in &changed_lines[changed - tail..] {
+            result.push(format!("  {}", line));
+        }
+        return;
+    }
+
+    // All changes fit: add context closest to the changes first
+    let distance: Vec<usize> = context_distances(&hunk.lines);
+    let mut by_distance: Vec<usize> = (0..hunk.lines.len()).filter(|i| distance[*i] > 0).collect();
+    by_distance.sort_by_key(|i| distance[*i]);
+    let keep: std::collections::HashSet<usize> =
+        by_distance.into_iter().take(context_budget).collect();
+
+    for (i, line) in hunk.lines.iter().enumerate() {
+        if distance[i] == 0 || keep.contains(&i) {
+            result.push(format!("  {}", line));
+        }
+    }
+}
+
+/// Distance of each line to the nearest changed line (0 for changed lines).
+fn context_distances(lines: &[&str]) -> Vec<usize> {
+    let mut distance = vec![usize::MAX; lines.len()];
+    let mut last = None;
+    for (i, line) in lines.iter().enumerate() {
+        if is_changed_line(line) {
+            last = Some(i);
+        }
+        if let Some(j) = last {
+            distance[i] = i - j;
+        }
+    }
+    let mut next = None;
+    for i in (0..lines.len()).rev() {
+        if is_changed_line(lines[i]) {
+            next = Some(i);
+        }
+        if let Some(j) = next {
+            distance[i] = distance[i].min(j - i);
+        }
+    }
+    distance
+}
+
+/// Max-min fair split of `budget` across `demands`: small demands are met in
+/// full and the rest is divided evenly among the larger ones.
+fn fair_share(demands: &[usize], budget: usize) -> Vec<usize> {
+    let mut alloc = vec![0; demands.len()];
+    let mut order: Vec<usize> = (0..demands.len()).collect();
+    order.sort_by_key(|i| demands[*i]);
+
+    let mut remaining = budget;
+    for (pos, &i) in order.iter().enumerate() {
+        let share = remaining / (order.len() - pos);
+        if demands[i] > share {
+            // Everyone left wants more than an even split: split evenly
+            for &j in &order[pos..] {
+                alloc[j] = share;
+            }
+            remaining -= share * (order.len() - pos);
+            break;
+        }
+        alloc[i] = demands[i];
+        remaining -= demands[i];
+    }
+
+    // Hand out rounding leftovers in diff order
+    for (i, demand) in demands.iter().enumerate() {
+        if remaining == 0 {
+            break;
+        }
+        if alloc[i] < *demand {
+            alloc[i] += 1;
+            remaining -= 1;
+        }
+    }
+    alloc
+}
+
 fn run_log(args: &[String], _max_lines: Option<usize>, verbose: u8) -> Result<()> {
+    // rtk-only structured modes; names must not shadow git log flags (--summary is git's)
+    let mode = args.iter().find_map(|arg| match arg.as_str() {
+        "--files" => Some(LogMode::Files),
+        "--by-type" => Some(LogMode::ByType),
+        "--overview" => Some(LogMode::Overview),
+        _ => None,
+    });
+    if let Some(mode) = mode {
+        let rest: Vec<String> = args
+            .iter()
+            .filter(|a| !matches!(a.as_str(), "--files" | "--by-type" | "--overview"))
+            .cloned()
+            .collect();
+        return run_log_mode(mode, &rest, verbose);
+    }
+
     let timer = tracking::TimedExecution::start();
 
     let mut cmd = Command::new("git");
@@ -390,11 +948,318 @@ fn filter_log_output(output: &str, limit: usize) -> String {
     capped.join("\n").trim().to_string()
 }
 
-/// Format porcelain output into compact RTK status display
-fn format_status_output(porcelain: &str) -> String {
-    let lines: Vec<&str> = porcelain.lines().collect();
+/// Output line budget for the structured log modes.
+const LOG_MODE_MAX_LINES: usize = 60;
+/// Files listed per commit in `--files` mode.
+const LOG_FILES_PER_COMMIT: usize = 5;
+/// Example subjects listed per type in `--by-type` mode.
+const LOG_EXAMPLES_PER_TYPE: usize = 3;
+/// Directories listed in `--overview` mode.
+const LOG_TOP_DIRS: usize = 8;
+
+/// Structured `rtk git log` modes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LogMode {
+    /// Each commit with a compact per-file stat
+    Files,
+    /// Conventional-commit types with counts
+    ByType,
+    /// Authors and touched directories over the range
+    Overview,
+}
 
-    if lines.is_empty() {
+/// Record separator between commits in structured log output.
+const LOG_RS: char = '\x1e';
+
+fn run_log_mode(mode: LogMode, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let has_limit = args.iter().any(|arg| {
+        arg == "-n"
+            || arg.starts_with("--max-count")
+            || (arg.starts_with('-') && arg.chars().nth(1).is_some_and(|c| c.is_ascii_digit()))
+    });
+    let has_window = args.iter().any(|arg| {
+        arg.starts_with("--since")
+            || arg.starts_with("--after")
+            || arg.starts_with("--until")
+            || arg.starts_with("--before")
+            || arg.contains("..")
+    });
+
+    let mut cmd = Command::new("git");
+    cmd.args(["log", "--no-merges"]);
+    match mode {
+        LogMode::Files => {
+            cmd.args(["--pretty=format:%x1e%h %s (%ar) <%an>", "--numstat"]);
+        }
+        LogMode::ByType => {
+            cmd.arg("--pretty=format:%h %s");
+        }
+        LogMode::Overview => {
+            cmd.args(["--pretty=format:%x1e%an%x09%at", "--name-only"]);
+        }
+    }
+    // Default window when the user gave neither a limit nor a date/range
+    if !has_limit && !has_window {
+        cmd.arg(match mode {
+            LogMode::Files => "-10",
+            LogMode::ByType | LogMode::Overview => "-100",
+        });
+    }
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    let output = cmd.output().context("Failed to run git log")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("{}", stderr);
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if verbose > 0 {
+        eprintln!("git log mode: {:?}", mode);
+    }
+
+    let formatted = match mode {
+        LogMode::Files => format_log_files(&stdout),
+        LogMode::ByType => format_log_by_type(&stdout),
+        LogMode::Overview => format_log_overview(&stdout),
+    };
+    let formatted = cap_lines(&formatted, LOG_MODE_MAX_LINES);
+    println!("{}", formatted);
+
+    let flag = match mode {
+        LogMode::Files => "--files",
+        LogMode::ByType => "--by-type",
+        LogMode::Overview => "--overview",
+    };
+    timer.track(
+        &format!("git log {}", args.join(" ")),
+        &format!("rtk git log {} {}", flag, args.join(" ")),
+        &stdout,
+        &formatted,
+    );
+
+    Ok(())
+}
+
+fn cap_lines(text: &str, max: usize) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.len() <= max {
+        return text.to_string();
+    }
+    format!(
+        "{}\n... +{} more lines (narrow with -N or --since)",
+        lines[..max].join("\n"),
+        lines.len() - max
+    )
+}
+
+/// `--files`: commit header plus `path +added -removed` per file.
+fn format_log_files(output: &str) -> String {
+    let mut out = Vec::new();
+    for record in output.split(LOG_RS).filter(|r| !r.trim().is_empty()) {
+        let mut lines = record.lines();
+        let header = lines.next().unwrap_or("");
+        out.push(truncate(header, 80));
+
+        let files: Vec<String> = lines
+            .filter_map(|line| {
+                let mut parts = line.splitn(3, '\t');
+                let added = parts.next()?;
+                let removed = parts.next()?;
+                let path = parts.next()?;
+                Some(if added == "-" {
+                    format!("   {} (binary)", path)
+                } else {
+                    format!("   {} +{} -{}", path, added, removed)
+                })
+            })
+            .collect();
+        let total = files.len();
+        out.extend(files.into_iter().take(LOG_FILES_PER_COMMIT));
+        if total > LOG_FILES_PER_COMMIT {
+            out.push(format!("   ... +{} files", total - LOG_FILES_PER_COMMIT));
+        }
+    }
+    if out.is_empty() {
+        return "No commits".to_string();
+    }
+    out.join("\n")
+}
+
+/// Split a conventional-commit subject into (type, breaking, description).
+fn parse_conventional(subject: &str) -> Option<(String, bool, &str)> {
+    let (prefix, desc) = subject.split_once(": ")?;
+    let breaking = prefix.ends_with('!');
+    let prefix = prefix.trim_end_matches('!');
+    let kind = prefix.split('(').next().unwrap_or(prefix);
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some((kind.to_lowercase(), breaking, desc))
+}
+
+/// `--by-type`: commits grouped by conventional-commit type.
+fn format_log_by_type(output: &str) -> String {
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+    let mut breaking = 0;
+    let mut total = 0;
+
+    for line in output.lines().filter(|l| !l.trim().is_empty()) {
+        total += 1;
+        let (hash, subject) = line.split_once(' ').unwrap_or((line, ""));
+        let (kind, example) = match parse_conventional(subject) {
+            Some((kind, is_breaking, desc)) => {
+                if is_breaking {
+                    breaking += 1;
+                }
+                (kind, format!("{} {}", hash, desc))
+            }
+            None => ("other".to_string(), format!("{} {}", hash, subject)),
+        };
+        groups.entry(kind).or_default().push(example);
+    }
+
+    if total == 0 {
+        return "No commits".to_string();
+    }
+
+    let mut sorted: Vec<(String, Vec<String>)> = groups.into_iter().collect();
+    // Largest groups first; "other" always last
+    sorted.sort_by(|a, b| {
+        (a.0 == "other")
+            .cmp(&(b.0 == "other"))
+            .then(b.1.len().cmp(&a.1.len()))
+            .then(a.0.cmp(&b.0))
+    });
+
+    let mut out = vec![format!("📊 {} commits by type", total)];
+    if breaking > 0 {
+        out[0].push_str(&format!(" (⚠️  {} breaking)", breaking));
+    }
+    for (kind, examples) in &sorted {
+        out.push(format!("{} ({})", kind, examples.len()));
+        for example in examples.iter().take(LOG_EXAMPLES_PER_TYPE) {
+            out.push(format!("  {}", truncate(example, 76)));
+        }
+        if examples.len() > LOG_EXAMPLES_PER_TYPE {
+            out.push(format!(
+                "  ... +{} more",
+                examples.len() - LOG_EXAMPLES_PER_TYPE
+            ));
+        }
+    }
+    out.join("\n")
+}
+
+/// Directory bucket for a path: first two components (`src/discover`), `.` for root files.
+fn log_dir_bucket(path: &str) -> String {
+    let parts: Vec<&str> = path.split('/').collect();
+    match parts.len() {
+        0 | 1 => ".".to_string(),
+        2 => parts[0].to_string(),
+        _ => format!("{}/{}", parts[0], parts[1]),
+    }
+}
+
+/// `--overview`: commit count, date span, authors and most-touched directories.
+fn format_log_overview(output: &str) -> String {
+    let mut authors: HashMap<String, usize> = HashMap::new();
+    // dir -> (commits touching it, files changed)
+    let mut dirs: HashMap<String, (usize, usize)> = HashMap::new();
+    let mut commits = 0;
+    let mut oldest = i64::MAX;
+    let mut newest = i64::MIN;
+
+    for record in output.split(LOG_RS).filter(|r| !r.trim().is_empty()) {
+        let mut lines = record.lines();
+        let header = lines.next().unwrap_or("");
+        let (author, time) = header.split_once('\t').unwrap_or((header, "0"));
+        let time: i64 = time.trim().parse().unwrap_or(0);
+
+        commits += 1;
+        *authors.entry(author.to_string()).or_insert(0) += 1;
+        oldest = oldest.min(time);
+        newest = newest.max(time);
+
+        let mut touched: HashSet<String> = HashSet::new();
+        for path in lines.filter(|l| !l.trim().is_empty()) {
+            let dir = log_dir_bucket(path.trim());
+            dirs.entry(dir.clone()).or_insert((0, 0)).1 += 1;
+            touched.insert(dir);
+        }
+        for dir in touched {
+            dirs.entry(dir).or_insert((0, 0)).0 += 1;
+        }
+    }
+
+    if commits == 0 {
+        return "No commits".to_string();
+    }
+
+    let date = |ts: i64| {
+        chrono::DateTime::from_timestamp(ts, 0)
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "?".to_string())
+    };
+
+    let mut out = vec![format!(
+        "📊 {} commits, {} authors, {} → {}",
+        commits,
+        authors.len(),
+        date(oldest),
+        date(newest)
+    )];
+
+    let mut authors: Vec<(String, usize)> = authors.into_iter().collect();
+    authors.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let shown: Vec<String> = authors
+        .iter()
+        .take(5)
+        .map(|(name, n)| format!("{} {} ({}%)", name, n, n * 100 / commits))
+        .collect();
+    let mut line = format!("Authors: {}", shown.join(", "));
+    if authors.len() > 5 {
+        line.push_str(&format!(", +{} more", authors.len() - 5));
+    }
+    out.push(line);
+
+    let mut dirs: Vec<(String, (usize, usize))> = dirs.into_iter().collect();
+    dirs.sort_by(|a, b| b.1 .0.cmp(&a.1 .0).then(a.0.cmp(&b.0)));
+    if !dirs.is_empty() {
+        out.push("Top directories:".to_string());
+        let width = dirs
+            .iter()
+            .take(LOG_TOP_DIRS)
+            .map(|(d, _)| d.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (dir, (dir_commits, files)) in dirs.iter().take(LOG_TOP_DIRS) {
+            out.push(format!(
+                "  {:<w$}  {} commits, {} file changes",
+                dir,
+                dir_commits,
+                files,
+                w = width
+            ));
+        }
+        if dirs.len() > LOG_TOP_DIRS {
+            out.push(format!("  ... +{} more", dirs.len() - LOG_TOP_DIRS));
+        }
+    }
+
+    out.join("\n")
+}
+
+/// Format porcelain output into compact RTK status display
+fn format_status_output(porcelain: &str) -> String {
+    let lines: Vec<&str> = porcelain.lines().collect();
+
+    if lines.is_empty() {
         return "Clean working tree".to_string();
     }
 
@@ -412,7 +1277,6 @@ fn format_status_output(porcelain: &str) -> String {
     let mut staged = 0;
     let mut modified = 0;
     let mut untracked = 0;
-    let mut conflicts = 0;
 
     let mut staged_files = Vec::new();
     let mut modified_files = Vec::new();
@@ -425,12 +1289,16 @@ fn format_status_output(porcelain: &str) -> String {
         let status = line.get(0..2).unwrap_or("  ");
         let file = line.get(3..).unwrap_or("");
 
+        // Unmerged paths are reported by conflict_status_summary()
+        if is_unmerged(status) {
+            continue;
+        }
+
         match status.chars().next().unwrap_or(' ') {
             'M' | 'A' | 'D' | 'R' | 'C' => {
                 staged += 1;
                 staged_files.push(file);
             }
-            'U' => conflicts += 1,
             _ => {}
         }
 
@@ -479,15 +1347,11 @@ fn format_status_output(porcelain: &str) -> String {
         }
     }
 
-    if conflicts > 0 {
-        output.push_str(&format!("⚠️  Conflicts: {} files\n", conflicts));
-    }
-
     output.trim_end().to_string()
 }
 
 /// Minimal filtering for git status with user-provided args
-fn filter_status_with_args(output: &str) -> String {
+pub(crate) fn filter_status_with_args(output: &str) -> String {
     let mut result = Vec::new();
 
     for line in output.lines() {
@@ -523,7 +1387,11 @@ fn filter_status_with_args(output: &str) -> String {
     }
 }
 
-fn run_status(args: &[String], verbose: u8) -> Result<()> {
+fn run_status(args: &[String], ultra_compact: bool, verbose: u8) -> Result<()> {
+    if args.iter().any(|a| a == "--recursive") {
+        return run_status_recursive(ultra_compact, verbose);
+    }
+
     let timer = tracking::TimedExecution::start();
 
     // If user provided flags, apply minimal filtering
@@ -555,32 +1423,799 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
         return Ok(());
     }
 
-    // Default RTK compact mode (no args provided)
-    // Get raw git status for tracking
-    let raw_output = Command::new("git")
-        .args(["status"])
-        .output()
-        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
-        .unwrap_or_default();
+    // Default RTK compact mode (no args provided)
+    // Get raw git status for tracking
+    let raw_output = Command::new("git")
+        .args(["status"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        .unwrap_or_default();
+
+    let output = Command::new("git")
+        .args(["status", "--porcelain", "-b"])
+        .output()
+        .context("Failed to run git status")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    let formatted = if !stderr.is_empty() && stderr.contains("not a git repository") {
+        "Not a git repository".to_string()
+    } else {
+        let mut formatted = format_status_output(&stdout);
+        if let Some(summary) = conflict_status_summary(&stdout) {
+            formatted.push('\n');
+            formatted.push_str(&summary);
+        }
+        formatted
+    };
+
+    println!("{}", formatted);
+
+    // Track for statistics
+    timer.track("git status", "rtk git status", &raw_output, &formatted);
+
+    Ok(())
+}
+
+/// Where a repository in `git status --recursive` comes from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RepoKind {
+    Current,
+    Submodule,
+    Worktree,
+}
+
+/// Per-repository status for `git status --recursive`.
+#[derive(Debug, Default)]
+struct RepoStatus {
+    branch: String,
+    ahead: usize,
+    behind: usize,
+    staged: usize,
+    modified: usize,
+    untracked: usize,
+    conflicted: usize,
+}
+
+impl RepoStatus {
+    fn is_dirty(&self) -> bool {
+        self.staged + self.modified + self.untracked + self.conflicted > 0
+    }
+}
+
+/// Parse a `## branch...upstream [ahead N, behind M]` header from `status -b`.
+fn parse_branch_header(line: &str) -> (String, usize, usize) {
+    let line = line.trim_start_matches("## ");
+    if let Some(branch) = line.strip_prefix("No commits yet on ") {
+        return (branch.to_string(), 0, 0);
+    }
+    if line.starts_with("HEAD (no branch)") {
+        return ("(detached)".to_string(), 0, 0);
+    }
+
+    let (refs, tracking) = match line.find(" [") {
+        Some(idx) => (&line[..idx], line[idx + 2..].trim_end_matches(']')),
+        None => (line, ""),
+    };
+    let branch = refs.split("...").next().unwrap_or(refs).to_string();
+
+    let mut ahead = 0;
+    let mut behind = 0;
+    for part in tracking.split(", ") {
+        if let Some(n) = part.strip_prefix("ahead ") {
+            ahead = n.parse().unwrap_or(0);
+        } else if let Some(n) = part.strip_prefix("behind ") {
+            behind = n.parse().unwrap_or(0);
+        }
+    }
+    (branch, ahead, behind)
+}
+
+fn parse_repo_status(porcelain: &str) -> RepoStatus {
+    let mut status = RepoStatus::default();
+    for line in porcelain.lines() {
+        if line.starts_with("##") {
+            let (branch, ahead, behind) = parse_branch_header(line);
+            status.branch = branch;
+            status.ahead = ahead;
+            status.behind = behind;
+            continue;
+        }
+        let Some(code) = line.get(0..2) else {
+            continue;
+        };
+        if is_unmerged(code) {
+            status.conflicted += 1;
+        } else if code == "??" {
+            status.untracked += 1;
+        } else {
+            if matches!(code.chars().next(), Some('M' | 'A' | 'D' | 'R' | 'C')) {
+                status.staged += 1;
+            }
+            if matches!(code.chars().nth(1), Some('M' | 'D')) {
+                status.modified += 1;
+            }
+        }
+    }
+    status
+}
+
+/// Label, origin and status (`None` when not initialized) of one repository.
+type RepoEntry = (String, RepoKind, Option<(RepoStatus, String)>);
+
+/// Display label for a repository path relative to the current toplevel.
+fn repo_label(path: &std::path::Path, root: &std::path::Path) -> String {
+    if path == root {
+        return ".".to_string();
+    }
+    if let Ok(rel) = path.strip_prefix(root) {
+        return rel.display().to_string();
+    }
+    if let Some(parent) = root.parent() {
+        if let Ok(rel) = path.strip_prefix(parent) {
+            return format!("../{}", rel.display());
+        }
+    }
+    let path = path.display().to_string();
+    match dirs::home_dir() {
+        Some(home) => {
+            let home = home.display().to_string();
+            match path.strip_prefix(&home) {
+                Some(rest) => format!("~{}", rest),
+                None => path,
+            }
+        }
+        None => path,
+    }
+}
+
+/// Current toplevel, its submodules (recursively) and linked worktrees.
+/// Uninitialized submodules are returned with `initialized == false`.
+fn discover_repos(root: &std::path::Path) -> Vec<(std::path::PathBuf, RepoKind, bool)> {
+    let mut repos = vec![(root.to_path_buf(), RepoKind::Current, true)];
+
+    // Paths are relative to the cwd, so list them from the toplevel
+    let root_arg = root.to_string_lossy();
+    if let Some(submodules) = git_output(&["-C", &root_arg, "submodule", "status", "--recursive"]) {
+        for (path, initialized) in parse_submodule_status(&submodules) {
+            repos.push((root.join(path), RepoKind::Submodule, initialized));
+        }
+    }
+
+    if let Some(worktrees) = git_output(&["worktree", "list", "--porcelain"]) {
+        for line in worktrees.lines() {
+            if let Some(path) = line.strip_prefix("worktree ") {
+                let path = std::path::PathBuf::from(path);
+                // Bare repos and submodule gitdirs are listed too; they have no checkout
+                if !path.join(".git").exists() {
+                    continue;
+                }
+                let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+                if !repos.iter().any(|(p, _, _)| *p == canonical) {
+                    repos.push((canonical, RepoKind::Worktree, true));
+                }
+            }
+        }
+    }
+
+    repos
+}
+
+/// (path, initialized) per line of `git submodule status`.
+fn parse_submodule_status(output: &str) -> Vec<(&str, bool)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            // " <sha> path (desc)", "+<sha> path (desc)", "-<sha> path" when not initialized
+            let line = line.trim_start();
+            let initialized = !line.starts_with('-');
+            let path = line
+                .trim_start_matches(['-', '+', 'U'])
+                .split_whitespace()
+                .nth(1)?;
+            Some((path, initialized))
+        })
+        .collect()
+}
+
+/// One status line per repository; dirty repositories get indented details
+/// unless `ultra_compact` is set.
+fn format_recursive_status(repos: &[RepoEntry], ultra_compact: bool) -> String {
+    let dirty = repos
+        .iter()
+        .filter(|(_, _, s)| s.as_ref().is_some_and(|(st, _)| st.is_dirty()))
+        .count();
+
+    let mut out = Vec::new();
+    if !ultra_compact {
+        out.push(format!("📦 {} repos ({} dirty)", repos.len(), dirty));
+    }
+
+    for (label, kind, status) in repos {
+        let kind_tag = match (kind, ultra_compact) {
+            (RepoKind::Current, _) => "",
+            (RepoKind::Submodule, false) => " (submodule)",
+            (RepoKind::Worktree, false) => " (worktree)",
+            (RepoKind::Submodule, true) => " [sub]",
+            (RepoKind::Worktree, true) => " [wt]",
+        };
+
+        let Some((st, porcelain)) = status else {
+            let marker = if ultra_compact { "-" } else { "⚪" };
+            out.push(format!("{} {}{} not initialized", marker, label, kind_tag));
+            continue;
+        };
+
+        let mut line = if ultra_compact {
+            format!("{}{} {}", label, kind_tag, st.branch)
+        } else {
+            let marker = if st.is_dirty() { "●" } else { "✓" };
+            format!("{} {}{} {}", marker, label, kind_tag, st.branch)
+        };
+        if st.ahead > 0 {
+            line.push_str(&format!(
+                " {}{}",
+                if ultra_compact { "+" } else { "↑" },
+                st.ahead
+            ));
+        }
+        if st.behind > 0 {
+            line.push_str(&format!(
+                " {}{}",
+                if ultra_compact { "-" } else { "↓" },
+                st.behind
+            ));
+        }
+
+        let counts: Vec<String> = [
+            (st.conflicted, "C", "conflicts"),
+            (st.staged, "S", "staged"),
+            (st.modified, "M", "modified"),
+            (st.untracked, "?", "untracked"),
+        ]
+        .iter()
+        .filter(|(n, _, _)| *n > 0)
+        .map(|(n, short, long)| {
+            if ultra_compact {
+                format!("{}{}", short, n)
+            } else {
+                format!("{} {}", n, long)
+            }
+        })
+        .collect();
+
+        if counts.is_empty() {
+            if ultra_compact {
+                line.push_str(" clean");
+            }
+            out.push(line);
+            continue;
+        }
+
+        if ultra_compact {
+            line.push(' ');
+            line.push_str(&counts.join(" "));
+            out.push(line);
+            continue;
+        }
+
+        line.push_str(&format!(": {}", counts.join(", ")));
+        out.push(line);
+        // Expand file lists for dirty repos (branch line already shown)
+        let details = format_status_output(porcelain);
+        for detail in details.lines().filter(|l| !l.starts_with("📌")) {
+            out.push(format!("   {}", detail));
+        }
+        for file in porcelain
+            .lines()
+            .filter(|l| l.get(0..2).is_some_and(is_unmerged))
+            .take(5)
+        {
+            out.push(format!("   ⚠️  {}", file.get(3..).unwrap_or("")));
+        }
+    }
+
+    out.join("\n")
+}
+
+fn run_status_recursive(ultra_compact: bool, verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let root = git_output(&["rev-parse", "--show-toplevel"]).context("Not a git repository")?;
+    let root = std::path::PathBuf::from(root);
+    let root = root.canonicalize().unwrap_or(root);
+
+    let mut raw = String::new();
+    let mut repos = Vec::new();
+    for (path, kind, initialized) in discover_repos(&root) {
+        let label = repo_label(&path, &root);
+        if verbose > 0 {
+            eprintln!("git -C {} status --porcelain -b", path.display());
+        }
+        if !initialized {
+            repos.push((label, kind, None));
+            continue;
+        }
+
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&path)
+            .args(["status", "--porcelain", "-b"])
+            .output()
+            .context("Failed to run git status")?;
+        if !output.status.success() {
+            repos.push((label, kind, None));
+            continue;
+        }
+        let porcelain = String::from_utf8_lossy(&output.stdout).to_string();
+        raw.push_str(&format!("Entering '{}'\n{}", label, porcelain));
+        repos.push((
+            label,
+            kind,
+            Some((parse_repo_status(&porcelain), porcelain)),
+        ));
+    }
+
+    let formatted = format_recursive_status(&repos, ultra_compact);
+    println!("{}", formatted);
+
+    timer.track(
+        "git status --recursive",
+        "rtk git status --recursive",
+        &raw,
+        &formatted,
+    );
+
+    Ok(())
+}
+
+/// Lines of context shown around each conflict region.
+const CONFLICT_CONTEXT: usize = 2;
+/// Max lines shown per side of a conflict region.
+const CONFLICT_SIDE_LINES: usize = 8;
+/// Max regions shown per conflicted file.
+const CONFLICT_MAX_REGIONS: usize = 5;
+
+/// A multi-step git operation that can stop on conflicts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GitOperation {
+    Merge,
+    Rebase,
+    CherryPick,
+    Revert,
+    Am,
+}
+
+impl GitOperation {
+    fn name(&self) -> &'static str {
+        match self {
+            GitOperation::Merge => "Merge",
+            GitOperation::Rebase => "Rebase",
+            GitOperation::CherryPick => "Cherry-pick",
+            GitOperation::Revert => "Revert",
+            GitOperation::Am => "am",
+        }
+    }
+
+    fn command(&self) -> &'static str {
+        match self {
+            GitOperation::Merge => "git merge",
+            GitOperation::Rebase => "git rebase",
+            GitOperation::CherryPick => "git cherry-pick",
+            GitOperation::Revert => "git revert",
+            GitOperation::Am => "git am",
+        }
+    }
+}
+
+/// In-progress operation with optional (current, total) step progress.
+#[derive(Debug, PartialEq)]
+struct OperationState {
+    operation: GitOperation,
+    progress: Option<(usize, usize)>,
+}
+
+/// One `<<<<<<<` ... `>>>>>>>` region of a conflicted file.
+#[derive(Debug, PartialEq)]
+struct ConflictRegion {
+    /// 1-based line of the `<<<<<<<` marker
+    line: usize,
+    ours_label: String,
+    theirs_label: String,
+    ours: Vec<String>,
+    theirs: Vec<String>,
+    before: Vec<String>,
+    after: Vec<String>,
+}
+
+/// A conflicted path with its porcelain status and parsed regions.
+struct ConflictedFile {
+    path: String,
+    status: String,
+    regions: Vec<ConflictRegion>,
+}
+
+/// Porcelain XY codes for unmerged paths.
+fn is_unmerged(status: &str) -> bool {
+    matches!(status, "DD" | "AU" | "UD" | "UA" | "DU" | "AA" | "UU")
+}
+
+fn describe_unmerged(status: &str) -> &'static str {
+    match status {
+        "UU" => "both modified",
+        "AA" => "both added",
+        "DD" => "both deleted",
+        "AU" => "added by us",
+        "UA" => "added by them",
+        "DU" => "deleted by us",
+        "UD" => "deleted by them",
+        _ => "unmerged",
+    }
+}
+
+fn git_output(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Detect a merge/rebase/cherry-pick/revert/am stopped in this repository.
+fn detect_operation(git_dir: &std::path::Path) -> Option<OperationState> {
+    let read_num = |path: std::path::PathBuf| -> Option<usize> {
+        std::fs::read_to_string(path).ok()?.trim().parse().ok()
+    };
+
+    for dir in ["rebase-merge", "rebase-apply"] {
+        let base = git_dir.join(dir);
+        if !base.is_dir() {
+            continue;
+        }
+        let operation = if base.join("applying").exists() {
+            GitOperation::Am
+        } else {
+            GitOperation::Rebase
+        };
+        let progress = match dir {
+            "rebase-merge" => read_num(base.join("msgnum")).zip(read_num(base.join("end"))),
+            _ => read_num(base.join("next")).zip(read_num(base.join("last"))),
+        };
+        return Some(OperationState {
+            operation,
+            progress,
+        });
+    }
+
+    let heads = [
+        ("MERGE_HEAD", GitOperation::Merge),
+        ("CHERRY_PICK_HEAD", GitOperation::CherryPick),
+        ("REVERT_HEAD", GitOperation::Revert),
+    ];
+    heads
+        .iter()
+        .find(|(file, _)| git_dir.join(file).exists())
+        .map(|(_, operation)| OperationState {
+            operation: *operation,
+            progress: None,
+        })
+}
+
+/// Parse conflict regions out of a file containing conflict markers.
+fn parse_conflicts(content: &str, context: usize) -> Vec<ConflictRegion> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut regions = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let Some(ours_label) = lines[i].strip_prefix("<<<<<<<") else {
+            i += 1;
+            continue;
+        };
+        let start = i;
+        let mut ours = Vec::new();
+        let mut theirs = Vec::new();
+        let mut theirs_label = String::new();
+        // 0 = ours, 1 = base (diff3), 2 = theirs
+        let mut side = 0;
+        let mut end = None;
+
+        i += 1;
+        while i < lines.len() {
+            let line = lines[i];
+            if line.starts_with("|||||||") && side == 0 {
+                side = 1;
+            } else if line == "=======" && side < 2 {
+                side = 2;
+            } else if let Some(label) = line.strip_prefix(">>>>>>>") {
+                theirs_label = label.trim().to_string();
+                end = Some(i);
+                break;
+            } else if side == 0 {
+                ours.push(line.to_string());
+            } else if side == 2 {
+                theirs.push(line.to_string());
+            }
+            i += 1;
+        }
+
+        let Some(end) = end else {
+            break;
+        };
+
+        regions.push(ConflictRegion {
+            line: start + 1,
+            ours_label: ours_label.trim().to_string(),
+            theirs_label,
+            ours,
+            theirs,
+            before: lines[start.saturating_sub(context)..start]
+                .iter()
+                .map(|l| l.to_string())
+                .collect(),
+            after: lines[end + 1..(end + 1 + context).min(lines.len())]
+                .iter()
+                .map(|l| l.to_string())
+                .collect(),
+        });
+        i = end + 1;
+    }
+
+    regions
+}
+
+/// Collect conflicted files (with regions) from `git status --porcelain` output.
+fn collect_conflicted_files(porcelain: &str) -> Vec<ConflictedFile> {
+    let root = git_output(&["rev-parse", "--show-toplevel"]).unwrap_or_default();
+    porcelain
+        .lines()
+        .filter_map(|line| {
+            let status = line.get(0..2)?;
+            if !is_unmerged(status) {
+                return None;
+            }
+            let path = line.get(3..)?.trim_matches('"').to_string();
+            let regions = std::fs::read_to_string(std::path::Path::new(&root).join(&path))
+                .map(|content| parse_conflicts(&content, CONFLICT_CONTEXT))
+                .unwrap_or_default();
+            Some(ConflictedFile {
+                path,
+                status: status.to_string(),
+                regions,
+            })
+        })
+        .collect()
+}
+
+fn current_operation() -> Option<OperationState> {
+    let git_dir = git_output(&["rev-parse", "--absolute-git-dir"])?;
+    detect_operation(std::path::Path::new(&git_dir))
+}
+
+fn format_operation_header(state: Option<&OperationState>, files: usize) -> String {
+    let conflicts = match files {
+        0 => "no conflicts left".to_string(),
+        1 => "1 conflicted file".to_string(),
+        n => format!("{} conflicted files", n),
+    };
+    match state {
+        Some(state) => {
+            let progress = state
+                .progress
+                .map(|(current, total)| format!(" ({}/{})", current, total))
+                .unwrap_or_default();
+            format!(
+                "⚠️  {} in progress{}: {}",
+                state.operation.name(),
+                progress,
+                conflicts
+            )
+        }
+        None => format!("⚠️  {}", conflicts),
+    }
+}
+
+fn format_next_steps(state: Option<&OperationState>, files: &[ConflictedFile]) -> Vec<String> {
+    let mut steps = Vec::new();
+    // Deletion conflicts are resolved by keeping (add) or deleting (rm) the file
+    let (deleted, edited): (Vec<&ConflictedFile>, Vec<&ConflictedFile>) = files
+        .iter()
+        .partition(|f| matches!(f.status.as_str(), "DU" | "UD" | "DD"));
+    if !edited.is_empty() {
+        let paths: Vec<&str> = edited.iter().map(|f| f.path.as_str()).collect();
+        steps.push(format!("Resolve, then: git add {}", paths.join(" ")));
+    }
+    for file in deleted {
+        if file.status == "DD" {
+            steps.push(format!("Both deleted: git rm {}", file.path));
+        } else {
+            steps.push(format!(
+                "Keep or delete ({}): git add {} | git rm {}",
+                describe_unmerged(&file.status),
+                file.path,
+                file.path
+            ));
+        }
+    }
+    match state {
+        Some(state) if state.operation == GitOperation::Merge => {
+            steps.push("Continue: git merge --continue (or git commit)".to_string());
+            steps.push("Abort:    git merge --abort".to_string());
+        }
+        Some(state) => {
+            let cmd = state.operation.command();
+            steps.push(format!("Continue: {} --continue", cmd));
+            if state.operation != GitOperation::Revert {
+                steps.push(format!("Skip:     {} --skip", cmd));
+            }
+            steps.push(format!("Abort:    {} --abort", cmd));
+        }
+        None => {}
+    }
+    steps
+}
+
+/// Full conflict report for `rtk git conflicts`.
+fn format_conflicts(state: Option<&OperationState>, files: &[ConflictedFile]) -> String {
+    if state.is_none() && files.is_empty() {
+        return "No conflicts and no merge/rebase/cherry-pick in progress".to_string();
+    }
+
+    let mut out = vec![format_operation_header(state, files.len())];
+
+    for file in files {
+        out.push(String::new());
+        out.push(format!(
+            "📄 {} ({}{})",
+            file.path,
+            describe_unmerged(&file.status),
+            format_region_count(file.regions.len())
+        ));
+
+        for region in file.regions.iter().take(CONFLICT_MAX_REGIONS) {
+            out.push(format!(
+                "  @ L{}  ours: {} | theirs: {}",
+                region.line,
+                if region.ours_label.is_empty() {
+                    "?"
+                } else {
+                    &region.ours_label
+                },
+                if region.theirs_label.is_empty() {
+                    "?"
+                } else {
+                    &region.theirs_label
+                }
+            ));
+            for line in &region.before {
+                out.push(format!("      {}", line));
+            }
+            push_conflict_side(&mut out, '<', &region.ours);
+            push_conflict_side(&mut out, '>', &region.theirs);
+            for line in &region.after {
+                out.push(format!("      {}", line));
+            }
+        }
+        if file.regions.len() > CONFLICT_MAX_REGIONS {
+            out.push(format!(
+                "  ... +{} more regions",
+                file.regions.len() - CONFLICT_MAX_REGIONS
+            ));
+        }
+    }
+
+    let steps = format_next_steps(state, files);
+    if !steps.is_empty() {
+        out.push(String::new());
+        out.extend(steps);
+    }
+
+    out.join("\n")
+}
+
+fn format_region_count(regions: usize) -> String {
+    match regions {
+        0 => String::new(),
+        1 => ", 1 region".to_string(),
+        n => format!(", {} regions", n),
+    }
+}
+
+fn push_conflict_side(out: &mut Vec<String>, marker: char, lines: &[String]) {
+    if lines.is_empty() {
+        out.push(format!("    {} (empty)", marker));
+        return;
+    }
+    for line in lines.iter().take(CONFLICT_SIDE_LINES) {
+        out.push(format!("    {} {}", marker, line));
+    }
+    if lines.len() > CONFLICT_SIDE_LINES {
+        out.push(format!(
+            "    {} ... +{} lines",
+            marker,
+            lines.len() - CONFLICT_SIDE_LINES
+        ));
+    }
+}
+
+/// Short conflict block appended to `rtk git status` (None when nothing is in progress).
+fn conflict_status_summary(porcelain: &str) -> Option<String> {
+    let has_unmerged = porcelain
+        .lines()
+        .any(|l| l.get(0..2).is_some_and(is_unmerged));
+    let state = current_operation();
+    if state.is_none() && !has_unmerged {
+        return None;
+    }
+
+    let files = if has_unmerged {
+        collect_conflicted_files(porcelain)
+    } else {
+        Vec::new()
+    };
+
+    let mut out = vec![format_operation_header(state.as_ref(), files.len())];
+    for file in &files {
+        out.push(format!(
+            "   {} ({}{})",
+            file.path,
+            describe_unmerged(&file.status),
+            format_region_count(file.regions.len())
+        ));
+    }
+    if !files.is_empty() {
+        out.push("   → rtk git conflicts for ours/theirs details".to_string());
+    }
+    for step in format_next_steps(state.as_ref(), &files)
+        .into_iter()
+        .skip(usize::from(!files.is_empty()))
+    {
+        out.push(format!("   {}", step));
+    }
+    Some(out.join("\n"))
+}
+
+fn run_conflicts(verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
 
     let output = Command::new("git")
-        .args(["status", "--porcelain", "-b"])
+        .args(["status", "--porcelain"])
         .output()
         .context("Failed to run git status")?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
 
-    let formatted = if !stderr.is_empty() && stderr.contains("not a git repository") {
-        "Not a git repository".to_string()
-    } else {
-        format_status_output(&stdout)
-    };
+    let porcelain = String::from_utf8_lossy(&output.stdout);
+    let state = current_operation();
+    let files = collect_conflicted_files(&porcelain);
+
+    if verbose > 0 {
+        eprintln!("Conflicted files: {}", files.len());
+    }
 
+    let formatted = format_conflicts(state.as_ref(), &files);
     println!("{}", formatted);
 
-    // Track for statistics
-    timer.track("git status", "rtk git status", &raw_output, &formatted);
+    // Baseline: what the agent would otherwise read (status + whole files)
+    let root = git_output(&["rev-parse", "--show-toplevel"]).unwrap_or_default();
+    let mut raw = porcelain.to_string();
+    for file in &files {
+        if let Ok(content) = std::fs::read_to_string(std::path::Path::new(&root).join(&file.path)) {
+            raw.push_str(&content);
+        }
+    }
+
+    timer.track(
+        "git status + cat <conflicted>",
+        "rtk git conflicts",
+        &raw,
+        &formatted,
+    );
 
     Ok(())
 }
@@ -1149,124 +2784,691 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
                 combined.clone()
             };
 
-            timer.track("git stash", "rtk git stash", &combined, &msg);
+            timer.track("git stash", "rtk git stash", &combined, &msg);
+        }
+    }
+
+    Ok(())
+}
+
+fn filter_stash_list(output: &str) -> String {
+    // Format: "stash@{0}: WIP on main: abc1234 commit message"
+    let mut result = Vec::new();
+    for line in output.lines() {
+        if let Some(colon_pos) = line.find(": ") {
+            let index = &line[..colon_pos];
+            let rest = &line[colon_pos + 2..];
+            // Compact: strip "WIP on branch:" prefix if present
+            let message = if let Some(second_colon) = rest.find(": ") {
+                rest[second_colon + 2..].trim()
+            } else {
+                rest.trim()
+            };
+            result.push(format!("{}: {}", index, message));
+        } else {
+            result.push(line.to_string());
+        }
+    }
+    result.join("\n")
+}
+
+fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git worktree list");
+    }
+
+    // If args contain "add", "remove", "prune" etc., pass through
+    let has_action = args.iter().any(|a| {
+        a == "add" || a == "remove" || a == "prune" || a == "lock" || a == "unlock" || a == "move"
+    });
+
+    if has_action {
+        let mut cmd = Command::new("git");
+        cmd.arg("worktree");
+        for arg in args {
+            cmd.arg(arg);
+        }
+        let output = cmd.output().context("Failed to run git worktree")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let combined = format!("{}{}", stdout, stderr);
+
+        let msg = if output.status.success() {
+            "ok ✓"
+        } else {
+            &combined
+        };
+
+        timer.track(
+            &format!("git worktree {}", args.join(" ")),
+            &format!("rtk git worktree {}", args.join(" ")),
+            &combined,
+            msg,
+        );
+
+        if output.status.success() {
+            println!("ok ✓");
+        } else {
+            eprintln!("FAILED: git worktree {}", args.join(" "));
+            if !stderr.trim().is_empty() {
+                eprintln!("{}", stderr);
+            }
+        }
+        return Ok(());
+    }
+
+    // Default: list mode
+    let output = Command::new("git")
+        .args(["worktree", "list"])
+        .output()
+        .context("Failed to run git worktree list")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let raw = stdout.to_string();
+
+    let filtered = filter_worktree_list(&stdout);
+    println!("{}", filtered);
+    timer.track("git worktree list", "rtk git worktree", &raw, &filtered);
+
+    Ok(())
+}
+
+fn filter_worktree_list(output: &str) -> String {
+    let home = dirs::home_dir()
+        .map(|h| h.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut result = Vec::new();
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // Format: "/path/to/worktree  abc1234 [branch]"
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() >= 3 {
+            let mut path = parts[0].to_string();
+            if !home.is_empty() && path.starts_with(&home) {
+                path = format!("~{}", &path[home.len()..]);
+            }
+            let hash = parts[1];
+            let branch = parts[2..].join(" ");
+            result.push(format!("{} {} {}", path, hash, branch));
+        } else {
+            result.push(line.to_string());
+        }
+    }
+    result.join("\n")
+}
+
+/// Commits listed by `git compare` before collapsing.
+const COMPARE_MAX_COMMITS: usize = 15;
+/// Directories listed by `git compare`.
+const COMPARE_MAX_DIRS: usize = 8;
+/// Most-changed files whose hunks are shown by `git compare`.
+const COMPARE_TOP_FILES: usize = 5;
+/// Line budget for the compacted hunks of the top files.
+const COMPARE_DIFF_LINES: usize = 80;
+
+/// Files worth calling out separately when reviewing a range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NotableKind {
+    Lockfile,
+    Migration,
+    Config,
+}
+
+fn classify_notable(path: &str) -> Option<NotableKind> {
+    if let Some(SpecialFile::Lockfile(_)) = lockfile_diff::classify_path(path) {
+        return Some(NotableKind::Lockfile);
+    }
+
+    let lower = path.to_lowercase();
+    if lower.contains("migrations/")
+        || lower.contains("migrate/")
+        || lower.contains("alembic/versions/")
+        || lower.ends_with(".sql") && lower.contains("migration")
+    {
+        return Some(NotableKind::Migration);
+    }
+
+    let name = lower.rsplit('/').next().unwrap_or(&lower);
+    let is_config = matches!(
+        name,
+        "cargo.toml"
+            | "package.json"
+            | "pyproject.toml"
+            | "go.mod"
+            | "gemfile"
+            | "composer.json"
+            | "dockerfile"
+            | "docker-compose.yml"
+            | "docker-compose.yaml"
+            | "makefile"
+            | "tsconfig.json"
+            | "settings.py"
+    ) || name.starts_with(".env")
+        || name.ends_with(".tf")
+        || lower.starts_with(".github/workflows/")
+        || lower.starts_with(".gitlab-ci")
+        || (lower.starts_with("config/") || lower.contains("/config/"))
+            && (name.ends_with(".yml")
+                || name.ends_with(".yaml")
+                || name.ends_with(".toml")
+                || name.ends_with(".json"));
+    is_config.then_some(NotableKind::Config)
+}
+
+/// Split a unified diff into per-file chunks, keyed by the new path.
+fn split_diff_by_file(diff: &str) -> Vec<(String, String)> {
+    let mut chunks: Vec<(String, String)> = Vec::new();
+    for line in diff.lines() {
+        if line.starts_with("diff --git") {
+            let name = line.split(" b/").nth(1).unwrap_or("unknown").to_string();
+            chunks.push((name, String::new()));
+        }
+        if let Some((_, chunk)) = chunks.last_mut() {
+            chunk.push_str(line);
+            chunk.push('\n');
+        }
+    }
+    chunks
+}
+
+/// Budgeted review summary of `log` (one commit per line) and `diff` (base...head).
+fn format_compare(range: &str, log: &str, diff: &str) -> String {
+    let commits: Vec<&str> = log.lines().filter(|l| !l.trim().is_empty()).collect();
+    let chunks = split_diff_by_file(diff);
+    let stats: Vec<(&str, FileStat, &str)> = chunks
+        .iter()
+        .filter_map(|(name, chunk)| {
+            let stat = parse_diff_stats(chunk).into_iter().next()?;
+            Some((name.as_str(), stat, chunk.as_str()))
+        })
+        .collect();
+
+    let added: usize = stats.iter().map(|(_, s, _)| s.added).sum();
+    let removed: usize = stats.iter().map(|(_, s, _)| s.removed).sum();
+
+    let mut out = vec![format!(
+        "🔀 {}: {} commits, {} files, +{} -{}",
+        range,
+        commits.len(),
+        stats.len(),
+        added,
+        removed
+    )];
+
+    if !commits.is_empty() {
+        out.push(String::new());
+        out.push("Commits:".to_string());
+        out.push(filter_log_output(log, COMPARE_MAX_COMMITS));
+        if commits.len() > COMPARE_MAX_COMMITS {
+            out.push(format!(
+                "... +{} more commits",
+                commits.len() - COMPARE_MAX_COMMITS
+            ));
+        }
+    }
+
+    if stats.is_empty() {
+        out.push(String::new());
+        out.push("No file changes".to_string());
+        return out.join("\n");
+    }
+
+    // Aggregate stat by directory, most churn first
+    let mut dirs: HashMap<String, (usize, usize, usize)> = HashMap::new();
+    for (name, stat, _) in &stats {
+        let entry = dirs.entry(log_dir_bucket(name)).or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.1 += stat.added;
+        entry.2 += stat.removed;
+    }
+    let mut dirs: Vec<(String, (usize, usize, usize))> = dirs.into_iter().collect();
+    dirs.sort_by(|a, b| {
+        (b.1 .1 + b.1 .2)
+            .cmp(&(a.1 .1 + a.1 .2))
+            .then(a.0.cmp(&b.0))
+    });
+    let width = dirs
+        .iter()
+        .take(COMPARE_MAX_DIRS)
+        .map(|(d, _)| d.chars().count())
+        .max()
+        .unwrap_or(0);
+    out.push(String::new());
+    out.push("Directories:".to_string());
+    for (dir, (files, a, d)) in dirs.iter().take(COMPARE_MAX_DIRS) {
+        out.push(format!(
+            "  {:<w$}  {} files  +{} -{}",
+            dir,
+            files,
+            a,
+            d,
+            w = width
+        ));
+    }
+    if dirs.len() > COMPARE_MAX_DIRS {
+        out.push(format!("  ... +{} more", dirs.len() - COMPARE_MAX_DIRS));
+    }
+
+    // Lockfiles, migrations and config changes
+    let mut notable = Vec::new();
+    for (name, stat, chunk) in &stats {
+        match classify_notable(name) {
+            Some(NotableKind::Lockfile) => {
+                let summary = parse_diff_files(chunk)
+                    .first()
+                    .and_then(|f| f.special.map(|kind| special_summary(kind, f)))
+                    .unwrap_or_default();
+                let headline = summary.first().map(String::as_str).unwrap_or("");
+                notable.push(format!(
+                    "  📦 {}: {}",
+                    name,
+                    headline.trim_start_matches("📦 lockfile: ")
+                ));
+                notable.extend(summary.iter().skip(1).take(5).map(|l| format!("    {}", l)));
+                if summary.len() > 6 {
+                    notable.push(format!("    ... +{} more", summary.len() - 6));
+                }
+            }
+            Some(NotableKind::Migration) => {
+                notable.push(format!("  🗃️  {} +{} -{}", name, stat.added, stat.removed));
+            }
+            Some(NotableKind::Config) => {
+                notable.push(format!("  ⚙️  {} +{} -{}", name, stat.added, stat.removed));
+            }
+            None => {}
+        }
+    }
+    if !notable.is_empty() {
+        out.push(String::new());
+        out.push("Notable:".to_string());
+        out.extend(notable);
+    }
+
+    // Top-N most-changed files, compacted; lockfiles are already summarized
+    let mut ranked: Vec<&(&str, FileStat, &str)> = stats
+        .iter()
+        .filter(|(name, stat, _)| {
+            !stat.binary
+                && lockfile_diff::classify_path(name).is_none()
+                && classify_notable(name) != Some(NotableKind::Lockfile)
+        })
+        .collect();
+    ranked.sort_by_key(|(_, stat, _)| std::cmp::Reverse(stat.added + stat.removed));
+    if !ranked.is_empty() {
+        let top: String = ranked
+            .iter()
+            .take(COMPARE_TOP_FILES)
+            .map(|(_, _, chunk)| *chunk)
+            .collect();
+        out.push(String::new());
+        out.push(format!(
+            "Top {} changed files:",
+            ranked.len().min(COMPARE_TOP_FILES)
+        ));
+        out.push(
+            compact_diff(&top, COMPARE_DIFF_LINES)
+                .trim_start()
+                .to_string(),
+        );
+        if ranked.len() > COMPARE_TOP_FILES {
+            out.push(format!(
+                "\n... +{} other files changed",
+                ranked.len() - COMPARE_TOP_FILES
+            ));
+        }
+    }
+
+    out.join("\n")
+}
+
+fn run_compare(base: &str, head: Option<&str>, verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // Accept `base..head` / `base...head` as a single argument
+    let (base, head) = match (head, base.split_once("..")) {
+        (None, Some((b, h))) => (b, h.trim_start_matches('.')),
+        _ => (base, head.unwrap_or("HEAD")),
+    };
+    let head = if head.is_empty() { "HEAD" } else { head };
+    let log_range = format!("{}..{}", base, head);
+    let diff_range = format!("{}...{}", base, head);
+
+    if verbose > 0 {
+        eprintln!("git log {} && git diff {}", log_range, diff_range);
+    }
+
+    let log = Command::new("git")
+        .args([
+            "log",
+            "--no-merges",
+            "--pretty=format:%h %s (%ar) <%an>",
+            &log_range,
+        ])
+        .output()
+        .context("Failed to run git log")?;
+    if !log.status.success() {
+        let stderr = String::from_utf8_lossy(&log.stderr);
+        eprintln!("{}", stderr.trim());
+        std::process::exit(log.status.code().unwrap_or(1));
+    }
+
+    let diff = Command::new("git")
+        .args(["diff", &diff_range])
+        .output()
+        .context("Failed to run git diff")?;
+    if !diff.status.success() {
+        let stderr = String::from_utf8_lossy(&diff.stderr);
+        eprintln!("{}", stderr.trim());
+        std::process::exit(diff.status.code().unwrap_or(1));
+    }
+
+    let log = String::from_utf8_lossy(&log.stdout);
+    let diff = String::from_utf8_lossy(&diff.stdout);
+
+    let formatted = format_compare(&diff_range, &log, &diff);
+    println!("{}", formatted);
+
+    timer.track(
+        &format!("git log {} && git diff {}", log_range, diff_range),
+        &format!("rtk git compare {} {}", base, head),
+        &format!("{}\n{}", log, diff),
+        &formatted,
+    );
+
+    Ok(())
+}
+
+/// Max blame ranges printed before collapsing.
+const BLAME_MAX_RANGES: usize = 60;
+
+/// Commit metadata from `git blame --porcelain`.
+#[derive(Debug, Clone)]
+struct BlameCommit {
+    author: String,
+    time: i64,
+    summary: String,
+}
+
+/// One attributed line from `git blame --porcelain`.
+#[derive(Debug)]
+struct BlameLine {
+    sha: String,
+    line: usize,
+    content: String,
+}
+
+/// Consecutive lines attributed to the same commit.
+#[derive(Debug, PartialEq)]
+struct BlameRange {
+    sha: String,
+    start: usize,
+    end: usize,
+}
+
+fn parse_blame_porcelain(output: &str) -> (Vec<BlameLine>, HashMap<String, BlameCommit>) {
+    let mut lines = Vec::new();
+    let mut commits: HashMap<String, BlameCommit> = HashMap::new();
+    let mut current: Option<(String, usize)> = None;
+
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            if let Some((sha, final_line)) = current.take() {
+                lines.push(BlameLine {
+                    sha,
+                    line: final_line,
+                    content: content.to_string(),
+                });
+            }
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let first = parts.next().unwrap_or("");
+        if first.len() == 40 && first.chars().all(|c| c.is_ascii_hexdigit()) {
+            let final_line = parts.nth(1).and_then(|n| n.parse().ok()).unwrap_or(0);
+            commits
+                .entry(first.to_string())
+                .or_insert_with(|| BlameCommit {
+                    author: String::new(),
+                    time: 0,
+                    summary: String::new(),
+                });
+            current = Some((first.to_string(), final_line));
+            continue;
+        }
+
+        let Some((sha, _)) = &current else {
+            continue;
+        };
+        let Some(commit) = commits.get_mut(sha) else {
+            continue;
+        };
+        if let Some(author) = line.strip_prefix("author ") {
+            commit.author = author.to_string();
+        } else if let Some(time) = line.strip_prefix("author-time ") {
+            commit.time = time.parse().unwrap_or(0);
+        } else if let Some(summary) = line.strip_prefix("summary ") {
+            commit.summary = summary.to_string();
+        }
+    }
+
+    (lines, commits)
+}
+
+fn group_blame_ranges(lines: &[BlameLine]) -> Vec<BlameRange> {
+    let mut ranges: Vec<BlameRange> = Vec::new();
+    for line in lines {
+        match ranges.last_mut() {
+            Some(range) if range.sha == line.sha && range.end + 1 == line.line => {
+                range.end = line.line;
+            }
+            _ => ranges.push(BlameRange {
+                sha: line.sha.clone(),
+                start: line.line,
+                end: line.line,
+            }),
+        }
+    }
+    ranges
+}
+
+/// Compact age like `3w ago`.
+fn format_age(seconds: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    let age = match seconds.max(0) {
+        s if s < HOUR => format!("{}m", s / MINUTE),
+        s if s < DAY => format!("{}h", s / HOUR),
+        s if s < 14 * DAY => format!("{}d", s / DAY),
+        s if s < 60 * DAY => format!("{}w", s / (7 * DAY)),
+        s if s < 365 * DAY => format!("{}mo", s / (30 * DAY)),
+        s => format!("{}y", s / (365 * DAY)),
+    };
+    format!("{} ago", age)
+}
+
+fn is_uncommitted(sha: &str) -> bool {
+    sha.chars().all(|c| c == '0')
+}
+
+fn format_blame(
+    file: &str,
+    lines: &[BlameLine],
+    commits: &HashMap<String, BlameCommit>,
+    authors: bool,
+    now: i64,
+) -> String {
+    if lines.is_empty() {
+        return format!("📜 {} (no lines)", file);
+    }
+
+    let ranges = group_blame_ranges(lines);
+    let author_of = |sha: &str| -> String {
+        if is_uncommitted(sha) {
+            return "uncommitted".to_string();
         }
+        commits
+            .get(sha)
+            .map(|c| c.author.clone())
+            .unwrap_or_default()
+    };
+
+    let mut author_lines: HashMap<String, usize> = HashMap::new();
+    for line in lines {
+        *author_lines.entry(author_of(&line.sha)).or_insert(0) += 1;
     }
 
-    Ok(())
-}
+    let first = lines.first().map(|l| l.line).unwrap_or(0);
+    let last = lines.last().map(|l| l.line).unwrap_or(0);
+    let distinct_commits: HashSet<&str> = lines.iter().map(|l| l.sha.as_str()).collect();
+
+    let mut out = vec![format!(
+        "📜 {}:{}-{} ({} lines, {} commits, {} authors)",
+        file,
+        first,
+        last,
+        lines.len(),
+        distinct_commits.len(),
+        author_lines.len()
+    )];
+
+    let range_width = format!("{}-{}", last, last).len();
+    let author_width = author_lines
+        .keys()
+        .map(|a| a.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(16);
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    for range in ranges.iter().take(BLAME_MAX_RANGES) {
+        let span = if range.start == range.end {
+            range.start.to_string()
+        } else {
+            format!("{}-{}", range.start, range.end)
+        };
+        let short = &range.sha[..7.min(range.sha.len())];
 
-fn filter_stash_list(output: &str) -> String {
-    // Format: "stash@{0}: WIP on main: abc1234 commit message"
-    let mut result = Vec::new();
-    for line in output.lines() {
-        if let Some(colon_pos) = line.find(": ") {
-            let index = &line[..colon_pos];
-            let rest = &line[colon_pos + 2..];
-            // Compact: strip "WIP on branch:" prefix if present
-            let message = if let Some(second_colon) = rest.find(": ") {
-                rest[second_colon + 2..].trim()
-            } else {
-                rest.trim()
-            };
-            result.push(format!("{}: {}", index, message));
+        if is_uncommitted(&range.sha) {
+            out.push(format!("  {:<rw$} uncommitted", span, rw = range_width));
+        } else if !seen.insert(range.sha.as_str()) {
+            // Repeated commit: details were printed on its first range
+            out.push(format!("  {:<rw$} {} ↑", span, short, rw = range_width));
         } else {
-            result.push(line.to_string());
+            let commit = commits.get(&range.sha);
+            let author = commit.map(|c| c.author.as_str()).unwrap_or("");
+            out.push(format!(
+                "  {:<rw$} {} {:<aw$} {:>7} \"{}\"",
+                span,
+                short,
+                truncate(author, author_width),
+                commit.map(|c| format_age(now - c.time)).unwrap_or_default(),
+                truncate(commit.map(|c| c.summary.as_str()).unwrap_or(""), 50),
+                rw = range_width,
+                aw = author_width
+            ));
         }
     }
-    result.join("\n")
+    if ranges.len() > BLAME_MAX_RANGES {
+        out.push(format!(
+            "  ... +{} more ranges (narrow with -L a,b)",
+            ranges.len() - BLAME_MAX_RANGES
+        ));
+    }
+
+    if authors {
+        let mut owners: Vec<(String, usize)> = author_lines.into_iter().collect();
+        owners.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        out.push(String::new());
+        out.push("Ownership:".to_string());
+        for (author, count) in owners {
+            out.push(format!(
+                "  {:<aw$} {:>3}% ({} lines)",
+                truncate(&author, author_width),
+                count * 100 / lines.len(),
+                count,
+                aw = author_width
+            ));
+        }
+    }
+
+    out.join("\n")
 }
 
-fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
+fn run_blame(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
-    if verbose > 0 {
-        eprintln!("git worktree list");
-    }
+    // rtk-only flag; everything else goes to git blame
+    let authors = args.iter().any(|a| a == "--authors");
+    let git_args: Vec<&String> = args.iter().filter(|a| *a != "--authors").collect();
 
-    // If args contain "add", "remove", "prune" etc., pass through
-    let has_action = args.iter().any(|a| {
-        a == "add" || a == "remove" || a == "prune" || a == "lock" || a == "unlock" || a == "move"
-    });
+    let mut cmd = Command::new("git");
+    cmd.args(["blame", "--porcelain"]);
+    for arg in &git_args {
+        cmd.arg(arg);
+    }
 
-    if has_action {
-        let mut cmd = Command::new("git");
-        cmd.arg("worktree");
-        for arg in args {
-            cmd.arg(arg);
-        }
-        let output = cmd.output().context("Failed to run git worktree")?;
-        let stdout = String::from_utf8_lossy(&output.stdout);
+    let output = cmd.output().context("Failed to run git blame")?;
+    if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        let combined = format!("{}{}", stdout, stderr);
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
 
-        let msg = if output.status.success() {
-            "ok ✓"
-        } else {
-            &combined
-        };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (lines, commits) = parse_blame_porcelain(&stdout);
 
-        timer.track(
-            &format!("git worktree {}", args.join(" ")),
-            &format!("rtk git worktree {}", args.join(" ")),
-            &combined,
-            msg,
+    if verbose > 0 {
+        eprintln!(
+            "Blamed {} lines across {} commits",
+            lines.len(),
+            commits.len()
         );
-
-        if output.status.success() {
-            println!("ok ✓");
-        } else {
-            eprintln!("FAILED: git worktree {}", args.join(" "));
-            if !stderr.trim().is_empty() {
-                eprintln!("{}", stderr);
-            }
-        }
-        return Ok(());
     }
 
-    // Default: list mode
-    let output = Command::new("git")
-        .args(["worktree", "list"])
-        .output()
-        .context("Failed to run git worktree list")?;
+    // Last non-flag argument is the file (a revision may precede it)
+    let file = git_args
+        .iter()
+        .rev()
+        .find(|a| !a.starts_with('-') && !a.contains(','))
+        .map(|a| a.as_str())
+        .unwrap_or("");
+    let now = chrono::Utc::now().timestamp();
+    let formatted = format_blame(file, &lines, &commits, authors, now);
+    println!("{}", formatted);
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let raw = stdout.to_string();
+    // Equivalent of plain `git blame` output, for savings tracking
+    let raw: String = lines
+        .iter()
+        .map(|l| {
+            let commit = commits.get(&l.sha);
+            format!(
+                "{} ({} {} {}) {}\n",
+                &l.sha[..8.min(l.sha.len())],
+                commit.map(|c| c.author.as_str()).unwrap_or(""),
+                commit.map(|c| c.time).unwrap_or(0),
+                l.line,
+                l.content
+            )
+        })
+        .collect();
 
-    let filtered = filter_worktree_list(&stdout);
-    println!("{}", filtered);
-    timer.track("git worktree list", "rtk git worktree", &raw, &filtered);
+    timer.track(
+        &format!("git blame {}", args.join(" ")),
+        &format!("rtk git blame {}", args.join(" ")),
+        &raw,
+        &formatted,
+    );
 
     Ok(())
 }
 
-fn filter_worktree_list(output: &str) -> String {
-    let home = dirs::home_dir()
-        .map(|h| h.to_string_lossy().to_string())
-        .unwrap_or_default();
-
-    let mut result = Vec::new();
-    for line in output.lines() {
-        if line.trim().is_empty() {
-            continue;
-        }
-        // Format: "/path/to/worktree  abc1234 [branch]"
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() >= 3 {
-            let mut path = parts[0].to_string();
-            if !home.is_empty() && path.starts_with(&home) {
-                path = format!("~{}", &path[home.len()..]);
-            }
-            let hash = parts[1];
-            let branch = parts[2..].join(" ");
-            result.push(format!("{} {} {}", path, hash, branch));
-        } else {
-            result.push(line.to_string());
-        }
-    }
-    result.join("\n")
-}
-
 /// Runs an unsupported git subcommand by passing it through directly
 pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
@@ -1310,6 +3512,384 @@ mod tests {
         assert!(result.contains("+"));
     }
 
+    #[test]
+    fn test_parse_conflicts() {
+        let content = "a\nb\n<<<<<<< HEAD\nours 1\nours 2\n||||||| base\nbase\n=======\ntheirs\n>>>>>>> feature\nc\nd\ne\n<<<<<<< HEAD\n=======\nonly theirs\n>>>>>>> feature\n";
+        let regions = parse_conflicts(content, 2);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].line, 3);
+        assert_eq!(regions[0].ours_label, "HEAD");
+        assert_eq!(regions[0].theirs_label, "feature");
+        assert_eq!(regions[0].ours, vec!["ours 1", "ours 2"]);
+        assert_eq!(regions[0].theirs, vec!["theirs"]);
+        assert_eq!(regions[0].before, vec!["a", "b"]);
+        assert_eq!(regions[0].after, vec!["c", "d"]);
+        assert!(regions[1].ours.is_empty());
+        assert_eq!(regions[1].theirs, vec!["only theirs"]);
+
+        // Unterminated region is ignored
+        assert!(parse_conflicts("<<<<<<< HEAD\nx\n=======\n", 2).is_empty());
+    }
+
+    #[test]
+    fn test_detect_operation() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(detect_operation(dir.path()), None);
+
+        std::fs::write(dir.path().join("CHERRY_PICK_HEAD"), "abc\n").unwrap();
+        assert_eq!(
+            detect_operation(dir.path()).unwrap().operation,
+            GitOperation::CherryPick
+        );
+
+        let rebase = dir.path().join("rebase-merge");
+        std::fs::create_dir(&rebase).unwrap();
+        std::fs::write(rebase.join("msgnum"), "3\n").unwrap();
+        std::fs::write(rebase.join("end"), "7\n").unwrap();
+        assert_eq!(
+            detect_operation(dir.path()),
+            Some(OperationState {
+                operation: GitOperation::Rebase,
+                progress: Some((3, 7)),
+            })
+        );
+    }
+
+    #[test]
+    fn test_format_conflicts() {
+        let state = OperationState {
+            operation: GitOperation::Merge,
+            progress: None,
+        };
+        let files = vec![ConflictedFile {
+            path: "src/lib.rs".to_string(),
+            status: "UU".to_string(),
+            regions: parse_conflicts(
+                "fn a() {\n<<<<<<< HEAD\n    1\n=======\n    2\n>>>>>>> topic\n}\n",
+                2,
+            ),
+        }];
+        let out = format_conflicts(Some(&state), &files);
+        assert!(out.starts_with("⚠️  Merge in progress: 1 conflicted file"));
+        assert!(out.contains("📄 src/lib.rs (both modified, 1 region)"));
+        assert!(out.contains("@ L2  ours: HEAD | theirs: topic"));
+        assert!(out.contains("    <     1"));
+        assert!(out.contains("    >     2"));
+        assert!(out.contains("git add src/lib.rs"));
+        assert!(out.contains("Abort:    git merge --abort"));
+
+        assert_eq!(
+            format_conflicts(None, &[]),
+            "No conflicts and no merge/rebase/cherry-pick in progress"
+        );
+    }
+
+    #[test]
+    fn test_format_next_steps_deletion_conflicts() {
+        let file = |path: &str, status: &str| ConflictedFile {
+            path: path.to_string(),
+            status: status.to_string(),
+            regions: Vec::new(),
+        };
+        let files = vec![
+            file("src/lib.rs", "UU"),
+            file("old.rs", "UD"),
+            file("legacy.rs", "DU"),
+            file("gone.rs", "DD"),
+        ];
+        let steps = format_next_steps(None, &files);
+        assert_eq!(
+            steps,
+            [
+                "Resolve, then: git add src/lib.rs",
+                "Keep or delete (deleted by them): git add old.rs | git rm old.rs",
+                "Keep or delete (deleted by us): git add legacy.rs | git rm legacy.rs",
+                "Both deleted: git rm gone.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_status_output_unmerged_not_staged() {
+        let porcelain = "## main\nUU src/lib.rs\nAA new.rs\nDU gone.rs\nM  ok.rs\n";
+        let result = format_status_output(porcelain);
+        assert!(result.contains("✅ Staged: 1 files"));
+        assert!(!result.contains("gone.rs"));
+        assert!(is_unmerged("DU"));
+        assert!(!is_unmerged("M "));
+    }
+
+    const BLAME_PORCELAIN: &str = "\
+aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa 1 1 2
+author alice
+author-time 1000000
+summary initial parser
+filename src/parser.rs
+	fn parse() {
+aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa 2 2
+	    let x = 1;
+bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb 3 3 1
+author bob
+author-time 1086400
+summary fix parser
+filename src/parser.rs
+	    let y = 2;
+aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa 3 4 1
+	}
+";
+
+    #[test]
+    fn test_parse_blame_porcelain() {
+        let (lines, commits) = parse_blame_porcelain(BLAME_PORCELAIN);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[2].line, 3);
+        assert_eq!(lines[2].content, "    let y = 2;");
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[&"b".repeat(40)].author, "bob");
+        assert_eq!(commits[&"a".repeat(40)].summary, "initial parser");
+
+        let ranges = group_blame_ranges(&lines);
+        assert_eq!(ranges.len(), 3);
+        assert_eq!((ranges[0].start, ranges[0].end), (1, 2));
+        assert_eq!((ranges[2].start, ranges[2].end), (4, 4));
+    }
+
+    #[test]
+    fn test_format_blame() {
+        let (lines, commits) = parse_blame_porcelain(BLAME_PORCELAIN);
+        let now = 1000000 + 21 * 86400;
+        let out = format_blame("src/parser.rs", &lines, &commits, true, now);
+
+        assert!(out.starts_with("📜 src/parser.rs:1-4 (4 lines, 2 commits, 2 authors)"));
+        assert!(out.contains("1-2 aaaaaaa alice  3w ago \"initial parser\""));
+        assert!(out.contains("3   bbbbbbb bob    2w ago \"fix parser\""));
+        assert!(out.contains("4   aaaaaaa ↑"));
+        assert!(out.contains("alice  75% (3 lines)"));
+        assert!(out.contains("bob    25% (1 lines)"));
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(30), "0m ago");
+        assert_eq!(format_age(3 * 3600), "3h ago");
+        assert_eq!(format_age(3 * 86400), "3d ago");
+        assert_eq!(format_age(90 * 86400), "3mo ago");
+        assert_eq!(format_age(800 * 86400), "2y ago");
+    }
+
+    #[test]
+    fn test_fair_share() {
+        assert_eq!(fair_share(&[2, 50, 50], 40), vec![2, 19, 19]);
+        assert_eq!(fair_share(&[3, 4], 100), vec![3, 4]);
+        assert_eq!(fair_share(&[10, 10, 10], 10), vec![4, 3, 3]);
+        assert_eq!(fair_share(&[], 10), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_compact_diff_keeps_end_of_long_hunk() {
+        let mut diff = String::from(
+            "diff --git a/big.rs b/big.rs\n--- a/big.rs\n+++ b/big.rs\n@@ -1,60 +1,60 @@ fn big()\n",
+        );
+        for i in 0..60 {
+            diff.push_str(&format!("+line {}\n", i));
+        }
+        diff.push_str("diff --git a/small.rs b/small.rs\n--- a/small.rs\n+++ b/small.rs\n@@ -1,2 +1,2 @@\n-old\n+new\n");
+
+        let result = compact_diff(&diff, 30);
+        assert!(result.contains("@@ -1,60 +1,60 @@ fn big()"));
+        assert!(result.contains("+line 0"));
+        assert!(result.contains("+line 59"), "end of hunk must survive");
+        assert!(result.contains("lines elided in hunk"));
+        // The small file is not starved by the big one
+        assert!(result.contains("-old"));
+        assert!(result.contains("+new"));
+        assert!(result.lines().filter(|l| !l.is_empty()).count() <= 30);
+    }
+
+    #[test]
+    fn test_compact_diff_scope_from_context() {
+        let diff = r#"diff --git a/lib.py b/lib.py
+--- a/lib.py
++++ b/lib.py
+@@ -10,6 +10,6 @@ class Parser:
+     def parse(self, text):
+         tokens = self.lex(text)
+-        return tokens
++        return self.build(tokens)
+"#;
+        let result = compact_diff(diff, 100);
+        assert!(result.contains("@@ -10,6 +10,6 @@ def parse(self, text):"));
+        assert!(result.contains("+        return self.build(tokens)"));
+    }
+
+    #[test]
+    fn test_compact_diff_lockfile_and_generated() {
+        let diff = r#"diff --git a/Cargo.lock b/Cargo.lock
+index 1111111..2222222 100644
+--- a/Cargo.lock
++++ b/Cargo.lock
+@@ -10,7 +10,7 @@
+ [[package]]
+ name = "anyhow"
+-version = "1.0.70"
++version = "1.0.75"
+ source = "registry+https://github.com/rust-lang/crates.io-index"
+-checksum = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
++checksum = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+diff --git a/api/user.pb.go b/api/user.pb.go
+--- a/api/user.pb.go
++++ b/api/user.pb.go
+@@ -1,3 +1,3 @@
+-// protoc v3.21.0
++// protoc v3.21.12
+ package api
+diff --git a/src/main.rs b/src/main.rs
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,2 +1,3 @@
+ fn main() {
++    run();
+ }
+"#;
+        let result = compact_diff(diff, 100);
+        assert!(result.contains("📦 lockfile: 0 added, 0 removed, 1 bumped"));
+        assert!(result.contains("~ anyhow 1.0.70 → 1.0.75"));
+        assert!(!result.contains("checksum"));
+        assert!(result.contains("generated, 2 lines changed"));
+        assert!(!result.contains("protoc"));
+        assert!(result.contains("+    run();"));
+    }
+
+    #[test]
+    fn test_format_diff_stat() {
+        let diff = r#"diff --git a/foo.rs b/foo.rs
+index 1111111..2222222 100644
+--- a/foo.rs
++++ b/foo.rs
+@@ -1,3 +1,4 @@
+ fn main() {
++    println!("hello");
+-    // -- old comment
+ }
+diff --git a/src/old/bar.rs b/src/new/bar.rs
+similarity index 100%
+rename from src/old/bar.rs
+rename to src/new/bar.rs
+diff --git a/gone.txt b/gone.txt
+deleted file mode 100644
+index 3333333..0000000
+--- a/gone.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+"#;
+        assert_eq!(
+            format_diff_stat(diff),
+            " foo.rs                  | 2 +-\n src/{old => new}/bar.rs | 0\n gone.txt                | 1 -\n 3 files changed, 1 insertion(+), 2 deletions(-)\n"
+        );
+        assert_eq!(format_diff_stat(""), "");
+    }
+
+    #[test]
+    fn test_pprint_rename() {
+        assert_eq!(
+            pprint_rename("mv.txt", "lib/mv.txt"),
+            "mv.txt => lib/mv.txt"
+        );
+        assert_eq!(pprint_rename("src/a.rs", "src/b.rs"), "src/{a.rs => b.rs}");
+        assert_eq!(
+            pprint_rename("x/old/f.rs", "x/new/f.rs"),
+            "x/{old => new}/f.rs"
+        );
+        assert_eq!(pprint_rename("q/f.rs", "f.rs"), "q/f.rs => f.rs");
+    }
+
+    #[test]
+    fn test_split_show_output() {
+        let out =
+            "abc1234 Fix parser (2 days ago) <alice>\ndiff --git a/x b/x\n@@ -1 +1 @@\n-a\n+b\n";
+        let (summary, patch) = split_show_output(out);
+        assert_eq!(summary, "abc1234 Fix parser (2 days ago) <alice>\n");
+        assert!(patch.starts_with("diff --git a/x b/x"));
+
+        let (summary, patch) = split_show_output("abc1234 Empty merge (now) <bob>");
+        assert_eq!(summary, "abc1234 Empty merge (now) <bob>");
+        assert_eq!(patch, "");
+    }
+
+    /// Single-pass output must match what the previous multi-call
+    /// implementation printed (`git diff --stat`, `git show --stat`, ...).
+    #[test]
+    fn test_single_pass_matches_git() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| -> String {
+            let out = Command::new("git")
+                .arg("-C")
+                .arg(dir.path())
+                .args(["-c", "user.name=rtk", "-c", "user.email=rtk@example.com"])
+                .args(args)
+                .output()
+                .expect("git must be installed to run tests");
+            assert!(out.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&out.stdout).to_string()
+        };
+        let write = |path: &str, content: String| {
+            let full = dir.path().join(path);
+            std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+            std::fs::write(full, content).unwrap();
+        };
+        let lines = |range: std::ops::Range<usize>, tag: &str| -> String {
+            range.map(|i| format!("{}{}\n", tag, i)).collect()
+        };
+
+        git(&["init", "-q"]);
+        write("small.txt", lines(0..10, ""));
+        write("big.txt", lines(0..200, ""));
+        write("mv.txt", lines(0..50, ""));
+        write(
+            "src/deep/very/long/directory/name/some_really_long_file_name_here.rs",
+            "x\n".to_string(),
+        );
+        git(&["add", "-A"]);
+        git(&["commit", "-qm", "init"]);
+
+        write("small.txt", lines(2..12, ""));
+        write("big.txt", lines(0..200, "v2-"));
+        write("new.txt", "new\n".to_string());
+        write(
+            "src/deep/very/long/directory/name/some_really_long_file_name_here.rs",
+            "x\ny\n".to_string(),
+        );
+        std::fs::create_dir_all(dir.path().join("lib")).unwrap();
+        git(&["mv", "mv.txt", "lib/mv.txt"]);
+        git(&["add", "-A"]);
+
+        // git diff: `--stat` call vs in-process stat
+        let patch = git(&["diff", "--cached"]);
+        assert_eq!(
+            format_diff_stat(&patch),
+            git(&["diff", "--cached", "--stat"])
+        );
+
+        git(&["commit", "-qm", "second"]);
+
+        // git show: summary, stat and patch calls vs one combined call
+        let combined = git(&["show", "--pretty=format:%h %s (%ar) <%an>"]);
+        let (summary, patch) = split_show_output(&combined);
+        assert_eq!(
+            summary.trim(),
+            git(&["show", "--no-patch", "--pretty=format:%h %s (%ar) <%an>"]).trim()
+        );
+        assert_eq!(
+            format_diff_stat(patch).trim(),
+            git(&["show", "--stat", "--pretty=format:"]).trim()
+        );
+        assert_eq!(
+            compact_diff(patch.trim(), 100),
+            compact_diff(git(&["show", "--pretty=format:"]).trim(), 100)
+        );
+    }
+
     #[test]
     fn test_filter_branch_output() {
         let output = "* main\n  feature/auth\n  fix/bug-123\n  remotes/origin/HEAD -> origin/main\n  remotes/origin/main\n  remotes/origin/feature/auth\n  remotes/origin/release/v2\n";
@@ -1521,4 +4101,234 @@ no changes added to commit (use "git add" and/or "git commit -a")
         let result = format_status_output(porcelain);
         assert!(result.contains("📌 main"));
     }
+
+    #[test]
+    fn test_format_log_files() {
+        let output = "\x1eabc1234 feat: add thing (2 days ago) <Ann>\n\n3\t1\tsrc/a.rs\n-\t-\tlogo.png\n\x1edef5678 fix: typo (3 days ago) <Bob>\n\n1\t1\tREADME.md\n";
+        let result = format_log_files(output);
+        assert_eq!(
+            result,
+            "abc1234 feat: add thing (2 days ago) <Ann>\n   src/a.rs +3 -1\n   logo.png (binary)\ndef5678 fix: typo (3 days ago) <Bob>\n   README.md +1 -1"
+        );
+
+        let many: String = (0..8)
+            .map(|i| format!("1\t0\tf{}.rs\n", i))
+            .collect::<String>();
+        let result = format_log_files(&format!("\x1eaaa big (now) <A>\n\n{}", many));
+        assert!(result.contains("   ... +3 files"));
+        assert_eq!(result.lines().count(), 1 + LOG_FILES_PER_COMMIT + 1);
+    }
+
+    #[test]
+    fn test_format_log_by_type() {
+        let output = "a1 feat(git): add log modes\na2 fix: off by one\na3 feat!: drop old flag\na4 Merge stuff\na5 feat: more\na6 feat(x): even more\n";
+        let result = format_log_by_type(output);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "📊 6 commits by type (⚠️  1 breaking)");
+        assert_eq!(lines[1], "feat (4)");
+        assert_eq!(lines[2], "  a1 add log modes");
+        assert_eq!(lines[5], "  ... +1 more");
+        assert_eq!(lines[6], "fix (1)");
+        assert_eq!(lines[8], "other (1)");
+        assert_eq!(lines[9], "  a4 Merge stuff");
+        assert_eq!(
+            parse_conventional("Fix: x").map(|c| c.0),
+            Some("fix".into())
+        );
+        assert!(parse_conventional("see http://x: y").is_none());
+    }
+
+    #[test]
+    fn test_format_log_overview() {
+        let output = "\x1eAnn\t1700000000\n\nsrc/git.rs\nsrc/discover/a.rs\n\x1eBob\t1700086400\n\nREADME.md\nsrc/main.rs\n\x1eAnn\t1700172800\n\nsrc/git.rs\n";
+        let result = format_log_overview(output);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "📊 3 commits, 2 authors, 2023-11-14 → 2023-11-16");
+        assert_eq!(lines[1], "Authors: Ann 2 (66%), Bob 1 (33%)");
+        assert_eq!(lines[2], "Top directories:");
+        assert_eq!(lines[3], "  src           3 commits, 3 file changes");
+        assert!(result.contains("  src/discover  1 commits, 1 file changes"));
+        assert!(result.contains("  .             1 commits, 1 file changes"));
+        assert_eq!(format_log_overview(""), "No commits");
+    }
+
+    #[test]
+    fn test_cap_lines() {
+        let text = (0..10)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(cap_lines(&text, 20), text);
+        let capped = cap_lines(&text, 4);
+        assert_eq!(capped.lines().count(), 5);
+        assert!(capped.ends_with("... +6 more lines (narrow with -N or --since)"));
+    }
+
+    #[test]
+    fn test_parse_branch_header() {
+        assert_eq!(
+            parse_branch_header("## main...origin/main [ahead 2, behind 1]"),
+            ("main".to_string(), 2, 1)
+        );
+        assert_eq!(
+            parse_branch_header("## feat...origin/feat [behind 3]"),
+            ("feat".to_string(), 0, 3)
+        );
+        assert_eq!(parse_branch_header("## dev"), ("dev".to_string(), 0, 0));
+        assert_eq!(
+            parse_branch_header("## No commits yet on main"),
+            ("main".to_string(), 0, 0)
+        );
+        assert_eq!(
+            parse_branch_header("## HEAD (no branch)"),
+            ("(detached)".to_string(), 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_submodule_status() {
+        let output = "\
+ 1a2b3c4d vendor/lib (v1.2.0)
++5e6f7a8b vendor/other (heads/main)
+-9c0d1e2f deps/missing";
+        assert_eq!(
+            parse_submodule_status(output),
+            vec![
+                ("vendor/lib", true),
+                ("vendor/other", true),
+                ("deps/missing", false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_recursive_status() {
+        let main =
+            "## main...origin/main [ahead 1]\nM  src/a.rs\n M src/b.rs\n?? new.txt\nUU c.rs\n";
+        let sub = "## master\n";
+        let repos = vec![
+            (
+                ".".to_string(),
+                RepoKind::Current,
+                Some((parse_repo_status(main), main.to_string())),
+            ),
+            (
+                "vendor/sub".to_string(),
+                RepoKind::Submodule,
+                Some((parse_repo_status(sub), sub.to_string())),
+            ),
+            ("vendor/old".to_string(), RepoKind::Submodule, None),
+        ];
+
+        let result = format_recursive_status(&repos, false);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "📦 3 repos (1 dirty)");
+        assert_eq!(
+            lines[1],
+            "● . main ↑1: 1 conflicts, 1 staged, 1 modified, 1 untracked"
+        );
+        assert!(result.contains("   ✅ Staged: 1 files\n      src/a.rs"));
+        assert!(result.contains("   ⚠️  c.rs"));
+        assert!(result.contains("\n✓ vendor/sub (submodule) master\n"));
+        assert!(result.ends_with("⚪ vendor/old (submodule) not initialized"));
+
+        let compact = format_recursive_status(&repos, true);
+        assert_eq!(
+            compact,
+            ". main +1 C1 S1 M1 ?1\nvendor/sub [sub] master clean\n- vendor/old [sub] not initialized"
+        );
+    }
+
+    #[test]
+    fn test_classify_notable() {
+        assert_eq!(classify_notable("Cargo.lock"), Some(NotableKind::Lockfile));
+        assert_eq!(
+            classify_notable("db/migrate/20240101_add_users.rb"),
+            Some(NotableKind::Migration)
+        );
+        assert_eq!(
+            classify_notable("app/migrations/0003_auto.py"),
+            Some(NotableKind::Migration)
+        );
+        assert_eq!(classify_notable("Cargo.toml"), Some(NotableKind::Config));
+        assert_eq!(
+            classify_notable(".github/workflows/ci.yml"),
+            Some(NotableKind::Config)
+        );
+        assert_eq!(
+            classify_notable("config/app.yaml"),
+            Some(NotableKind::Config)
+        );
+        assert_eq!(classify_notable("src/config.rs"), None);
+        assert_eq!(classify_notable("src/main.rs"), None);
+    }
+
+    #[test]
+    fn test_format_compare() {
+        let log = "abc1234 feat: add users (2 days ago) <Ann>\ndef5678 fix: typo (1 day ago) <Bob>";
+        let diff = "\
+diff --git a/src/users.rs b/src/users.rs
+index 111..222 100644
+--- a/src/users.rs
++++ b/src/users.rs
+@@ -1,2 +1,4 @@ fn users()
+ fn users() {
++    let a = 1;
++    let b = 2;
+ }
+diff --git a/src/lib.rs b/src/lib.rs
+index 111..222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1 +1 @@
+-mod old;
++mod users;
+diff --git a/Cargo.toml b/Cargo.toml
+index 111..222 100644
+--- a/Cargo.toml
++++ b/Cargo.toml
+@@ -1 +1,2 @@
+ [dependencies]
++serde = \"1\"
+diff --git a/Cargo.lock b/Cargo.lock
+index 111..222 100644
+--- a/Cargo.lock
++++ b/Cargo.lock
+@@ -1,3 +1,7 @@
+ [[package]]
+ name = \"anyhow\"
+ version = \"1.0.0\"
++
++[[package]]
++name = \"serde\"
++version = \"1.0.190\"
+diff --git a/migrations/0002_users.sql b/migrations/0002_users.sql
+new file mode 100644
+index 000..333
+--- /dev/null
++++ b/migrations/0002_users.sql
+@@ -0,0 +1 @@
++CREATE TABLE users (id int);
+";
+        let result = format_compare("main...feat", log, diff);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "🔀 main...feat: 2 commits, 5 files, +9 -1");
+        assert!(result.contains("Commits:\nabc1234 feat: add users"));
+        assert!(result.contains("  src         2 files  +3 -1"));
+        assert!(result.contains("  .           2 files  +5 -0"));
+        assert!(result.contains("  📦 Cargo.lock: 1 added, 0 removed, 0 bumped"));
+        assert!(result.contains("    + serde 1.0.190"));
+        assert!(result.contains("  🗃️  migrations/0002_users.sql +1 -0"));
+        assert!(result.contains("  ⚙️  Cargo.toml +1 -0"));
+
+        // Everything but lockfiles competes for hunks, most-changed first
+        let top = result.split("Top 4 changed files:\n").nth(1).unwrap();
+        assert!(top.starts_with("📄 src/users.rs"));
+        assert!(top.contains("📄 src/lib.rs"));
+        assert!(top.contains("📄 Cargo.toml"));
+        assert!(top.contains("📄 migrations/0002_users.sql"));
+        assert!(!top.contains("Cargo.lock"));
+
+        assert!(format_compare("a...b", "", "").ends_with("No file changes"));
+    }
 }
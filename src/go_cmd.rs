@@ -337,8 +337,85 @@ fn filter_go_test_json(output: &str) -> String {
     result.trim().to_string()
 }
 
+/// Filter plain-text `go test` output (e.g. CI logs, where -json was not used)
+pub(crate) fn filter_go_test_text(output: &str) -> String {
+    let mut pass = 0;
+    let mut fail = 0;
+    let mut failed_packages: Vec<(String, PackageResult)> = Vec::new();
+    let mut ok_packages = 0;
+    // Failed tests seen since the last package result line
+    let mut pending: Vec<(String, Vec<String>)> = Vec::new();
+    let mut in_failure = false;
+    // With -v, a test's messages are printed before its "--- FAIL" line
+    let mut run_output: Vec<String> = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("--- FAIL: ") {
+            fail += 1;
+            let name = rest.split(" (").next().unwrap_or(rest).to_string();
+            pending.push((name, std::mem::take(&mut run_output)));
+            in_failure = true;
+        } else if trimmed.starts_with("--- PASS: ") {
+            pass += 1;
+            run_output.clear();
+            in_failure = false;
+        } else if trimmed.starts_with("--- SKIP: ") || trimmed.starts_with("=== ") {
+            run_output.clear();
+            in_failure = false;
+        } else if line.starts_with("ok  \t") || line.starts_with("ok \t") {
+            ok_packages += 1;
+            pending.clear();
+            in_failure = false;
+        } else if let Some(rest) = line.strip_prefix("FAIL\t") {
+            let package = rest.split('\t').next().unwrap_or(rest);
+            let failed_tests = std::mem::take(&mut pending);
+            failed_packages.push((
+                compact_package_name(package),
+                PackageResult {
+                    fail: failed_tests.len(),
+                    failed_tests,
+                    ..Default::default()
+                },
+            ));
+            in_failure = false;
+        } else if !trimmed.is_empty() && trimmed != "FAIL" && trimmed != "PASS" {
+            match pending.last_mut() {
+                Some((_, lines)) if in_failure => lines.push(trimmed.to_string()),
+                _ => run_output.push(trimmed.to_string()),
+            }
+        }
+    }
+
+    if fail == 0 && failed_packages.is_empty() {
+        if pass == 0 && ok_packages == 0 {
+            return "Go test: No tests found".to_string();
+        }
+        return format!("✓ Go test: {} passed in {} packages", pass, ok_packages);
+    }
+
+    let mut result = format!(
+        "Go test: {} passed, {} failed in {} packages\n",
+        pass,
+        fail,
+        ok_packages + failed_packages.len()
+    );
+    result.push_str("═══════════════════════════════════════\n");
+    for (package, pkg_result) in &failed_packages {
+        result.push_str(&format!("\n📦 {} ({} failed)\n", package, pkg_result.fail));
+        for (test, lines) in &pkg_result.failed_tests {
+            result.push_str(&format!("  ❌ {}\n", test));
+            for line in lines.iter().take(5) {
+                result.push_str(&format!("     {}\n", truncate(line, 100)));
+            }
+        }
+    }
+
+    result.trim().to_string()
+}
+
 /// Filter go build output - show only errors
-fn filter_go_build(output: &str) -> String {
+pub(crate) fn filter_go_build(output: &str) -> String {
     let mut errors: Vec<String> = Vec::new();
 
     for line in output.lines() {
@@ -381,7 +458,7 @@ fn filter_go_build(output: &str) -> String {
 }
 
 /// Filter go vet output - show issues
-fn filter_go_vet(output: &str) -> String {
+pub(crate) fn filter_go_vet(output: &str) -> String {
     let mut issues: Vec<String> = Vec::new();
 
     for line in output.lines() {
@@ -498,4 +575,16 @@ utils.go:15:5: unreachable code"#;
         assert_eq!(compact_package_name("example.com/foo"), "foo");
         assert_eq!(compact_package_name("simple"), "simple");
     }
+
+    #[test]
+    fn test_filter_go_test_text() {
+        let output = "=== RUN   TestAdd\n--- PASS: TestAdd (0.00s)\n=== RUN   TestSub\n    math_test.go:12: expected 1, got 2\n--- FAIL: TestSub (0.00s)\nFAIL\nFAIL\texample.com/foo/math\t0.012s\nok  \texample.com/foo/util\t0.004s\n";
+        let result = filter_go_test_text(output);
+        assert!(result.starts_with("Go test: 1 passed, 1 failed in 2 packages"));
+        assert!(result.contains("📦 math (1 failed)"));
+        assert!(result.contains("  ❌ TestSub\n     math_test.go:12: expected 1, got 2"));
+
+        let ok = "--- PASS: TestA (0.00s)\nok  \texample.com/foo\t0.01s\n";
+        assert_eq!(filter_go_test_text(ok), "✓ Go test: 1 passed in 1 packages");
+    }
 }
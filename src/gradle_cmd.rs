@@ -0,0 +1,418 @@
+use crate::jvm_build::{self, JvmBuild};
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, FormatMode, LintIssue,
+    LintSeverity, OutputParser, ParseResult, TestResult, TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::relative_path;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::path::Path;
+use std::process::Command;
+use std::time::SystemTime;
+
+/// Task names (last path segment) whose output is summarized; other tasks
+/// (`dependencies`, `tasks`, ...) only get progress noise removed.
+const BUILD_TASKS: &[&str] = &[
+    "build",
+    "assemble",
+    "check",
+    "test",
+    "clean",
+    "compileJava",
+    "compileKotlin",
+    "compileTestJava",
+    "compileTestKotlin",
+    "classes",
+    "testClasses",
+    "jar",
+    "integrationTest",
+];
+
+lazy_static::lazy_static! {
+    // > Task :app:compileJava FAILED
+    static ref TASK: Regex = Regex::new(
+        r"^> Task (\S+)(?: (UP-TO-DATE|NO-SOURCE|SKIPPED|FROM-CACHE|FAILED))?$"
+    ).unwrap();
+    // /work/app/src/main/java/com/acme/Api.java:12: error: cannot find symbol
+    static ref JAVAC_DIAG: Regex =
+        Regex::new(r"^(.+?\.java):(\d+): (error|warning): (.+)$").unwrap();
+    // e: file:///work/app/src/main/kotlin/Api.kt:12:5 Unresolved reference: foo
+    static ref KOTLIN_DIAG: Regex =
+        Regex::new(r"^(e|w): (?:file://)?(.+?\.kts?):(\d+):(\d+):? (.+)$").unwrap();
+    // e: /work/app/src/main/kotlin/Api.kt: (12, 5): Unresolved reference: foo
+    static ref KOTLIN_OLD_DIAG: Regex =
+        Regex::new(r"^(e|w): (.+?\.kts?): \((\d+), (\d+)\): (.+)$").unwrap();
+    // CalcTest > divides() FAILED
+    static ref TEST_FAILED: Regex = Regex::new(r"^(\S+) > (.+) FAILED$").unwrap();
+    static ref TEST_SUMMARY: Regex = Regex::new(
+        r"^(\d+) tests? completed, (\d+) failed(?:, (\d+) skipped)?$"
+    ).unwrap();
+    static ref BUILD_RESULT: Regex =
+        Regex::new(r"^BUILD (SUCCESSFUL|FAILED)(?: in (.+))?$").unwrap();
+    static ref PROGRESS: Regex = Regex::new(r"^<[=\-]*> \d+%").unwrap();
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let started = SystemTime::now();
+
+    // Prefer the project's wrapper
+    let tool = if Path::new("gradlew").exists() {
+        "./gradlew"
+    } else {
+        "gradle"
+    };
+    let mut cmd = Command::new(tool);
+    // Plain console: no progress bar redraws, one "> Task" line per task
+    if !args.iter().any(|a| a.starts_with("--console")) {
+        cmd.arg("--console=plain");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: {} --console=plain {}", tool, args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run gradle. Is Gradle installed?")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let summarize = args.is_empty()
+        || args.iter().any(|a| {
+            let task = a.rsplit(':').next().unwrap_or(a);
+            BUILD_TASKS.contains(&task)
+        });
+
+    let filtered = if summarize {
+        let mode = FormatMode::from_verbosity(verbose);
+        match GradleParser::parse(&raw) {
+            ParseResult::Full(mut build) => {
+                attach_test_reports(&mut build, started);
+                build.format(mode)
+            }
+            ParseResult::Degraded(mut build, warnings) => {
+                if verbose > 0 {
+                    emit_degradation_warning("gradle", &warnings.join(", "));
+                }
+                attach_test_reports(&mut build, started);
+                build.format(mode)
+            }
+            ParseResult::Passthrough(truncated) => {
+                emit_passthrough_warning("gradle", "No build result found");
+                truncated
+            }
+        }
+    } else {
+        filter_gradle_noise(&raw)
+    };
+
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("gradle {}", args.join(" ")),
+        &format!("rtk gradle {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Gradle's console only names failed tests; the XML reports have the rest.
+fn attach_test_reports(build: &mut JvmBuild, since: SystemTime) {
+    let reports = jvm_build::collect_reports(&["test-results"], since);
+    if let ParseResult::Full(tests) = jvm_build::JunitReportParser::parse(&reports) {
+        build.tests = Some(tests);
+    }
+}
+
+fn is_noise(line: &str) -> bool {
+    PROGRESS.is_match(line)
+        || line.starts_with("> Configure project")
+        || line.starts_with("Starting a Gradle Daemon")
+        || line.starts_with("Download ")
+        || line.starts_with("Deprecated Gradle features were used")
+        || line.starts_with("You can use '--warning-mode all'")
+        || line.starts_with("For more on this, please refer to")
+        || line.contains("actionable tasks:")
+}
+
+/// Light filter for non-build tasks: keep task output, drop progress noise.
+fn filter_gradle_noise(output: &str) -> String {
+    output
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !is_noise(l))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+struct GradleParser;
+
+impl OutputParser for GradleParser {
+    type Output = JvmBuild;
+
+    fn parse(input: &str) -> ParseResult<JvmBuild> {
+        let mut build = JvmBuild::new("gradle", "tasks");
+        let mut found_result = false;
+        let mut tests: Option<TestResult> = None;
+        let lines: Vec<&str> = input.lines().collect();
+
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i].trim_end();
+            i += 1;
+
+            if let Some(caps) = TASK.captures(line) {
+                let status = caps.get(2).map(|m| m.as_str()).unwrap_or("EXECUTED");
+                // A task header can repeat (e.g. once more with FAILED)
+                match build.outcomes.iter_mut().find(|(name, _)| name == &caps[1]) {
+                    Some(entry) => entry.1 = status.to_string(),
+                    None => build
+                        .outcomes
+                        .push((caps[1].to_string(), status.to_string())),
+                }
+                continue;
+            }
+
+            if let Some(caps) = JAVAC_DIAG.captures(line) {
+                let mut message = caps[4].to_string();
+                // Source line, caret, then "symbol:"/"location:" details
+                while let Some(next) = lines.get(i) {
+                    let detail = next.trim();
+                    if let Some(symbol) = detail.strip_prefix("symbol:") {
+                        message.push_str(&format!(" ({})", symbol.trim()));
+                    } else if !next.starts_with(' ') || detail.is_empty() {
+                        break;
+                    }
+                    i += 1;
+                }
+                build.push_compile_error(LintIssue {
+                    file_path: relative_path(&caps[1]),
+                    line: caps[2].parse().unwrap_or(0),
+                    column: 0,
+                    severity: if &caps[3] == "error" {
+                        LintSeverity::Error
+                    } else {
+                        LintSeverity::Warning
+                    },
+                    rule_id: "javac".to_string(),
+                    message,
+                });
+                continue;
+            }
+
+            if let Some(caps) = KOTLIN_DIAG
+                .captures(line)
+                .or_else(|| KOTLIN_OLD_DIAG.captures(line))
+            {
+                build.push_compile_error(LintIssue {
+                    file_path: relative_path(&caps[2]),
+                    line: caps[3].parse().unwrap_or(0),
+                    column: caps[4].parse().unwrap_or(0),
+                    severity: if &caps[1] == "e" {
+                        LintSeverity::Error
+                    } else {
+                        LintSeverity::Warning
+                    },
+                    rule_id: "kotlinc".to_string(),
+                    message: caps[5].to_string(),
+                });
+                continue;
+            }
+
+            if let Some(caps) = TEST_FAILED.captures(line) {
+                let class = caps[1].to_string();
+                let short = class.rsplit('.').next().unwrap_or(&class).to_string();
+                let method = caps[2].trim_end_matches("()").to_string();
+                let mut trace = Vec::new();
+                while let Some(next) = lines.get(i) {
+                    if !next.starts_with("    ") {
+                        break;
+                    }
+                    trace.push(next.trim());
+                    i += 1;
+                }
+                let failure = jvm_build::test_failure(
+                    format!("{}.{}", short, method),
+                    class,
+                    &trace.join("\n"),
+                );
+                tests
+                    .get_or_insert(TestResult {
+                        total: 0,
+                        passed: 0,
+                        failed: 0,
+                        skipped: 0,
+                        duration_ms: None,
+                        failures: Vec::new(),
+                    })
+                    .failures
+                    .push(failure);
+                continue;
+            }
+
+            if let Some(caps) = TEST_SUMMARY.captures(line) {
+                let n = |idx: usize| {
+                    caps.get(idx)
+                        .and_then(|m| m.as_str().parse::<usize>().ok())
+                        .unwrap_or(0)
+                };
+                let t = tests.get_or_insert(TestResult {
+                    total: 0,
+                    passed: 0,
+                    failed: 0,
+                    skipped: 0,
+                    duration_ms: None,
+                    failures: Vec::new(),
+                });
+                t.total += n(1);
+                t.failed += n(2);
+                t.skipped += n(3);
+                continue;
+            }
+
+            if line == "* What went wrong:" {
+                while let Some(next) = lines.get(i) {
+                    if next.starts_with("* ") {
+                        break;
+                    }
+                    let text = next.trim().trim_start_matches("> ").trim();
+                    let text = text.split(" See the report at").next().unwrap_or(text);
+                    if !text.is_empty() {
+                        build
+                            .failure
+                            .push(format!("❌ {}", crate::utils::truncate(text, 200)));
+                    }
+                    i += 1;
+                }
+                continue;
+            }
+
+            if let Some(caps) = BUILD_RESULT.captures(line) {
+                found_result = true;
+                build.success = &caps[1] == "SUCCESSFUL";
+                build.duration = caps.get(2).map(|m| m.as_str().to_string());
+            }
+        }
+
+        if let Some(t) = tests.as_mut() {
+            // Console only lists failures: count them when no summary line was printed
+            t.failed = t.failed.max(t.failures.len());
+            t.total = t.total.max(t.failed + t.skipped);
+            t.passed = t.total.saturating_sub(t.failed + t.skipped);
+        }
+        build.tests = tests;
+
+        if found_result {
+            ParseResult::Full(build)
+        } else if !build.compile_errors.is_empty() || !build.outcomes.is_empty() {
+            build.success = build.compile_errors.is_empty()
+                && !build.outcomes.iter().any(|(_, s)| s == "FAILED");
+            ParseResult::Degraded(build, vec!["no BUILD SUCCESSFUL/FAILED line".to_string()])
+        } else {
+            let kept = filter_gradle_noise(input);
+            ParseResult::Passthrough(truncate_output(&kept, 2000))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradle_parser_test_failure() {
+        let output = "\
+Starting a Gradle Daemon (subsequent builds will be faster)
+> Task :lib:compileJava UP-TO-DATE
+> Task :lib:classes UP-TO-DATE
+> Task :app:compileJava
+> Task :app:compileTestJava FROM-CACHE
+> Task :app:test FAILED
+
+CalcTest > divides() FAILED
+    org.opentest4j.AssertionFailedError: expected: <2> but was: <3>
+        at app//org.junit.jupiter.api.AssertionUtils.fail(AssertionUtils.java:151)
+        at app//com.acme.CalcTest.divides(CalcTest.java:31)
+
+12 tests completed, 1 failed, 2 skipped
+
+FAILURE: Build failed with an exception.
+
+* What went wrong:
+Execution failed for task ':app:test'.
+> There were failing tests. See the report at: file:///work/app/build/reports/tests/test/index.html
+
+* Try:
+> Run with --stacktrace option to get the stack trace.
+
+BUILD FAILED in 8s
+5 actionable tasks: 2 executed, 1 from cache, 2 up-to-date
+";
+        let build = GradleParser::parse(output).unwrap();
+        assert!(!build.success);
+        assert_eq!(build.duration.as_deref(), Some("8s"));
+
+        let tests = build.tests.as_ref().unwrap();
+        assert_eq!(
+            (tests.total, tests.passed, tests.failed, tests.skipped),
+            (12, 9, 1, 2)
+        );
+        assert_eq!(tests.failures[0].test_name, "CalcTest.divides");
+
+        assert_eq!(
+            build.format(FormatMode::Compact),
+            "\
+❌ gradle build failed (8s)
+tasks: 2 UP-TO-DATE, 1 EXECUTED, 1 FROM-CACHE, 1 FAILED — failed: :app:test
+
+PASS (9) FAIL (1)
+
+1. CalcTest.divides
+   org.opentest4j.AssertionFailedError: expected: <2> but was: <3> (CalcTest.java:31)
+
+❌ Execution failed for task ':app:test'.
+❌ There were failing tests."
+        );
+    }
+
+    #[test]
+    fn test_gradle_parser_compile_errors() {
+        let output = "\
+> Task :app:compileJava
+/work/app/src/main/java/com/acme/Api.java:12: error: cannot find symbol
+        return client.get();
+               ^
+  symbol:   variable client
+  location: class Api
+/work/app/src/main/java/com/acme/Api.java:3: warning: [deprecation] Date(String) in Date has been deprecated
+e: file:///work/app/src/main/kotlin/Util.kt:7:13 Unresolved reference: foo
+> Task :app:compileJava FAILED
+
+BUILD FAILED in 2s
+";
+        let build = GradleParser::parse(output).unwrap();
+        assert_eq!(build.compile_errors.len(), 3);
+        assert_eq!(
+            build.compile_errors[0].message,
+            "cannot find symbol (variable client)"
+        );
+        let compact = build.format(FormatMode::Compact);
+        assert!(compact.contains("Compile: 2 errors, 1 warnings in 2 files"));
+        assert!(compact.contains("  L12 cannot find symbol (variable client)"));
+        assert!(compact
+            .contains("/work/app/src/main/kotlin/Util.kt (1)\n  L7:13 Unresolved reference: foo"));
+        // Warnings only in verbose mode
+        assert!(!compact.contains("deprecation"));
+        assert!(build
+            .format(FormatMode::Verbose)
+            .contains("L3 warning: [deprecation]"));
+    }
+}
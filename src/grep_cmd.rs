@@ -21,7 +21,7 @@ pub fn run(
     }
 
     let mut rg_cmd = Command::new("rg");
-    rg_cmd.args(["-n", "--no-heading", pattern, path]);
+    rg_cmd.args(["-n", "--no-heading", "-e", pattern, path]);
 
     if let Some(ft) = file_type {
         rg_cmd.arg("--type").arg(ft);
@@ -33,7 +33,11 @@ pub fn run(
 
     let output = rg_cmd
         .output()
-        .or_else(|_| Command::new("grep").args(["-rn", pattern, path]).output())
+        .or_else(|_| {
+            Command::new("grep")
+                .args(["-rn", "-e", pattern, path])
+                .output()
+        })
         .context("grep/rg failed")?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
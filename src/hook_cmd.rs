@@ -0,0 +1,516 @@
+//! Agent hook handlers (`rtk hook <event>`).
+//!
+//! `claude-post` is a Claude Code PostToolUse hook. The PreToolUse rewrite
+//! only helps when a command has an rtk equivalent; this hook catches
+//! everything else after it ran and runs large outputs through the matching
+//! rtk filter (or the generic log/summary dedupe).
+//!
+//! Claude Code only lets PostToolUse hooks replace the output of MCP tools
+//! (`updatedMCPToolOutput`). Bash output always reaches the model as-is, so
+//! Bash events are left alone unless `--bash-context` opts into attaching the
+//! digest as `additionalContext` (extra tokens, no savings recorded).
+
+use crate::tracking;
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::io::Read;
+
+/// Outputs smaller than this (estimated tokens) are passed through untouched.
+const MIN_TOKENS: usize = 500;
+
+/// Max lines kept by the diff filter.
+const DIFF_MAX_LINES: usize = 500;
+
+/// Filter selected for a command's output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PostFilter {
+    CargoBuild,
+    CargoTest,
+    CargoClippy,
+    CargoInstall,
+    GitDiff,
+    GitStatus,
+    GoBuild,
+    GoTest,
+    GoVet,
+    Tsc,
+    Pytest,
+    Vitest,
+    NextBuild,
+    NpmRun,
+    PnpmInstall,
+    Prettier,
+    RuffFormat,
+    Tree,
+    /// Unknown command whose output looks like a log
+    Log,
+    /// Unknown command: heuristic summary
+    Summary,
+}
+
+impl PostFilter {
+    /// Label used for tracking, e.g. `rtk cargo test (post)`.
+    fn label(&self) -> &'static str {
+        match self {
+            PostFilter::CargoBuild => "rtk cargo build (post)",
+            PostFilter::CargoTest => "rtk cargo test (post)",
+            PostFilter::CargoClippy => "rtk cargo clippy (post)",
+            PostFilter::CargoInstall => "rtk cargo install (post)",
+            PostFilter::GitDiff => "rtk git diff (post)",
+            PostFilter::GitStatus => "rtk git status (post)",
+            PostFilter::GoBuild => "rtk go build (post)",
+            PostFilter::GoTest => "rtk go test (post)",
+            PostFilter::GoVet => "rtk go vet (post)",
+            PostFilter::Tsc => "rtk tsc (post)",
+            PostFilter::Pytest => "rtk pytest (post)",
+            PostFilter::Vitest => "rtk vitest run (post)",
+            PostFilter::NextBuild => "rtk next build (post)",
+            PostFilter::NpmRun => "rtk npm (post)",
+            PostFilter::PnpmInstall => "rtk pnpm install (post)",
+            PostFilter::Prettier => "rtk prettier (post)",
+            PostFilter::RuffFormat => "rtk ruff format (post)",
+            PostFilter::Tree => "rtk tree (post)",
+            PostFilter::Log => "rtk log (post)",
+            PostFilter::Summary => "rtk summary (post)",
+        }
+    }
+
+    pub(crate) fn apply(&self, output: &str, command: &str, success: bool) -> String {
+        match self {
+            PostFilter::CargoBuild => crate::cargo_cmd::filter_cargo_build(output),
+            PostFilter::CargoTest => crate::cargo_cmd::filter_cargo_test(output),
+            PostFilter::CargoClippy => crate::cargo_cmd::filter_cargo_clippy(output),
+            PostFilter::CargoInstall => crate::cargo_cmd::filter_cargo_install(output),
+            PostFilter::GitDiff => crate::git::compact_diff(output, DIFF_MAX_LINES),
+            PostFilter::GitStatus => crate::git::filter_status_with_args(output),
+            PostFilter::GoBuild => crate::go_cmd::filter_go_build(output),
+            PostFilter::GoTest => crate::go_cmd::filter_go_test_text(output),
+            PostFilter::GoVet => crate::go_cmd::filter_go_vet(output),
+            PostFilter::Tsc => crate::tsc_cmd::filter_tsc_output(output),
+            PostFilter::Pytest => crate::pytest_cmd::filter_pytest_output(output),
+            PostFilter::Vitest => crate::vitest_cmd::filter_vitest_output(output),
+            PostFilter::NextBuild => crate::next_cmd::filter_next_build(output),
+            PostFilter::NpmRun => crate::npm_cmd::filter_npm_output(output),
+            PostFilter::PnpmInstall => crate::pnpm_cmd::filter_pnpm_install(output),
+            PostFilter::Prettier => crate::prettier_cmd::filter_prettier_output(output),
+            PostFilter::RuffFormat => crate::ruff_cmd::filter_ruff_format(output),
+            PostFilter::Tree => crate::tree::filter_tree_output(output),
+            PostFilter::Log => crate::log_cmd::run_stdin_str(output),
+            PostFilter::Summary => crate::summary::summarize_output(output, command, success),
+        }
+    }
+}
+
+/// Handle a Claude Code PostToolUse event read from stdin.
+///
+/// Prints nothing (leaving the tool output unchanged) unless the output is
+/// large and the filter actually shrinks it. Bash events only get a digest
+/// when `bash_context` is set.
+pub fn run_claude_post(bash_context: bool, verbose: u8) -> Result<()> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .context("Failed to read hook input from stdin")?;
+
+    let event: Value = serde_json::from_str(&input).context("Invalid PostToolUse JSON")?;
+
+    let Some(call) = extract_tool_call(&event) else {
+        return Ok(());
+    };
+    if call.kind == ToolKind::Bash && !bash_context {
+        return Ok(());
+    }
+
+    let timer = tracking::TimedExecution::start();
+
+    let Some((filter, filtered)) = compress(&call.command, &call.output, call.success) else {
+        if verbose > 0 {
+            eprintln!("rtk hook: left output of '{}' unchanged", call.command);
+        }
+        return Ok(());
+    };
+
+    if verbose > 0 {
+        eprintln!("rtk hook: {} -> {}", call.command, filter.label());
+    }
+
+    println!("{}", hook_response(&call, &filtered));
+    // Only a replaced output saves tokens; a Bash digest is extra context
+    if call.kind == ToolKind::Mcp {
+        timer.track(&call.command, filter.label(), &call.output, &filtered);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ToolKind {
+    Bash,
+    /// `mcp__<server>__<tool>`: output can be replaced
+    Mcp,
+}
+
+/// Tool call pulled from a PostToolUse event.
+#[derive(Debug)]
+struct ToolCall {
+    kind: ToolKind,
+    /// Shell command for Bash, tool name for MCP tools
+    command: String,
+    output: String,
+    success: bool,
+    /// Original MCP `tool_response`, kept to answer in the same shape
+    response: Value,
+}
+
+fn extract_tool_call(event: &Value) -> Option<ToolCall> {
+    let tool_name = event.get("tool_name").and_then(|v| v.as_str())?;
+    let response = event.get("tool_response")?;
+
+    if tool_name == "Bash" {
+        let command = event
+            .pointer("/tool_input/command")
+            .and_then(|v| v.as_str())?
+            .trim()
+            .to_string();
+        let (output, success) = bash_output(response)?;
+        return Some(ToolCall {
+            kind: ToolKind::Bash,
+            command,
+            output,
+            success,
+            response: Value::Null,
+        });
+    }
+
+    if tool_name.starts_with("mcp__") {
+        let output = mcp_text(response)?;
+        let success = !response
+            .get("isError")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        return Some(ToolCall {
+            kind: ToolKind::Mcp,
+            command: tool_name.to_string(),
+            output,
+            success,
+            response: response.clone(),
+        });
+    }
+
+    None
+}
+
+/// Combined output and success of a Bash `tool_response`.
+fn bash_output(response: &Value) -> Option<(String, bool)> {
+    match response {
+        Value::String(s) => Some((s.clone(), true)),
+        Value::Object(_) => {
+            let stdout = response
+                .get("stdout")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let stderr = response
+                .get("stderr")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let output = match (stdout.is_empty(), stderr.is_empty()) {
+                (_, true) => stdout.to_string(),
+                (true, false) => stderr.to_string(),
+                (false, false) => format!("{}\n{}", stdout, stderr),
+            };
+            let interrupted = response
+                .get("interrupted")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            // Warnings on stderr don't make a command fail; the exit status does
+            let exit_code = ["exit_code", "exitCode", "returnCode"]
+                .iter()
+                .find_map(|key| response.get(*key).and_then(|v| v.as_i64()));
+            let success = !interrupted && exit_code.is_none_or(|code| code == 0);
+            Some((output, success))
+        }
+        _ => None,
+    }
+}
+
+/// Text of an MCP tool result: a plain string, a list of content blocks, or
+/// an object with a `content` list. Results carrying non-text blocks are
+/// left alone.
+fn mcp_text(response: &Value) -> Option<String> {
+    let blocks = match response {
+        Value::String(s) => return Some(s.clone()),
+        Value::Array(blocks) => blocks,
+        Value::Object(_) => response.get("content")?.as_array()?,
+        _ => return None,
+    };
+    let mut texts = Vec::new();
+    for block in blocks {
+        if block.get("type").and_then(|v| v.as_str()) != Some("text") {
+            return None;
+        }
+        texts.push(block.get("text")?.as_str()?);
+    }
+    Some(texts.join("\n"))
+}
+
+/// Pick a filter and apply it. Returns None when the output should be left as-is.
+fn compress(command: &str, output: &str, success: bool) -> Option<(PostFilter, String)> {
+    if is_rtk_command(command) || tracking::estimate_tokens(output) < MIN_TOKENS {
+        return None;
+    }
+
+    let filter = classify(command).unwrap_or_else(|| {
+        if looks_like_log(output) {
+            PostFilter::Log
+        } else {
+            PostFilter::Summary
+        }
+    });
+
+    let filtered = filter.apply(output, command, success);
+    if filtered.trim().is_empty() || filtered.len() >= output.len() {
+        return None;
+    }
+
+    Some((filter, filtered))
+}
+
+/// Already filtered by rtk (directly or via the PreToolUse rewrite).
+fn is_rtk_command(command: &str) -> bool {
+    command_words(command)
+        .first()
+        .is_some_and(|w| *w == "rtk" || w.ends_with("/rtk"))
+}
+
+/// Leading words of the first command in a chain, skipping `VAR=value` prefixes.
+fn command_words(command: &str) -> Vec<&str> {
+    let first = command.split(['|', ';', '&']).next().unwrap_or("").trim();
+    first
+        .split_whitespace()
+        .skip_while(|w| w.contains('=') && !w.starts_with('-'))
+        .collect()
+}
+
+/// Map a command to a dedicated rtk filter.
+pub(crate) fn classify(command: &str) -> Option<PostFilter> {
+    let words = command_words(command);
+    let mut words = words.iter().map(|w| w.rsplit('/').next().unwrap_or(w));
+    let program = words.next()?;
+    // First non-flag argument is the subcommand
+    let sub = words.find(|w| !w.starts_with('-')).unwrap_or("");
+
+    let filter = match (program, sub) {
+        ("cargo", "build" | "check") => PostFilter::CargoBuild,
+        ("cargo", "test") => PostFilter::CargoTest,
+        ("cargo", "clippy") => PostFilter::CargoClippy,
+        ("cargo", "install") => PostFilter::CargoInstall,
+        ("git", "diff" | "show") => PostFilter::GitDiff,
+        ("git", "status") => PostFilter::GitStatus,
+        ("go", "build") => PostFilter::GoBuild,
+        ("go", "test") => PostFilter::GoTest,
+        ("go", "vet") => PostFilter::GoVet,
+        ("tsc", _) => PostFilter::Tsc,
+        ("npx", "tsc") => PostFilter::Tsc,
+        ("pytest", _) => PostFilter::Pytest,
+        ("python" | "python3", "pytest") => PostFilter::Pytest,
+        ("vitest", _) => PostFilter::Vitest,
+        ("npx", "vitest") => PostFilter::Vitest,
+        ("next", "build") => PostFilter::NextBuild,
+        ("npm", "run") => PostFilter::NpmRun,
+        ("pnpm", "install" | "add" | "i") => PostFilter::PnpmInstall,
+        ("prettier", _) => PostFilter::Prettier,
+        ("npx", "prettier") => PostFilter::Prettier,
+        ("ruff", "format") => PostFilter::RuffFormat,
+        ("tree", _) => PostFilter::Tree,
+        _ => return None,
+    };
+    Some(filter)
+}
+
+/// Heuristic: enough lines carry a log level to benefit from log dedupe.
+pub(crate) fn looks_like_log(output: &str) -> bool {
+    let mut total = 0;
+    let mut leveled = 0;
+    for line in output.lines().filter(|l| !l.trim().is_empty()) {
+        total += 1;
+        let upper = line.to_uppercase();
+        if ["ERROR", "WARN", "INFO", "DEBUG", "TRACE", "FATAL"]
+            .iter()
+            .any(|level| upper.contains(level))
+        {
+            leveled += 1;
+        }
+    }
+    total > 0 && leveled * 3 >= total
+}
+
+/// JSON printed back to Claude Code.
+///
+/// MCP outputs are replaced in the shape they arrived in. Bash outputs can't
+/// be replaced, so the opt-in digest is attached as additional context.
+fn hook_response(call: &ToolCall, filtered: &str) -> Value {
+    match call.kind {
+        ToolKind::Bash => json!({
+            "hookSpecificOutput": {
+                "hookEventName": "PostToolUse",
+                "additionalContext": format!(
+                    "rtk digest of `{}` output:\n{}",
+                    call.command, filtered
+                ),
+            }
+        }),
+        ToolKind::Mcp => {
+            let text_block = json!([{ "type": "text", "text": filtered }]);
+            let updated = match &call.response {
+                Value::String(_) => json!(filtered),
+                Value::Object(obj) => {
+                    let mut obj = obj.clone();
+                    obj.insert("content".to_string(), text_block);
+                    Value::Object(obj)
+                }
+                _ => text_block,
+            };
+            json!({
+                "hookSpecificOutput": {
+                    "hookEventName": "PostToolUse",
+                    "updatedMCPToolOutput": updated,
+                }
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(command: &str, stdout: &str) -> Value {
+        json!({
+            "session_id": "abc",
+            "hook_event_name": "PostToolUse",
+            "tool_name": "Bash",
+            "tool_input": { "command": command },
+            "tool_response": { "stdout": stdout, "stderr": "", "interrupted": false }
+        })
+    }
+
+    #[test]
+    fn test_extract_bash_call() {
+        let call = extract_tool_call(&event("  ls -la ", "a\nb")).unwrap();
+        assert_eq!(call.kind, ToolKind::Bash);
+        assert_eq!(call.command, "ls -la");
+        assert_eq!(call.output, "a\nb");
+        assert!(call.success);
+
+        let mut other = event("ls", "x");
+        other["tool_name"] = json!("Read");
+        assert!(extract_tool_call(&other).is_none());
+    }
+
+    #[test]
+    fn test_bash_success_follows_exit_status() {
+        let mut warned = event("npm install", "added 3 packages");
+        warned["tool_response"]["stderr"] = json!("npm WARN deprecated glob@7.2.3");
+        assert!(extract_tool_call(&warned).unwrap().success);
+
+        let mut failed = event("make", "");
+        failed["tool_response"]["exit_code"] = json!(2);
+        assert!(!extract_tool_call(&failed).unwrap().success);
+
+        let mut interrupted = event("sleep 100", "");
+        interrupted["tool_response"]["interrupted"] = json!(true);
+        assert!(!extract_tool_call(&interrupted).unwrap().success);
+    }
+
+    #[test]
+    fn test_extract_mcp_call() {
+        let mcp = json!({
+            "tool_name": "mcp__github__get_logs",
+            "tool_input": {},
+            "tool_response": [
+                { "type": "text", "text": "line 1" },
+                { "type": "text", "text": "line 2" }
+            ]
+        });
+        let call = extract_tool_call(&mcp).unwrap();
+        assert_eq!(call.kind, ToolKind::Mcp);
+        assert_eq!(call.command, "mcp__github__get_logs");
+        assert_eq!(call.output, "line 1\nline 2");
+
+        let image = json!({
+            "tool_name": "mcp__browser__screenshot",
+            "tool_response": [{ "type": "image", "data": "..." }]
+        });
+        assert!(extract_tool_call(&image).is_none());
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("cargo test --all"), Some(PostFilter::CargoTest));
+        assert_eq!(
+            classify("RUST_LOG=debug cargo build"),
+            Some(PostFilter::CargoBuild)
+        );
+        assert_eq!(
+            classify("git --no-pager diff HEAD"),
+            Some(PostFilter::GitDiff)
+        );
+        assert_eq!(
+            classify("/usr/bin/git status | head"),
+            Some(PostFilter::GitStatus)
+        );
+        assert_eq!(classify("npx tsc --noEmit"), Some(PostFilter::Tsc));
+        assert_eq!(classify("go test ./..."), Some(PostFilter::GoTest));
+        assert_eq!(classify("npx vitest run"), Some(PostFilter::Vitest));
+        assert_eq!(classify("terraform plan"), None);
+    }
+
+    #[test]
+    fn test_compress_skips_small_and_rtk() {
+        assert!(compress("terraform plan", "small output", true).is_none());
+
+        let big = "same line repeated\n".repeat(500);
+        assert!(compress("rtk git log", &big, true).is_none());
+    }
+
+    #[test]
+    fn test_compress_unknown_log_output() {
+        let big: String = (0..400)
+            .map(|i| {
+                format!(
+                    "2024-01-01 10:00:{:02} ERROR connection refused id={}\n",
+                    i % 60,
+                    i
+                )
+            })
+            .collect();
+        let (filter, filtered) = compress("kubectl logs api", &big, true).unwrap();
+        assert_eq!(filter, PostFilter::Log);
+        assert!(filtered.len() < big.len());
+        assert_eq!(filter.label(), "rtk log (post)");
+    }
+
+    #[test]
+    fn test_hook_response_shape() {
+        let bash = extract_tool_call(&event("kubectl logs api", "x")).unwrap();
+        let response = hook_response(&bash, "short");
+        let output = &response["hookSpecificOutput"];
+        assert_eq!(output["hookEventName"], "PostToolUse");
+        assert!(output.get("updatedToolOutput").is_none());
+        assert!(output.get("updatedMCPToolOutput").is_none());
+        assert!(output["additionalContext"]
+            .as_str()
+            .unwrap()
+            .ends_with("\nshort"));
+
+        let mcp = extract_tool_call(&json!({
+            "tool_name": "mcp__logs__tail",
+            "tool_response": { "content": [{ "type": "text", "text": "x" }], "isError": false }
+        }))
+        .unwrap();
+        let response = hook_response(&mcp, "short");
+        let updated = &response["hookSpecificOutput"]["updatedMCPToolOutput"];
+        assert_eq!(updated["content"][0]["text"], "short");
+        assert_eq!(updated["isError"], false);
+    }
+}
@@ -5,10 +5,10 @@ use std::path::{Path, PathBuf};
 use tempfile::NamedTempFile;
 
 // Embedded hook script (guards before set -euo pipefail)
-const REWRITE_HOOK: &str = include_str!("../hooks/rtk-rewrite.sh");
+pub const REWRITE_HOOK: &str = include_str!("../hooks/rtk-rewrite.sh");
 
 // Embedded slim RTK awareness instructions
-const RTK_SLIM: &str = include_str!("../hooks/rtk-awareness.md");
+pub const RTK_SLIM: &str = include_str!("../hooks/rtk-awareness.md");
 
 /// Control flow for settings.json patching
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -27,6 +27,99 @@ pub enum PatchResult {
     Skipped,        // --no-patch flag used
 }
 
+/// Coding agent whose instruction file `rtk init --agent` targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Agent {
+    Claude,
+    Codex,
+    Cursor,
+    Gemini,
+    Copilot,
+    Aider,
+    Windsurf,
+}
+
+impl std::str::FromStr for Agent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "claude" => Ok(Agent::Claude),
+            "codex" => Ok(Agent::Codex),
+            "cursor" => Ok(Agent::Cursor),
+            "gemini" => Ok(Agent::Gemini),
+            "copilot" => Ok(Agent::Copilot),
+            "aider" => Ok(Agent::Aider),
+            "windsurf" => Ok(Agent::Windsurf),
+            _ => Err(format!(
+                "Unknown agent: {} (expected claude, codex, cursor, gemini, copilot, aider, windsurf)",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Agent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Agent::Claude => "claude",
+            Agent::Codex => "codex",
+            Agent::Cursor => "cursor",
+            Agent::Gemini => "gemini",
+            Agent::Copilot => "copilot",
+            Agent::Aider => "aider",
+            Agent::Windsurf => "windsurf",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Agent {
+    /// Instruction file for this agent (project-local or global)
+    fn instructions_path(self, global: bool) -> Result<PathBuf> {
+        let home = || dirs::home_dir().context("Cannot determine home directory. Is $HOME set?");
+        let path = match (self, global) {
+            (Agent::Claude, false) => PathBuf::from("CLAUDE.md"),
+            (Agent::Claude, true) => resolve_claude_dir()?.join("CLAUDE.md"),
+            (Agent::Codex, false) => PathBuf::from("AGENTS.md"),
+            (Agent::Codex, true) => home()?.join(".codex").join("AGENTS.md"),
+            (Agent::Cursor, false) => PathBuf::from(".cursor/rules/rtk.mdc"),
+            (Agent::Gemini, false) => PathBuf::from("GEMINI.md"),
+            (Agent::Gemini, true) => home()?.join(".gemini").join("GEMINI.md"),
+            (Agent::Copilot, false) => PathBuf::from(".github/copilot-instructions.md"),
+            (Agent::Aider, false) => PathBuf::from("CONVENTIONS.md"),
+            (Agent::Windsurf, false) => PathBuf::from(".windsurfrules"),
+            (Agent::Windsurf, true) => home()?
+                .join(".codeium")
+                .join("windsurf")
+                .join("memories")
+                .join("global_rules.md"),
+            (agent, true) => anyhow::bail!(
+                "{} has no global instruction file. Run `rtk init --agent {}` inside the project instead.",
+                agent,
+                agent
+            ),
+        };
+        Ok(path)
+    }
+
+    /// Whether rtk owns the whole file (remove it on uninstall) or only its marker block
+    fn owns_file(self) -> bool {
+        matches!(self, Agent::Cursor)
+    }
+
+    /// Extra setup the agent needs to pick up the instruction file
+    fn post_install_hint(self) -> Option<&'static str> {
+        match self {
+            Agent::Aider => Some("Add `read: CONVENTIONS.md` to .aider.conf.yml so aider loads it"),
+            _ => None,
+        }
+    }
+}
+
+// Cursor project rules need frontmatter to be applied to every request
+const CURSOR_RULE_HEADER: &str = "---\ndescription: RTK (Rust Token Killer) token-optimized commands\nalwaysApply: true\n---\n\n";
+
 // Legacy full instructions for backward compatibility (--claude-md mode)
 const RTK_INSTRUCTIONS: &str = r##"<!-- rtk-instructions v2 -->
 # RTK (Rust Token Killer) - Token-Optimized Commands
@@ -357,7 +450,7 @@ fn remove_hook_from_json(root: &mut serde_json::Value) -> bool {
 
 /// Remove RTK hook from settings.json file
 /// Backs up before modification, returns true if hook was found and removed
-fn remove_hook_from_settings(verbose: u8) -> Result<bool> {
+pub fn remove_hook_from_settings(verbose: u8) -> Result<bool> {
     let claude_dir = resolve_claude_dir()?;
     let settings_path = claude_dir.join("settings.json");
 
@@ -483,8 +576,19 @@ fn patch_settings_json(hook_path: &Path, mode: PatchMode, verbose: u8) -> Result
         if content.trim().is_empty() {
             serde_json::json!({})
         } else {
-            serde_json::from_str(&content)
-                .with_context(|| format!("Failed to parse {} as JSON", settings_path.display()))?
+            match serde_json::from_str(&content) {
+                Ok(root) => root,
+                // --no-patch never writes, so a broken file only needs the manual steps
+                Err(_) if mode == PatchMode::Skip => {
+                    print_manual_instructions(hook_path);
+                    return Ok(PatchResult::Skipped);
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to parse {} as JSON", settings_path.display())
+                    })
+                }
+            }
         }
     } else {
         serde_json::json!({})
@@ -839,6 +943,138 @@ fn patch_claude_md(path: &Path, verbose: u8) -> Result<bool> {
     Ok(migrated)
 }
 
+/// Agent mode: versioned RTK block in the agent's instruction file
+pub fn run_agent(agent: Agent, global: bool, verbose: u8) -> Result<()> {
+    if agent == Agent::Claude {
+        return run_claude_md_mode(global, verbose);
+    }
+
+    let path = agent.instructions_path(global)?;
+    if verbose > 0 {
+        eprintln!("Writing rtk instructions to: {}", path.display());
+    }
+
+    if write_agent_file(agent, &path, verbose)? {
+        println!(
+            "✅ Wrote rtk instructions for {} to {}",
+            agent,
+            path.display()
+        );
+    } else {
+        println!("✅ {} already contains rtk instructions", path.display());
+    }
+
+    if let Some(hint) = agent.post_install_hint() {
+        println!("   {}", hint);
+    }
+
+    Ok(())
+}
+
+/// Write the agent's instructions to `path`; returns true if the file changed
+fn write_agent_file(agent: Agent, path: &Path, verbose: u8) -> Result<bool> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let existing = if path.exists() {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?
+    } else {
+        String::new()
+    };
+
+    let content = if agent.owns_file() {
+        format!("{}{}", CURSOR_RULE_HEADER, RTK_INSTRUCTIONS)
+    } else {
+        upsert_rtk_block(&existing, RTK_INSTRUCTIONS)?
+    };
+
+    write_if_changed(path, &content, &path.display().to_string(), verbose)
+}
+
+/// What uninstalling did to an agent's instruction file
+#[derive(Debug, PartialEq)]
+enum AgentRemoval {
+    NotInstalled,
+    RemovedFile,
+    RemovedBlock,
+}
+
+/// Remove RTK instructions from an agent's instruction file
+pub fn uninstall_agent(agent: Agent, global: bool, verbose: u8) -> Result<()> {
+    if agent == Agent::Claude {
+        return uninstall(global, verbose);
+    }
+
+    let path = agent.instructions_path(global)?;
+    match remove_agent_file(agent, &path)? {
+        AgentRemoval::NotInstalled => {
+            println!("RTK was not installed for {} (nothing to remove)", agent)
+        }
+        AgentRemoval::RemovedFile => println!(
+            "RTK uninstalled for {}:\n  - Removed {}",
+            agent,
+            path.display()
+        ),
+        AgentRemoval::RemovedBlock => println!(
+            "RTK uninstalled for {}:\n  - {}: removed rtk-instructions block",
+            agent,
+            path.display()
+        ),
+    }
+
+    Ok(())
+}
+
+/// Delete files rtk owns; strip only the marker block from shared files
+fn remove_agent_file(agent: Agent, path: &Path) -> Result<AgentRemoval> {
+    if !path.exists() {
+        return Ok(AgentRemoval::NotInstalled);
+    }
+
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let (remaining, removed) = remove_rtk_block(&content);
+
+    if !removed && !agent.owns_file() {
+        return Ok(AgentRemoval::NotInstalled);
+    }
+
+    if agent.owns_file() || remaining.trim().is_empty() {
+        fs::remove_file(path).with_context(|| format!("Failed to remove {}", path.display()))?;
+        Ok(AgentRemoval::RemovedFile)
+    } else {
+        fs::write(path, format!("{}\n", remaining.trim_end()))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(AgentRemoval::RemovedBlock)
+    }
+}
+
+/// Insert or replace the versioned RTK block in place, leaving the rest of the file untouched
+fn upsert_rtk_block(content: &str, block: &str) -> Result<String> {
+    const START: &str = "<!-- rtk-instructions";
+    const END: &str = "<!-- /rtk-instructions -->";
+
+    if content.contains(block.trim()) {
+        return Ok(content.to_string());
+    }
+
+    match (content.find(START), content.find(END)) {
+        (Some(start), Some(end)) if start < end => Ok(format!(
+            "{}{}{}",
+            &content[..start],
+            block.trim_end(),
+            &content[end + END.len()..]
+        )),
+        (Some(_), _) => {
+            anyhow::bail!("Incomplete rtk-instructions block, remove it manually and re-run")
+        }
+        _ if content.trim().is_empty() => Ok(block.to_string()),
+        _ => Ok(format!("{}\n\n{}", content.trim_end(), block)),
+    }
+}
+
 /// Remove old RTK block from CLAUDE.md (migration helper)
 fn remove_rtk_block(content: &str) -> (String, bool) {
     if let (Some(start), Some(end)) = (
@@ -878,7 +1114,7 @@ fn remove_rtk_block(content: &str) -> (String, bool) {
 }
 
 /// Resolve ~/.claude directory with proper home expansion
-fn resolve_claude_dir() -> Result<PathBuf> {
+pub fn resolve_claude_dir() -> Result<PathBuf> {
     dirs::home_dir()
         .map(|h| h.join(".claude"))
         .context("Cannot determine home directory. Is $HOME set?")
@@ -962,6 +1198,24 @@ pub fn show_config() -> Result<()> {
         println!("⚪ Local (./CLAUDE.md): not found");
     }
 
+    // Check other agents' instruction files (project-local)
+    for agent in [
+        Agent::Codex,
+        Agent::Cursor,
+        Agent::Gemini,
+        Agent::Copilot,
+        Agent::Aider,
+        Agent::Windsurf,
+    ] {
+        if let Ok(path) = agent.instructions_path(false) {
+            if let Ok(content) = fs::read_to_string(&path) {
+                if content.contains("<!-- rtk-instructions") {
+                    println!("✅ {} (./{}): rtk enabled", agent, path.display());
+                }
+            }
+        }
+    }
+
     // Check settings.json
     let settings_path = claude_dir.join("settings.json");
     if settings_path.exists() {
@@ -993,6 +1247,10 @@ pub fn show_config() -> Result<()> {
     println!("  rtk init -g --uninstall     # Remove all RTK artifacts");
     println!("  rtk init -g --claude-md     # Legacy: full injection into ~/.claude/CLAUDE.md");
     println!("  rtk init -g --hook-only     # Hook only, no RTK.md");
+    println!(
+        "  rtk init --agent codex      # AGENTS.md (also cursor, gemini, copilot, aider, windsurf)"
+    );
+    println!("  rtk init --agent codex --uninstall  # Remove rtk block from AGENTS.md");
 
     Ok(())
 }
@@ -1366,4 +1624,112 @@ More content"#;
         let removed = remove_hook_from_json(&mut json_content);
         assert!(!removed);
     }
+
+    #[test]
+    fn test_agent_install_is_idempotent() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("AGENTS.md");
+
+        assert!(write_agent_file(Agent::Codex, &path, 0).unwrap());
+        let first = fs::read_to_string(&path).unwrap();
+        assert!(!write_agent_file(Agent::Codex, &path, 0).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), first);
+        assert_eq!(first.matches("<!-- rtk-instructions").count(), 1);
+    }
+
+    #[test]
+    fn test_agent_install_replaces_old_block_in_place() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("GEMINI.md");
+        fs::write(
+            &path,
+            "# Project rules\n\n<!-- rtk-instructions v1 -->\nOLD RTK STUFF\n<!-- /rtk-instructions -->\n\n## Testing\nRun make test.\n",
+        )
+        .unwrap();
+
+        assert!(write_agent_file(Agent::Gemini, &path, 0).unwrap());
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("OLD RTK STUFF"));
+        assert!(content.starts_with("# Project rules\n\n<!-- rtk-instructions v2 -->"));
+        assert!(content.ends_with("<!-- /rtk-instructions -->\n\n## Testing\nRun make test.\n"));
+        assert_eq!(content.matches("<!-- rtk-instructions").count(), 1);
+    }
+
+    #[test]
+    fn test_agent_install_appends_after_user_content() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".github").join("copilot-instructions.md");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "Use tabs.\n").unwrap();
+
+        write_agent_file(Agent::Copilot, &path, 0).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("Use tabs.\n\n<!-- rtk-instructions v2 -->"));
+
+        assert!(
+            upsert_rtk_block("<!-- rtk-instructions v1 -->\nno end", RTK_INSTRUCTIONS).is_err()
+        );
+    }
+
+    #[test]
+    fn test_agent_uninstall_strips_block_only() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("CONVENTIONS.md");
+        fs::write(&path, "# Conventions\n\nPrefer small PRs.\n").unwrap();
+        write_agent_file(Agent::Aider, &path, 0).unwrap();
+
+        assert_eq!(
+            remove_agent_file(Agent::Aider, &path).unwrap(),
+            AgentRemoval::RemovedBlock
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# Conventions\n\nPrefer small PRs.\n"
+        );
+        assert_eq!(
+            remove_agent_file(Agent::Aider, &path).unwrap(),
+            AgentRemoval::NotInstalled
+        );
+
+        // A file holding nothing but the block goes away
+        let only = temp.path().join(".windsurfrules");
+        write_agent_file(Agent::Windsurf, &only, 0).unwrap();
+        assert_eq!(
+            remove_agent_file(Agent::Windsurf, &only).unwrap(),
+            AgentRemoval::RemovedFile
+        );
+        assert!(!only.exists());
+    }
+
+    #[test]
+    fn test_cursor_uninstall_deletes_owned_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".cursor").join("rules").join("rtk.mdc");
+
+        assert!(write_agent_file(Agent::Cursor, &path, 0).unwrap());
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with(CURSOR_RULE_HEADER));
+        assert!(!write_agent_file(Agent::Cursor, &path, 0).unwrap());
+
+        assert_eq!(
+            remove_agent_file(Agent::Cursor, &path).unwrap(),
+            AgentRemoval::RemovedFile
+        );
+        assert!(!path.exists());
+        assert_eq!(
+            remove_agent_file(Agent::Cursor, &path).unwrap(),
+            AgentRemoval::NotInstalled
+        );
+    }
+
+    #[test]
+    fn test_agent_global_requires_global_file() {
+        for agent in [Agent::Cursor, Agent::Copilot, Agent::Aider] {
+            let err = agent.instructions_path(true).unwrap_err().to_string();
+            assert!(err.contains("has no global instruction file"), "{}", err);
+            assert!(run_agent(agent, true, 0).is_err());
+            assert!(uninstall_agent(agent, true, 0).is_err());
+        }
+        assert!(Agent::Codex.instructions_path(true).is_ok());
+    }
 }
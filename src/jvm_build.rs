@@ -0,0 +1,439 @@
+//! Shared pieces of the Maven and Gradle filters: the build summary type and
+//! its `TokenFormatter` impl, JUnit XML report parsing (Surefire, Failsafe and
+//! Gradle all write the same format) and stack-trace trimming.
+
+use crate::parser::junit::parse_junit;
+use crate::parser::{
+    xml_attr, FormatMode, LintIssue, LintSeverity, OutputParser, ParseResult, TestFailure,
+    TestResult, TokenFormatter,
+};
+use crate::utils::truncate;
+use std::collections::HashMap;
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// Application stack frames kept per failed test.
+const MAX_STACK_FRAMES: usize = 3;
+/// Compile errors shown in compact mode.
+const MAX_COMPACT_ERRORS: usize = 20;
+
+/// Frames from test frameworks, build tools and the JDK itself.
+const FRAMEWORK_FRAMES: &[&str] = &[
+    "org.junit.",
+    "junit.",
+    "org.opentest4j.",
+    "org.assertj.",
+    "org.hamcrest.",
+    "org.mockito.",
+    "org.apache.maven.",
+    "org.gradle.",
+    "worker.org.gradle.",
+    "java.base/",
+    "java.lang.reflect.",
+    "java.util.",
+    "jdk.internal.",
+    "jdk.proxy",
+    "sun.reflect.",
+    "kotlin.",
+    "kotlinx.",
+];
+
+/// Outcome of a Maven or Gradle run.
+#[derive(Debug)]
+pub(crate) struct JvmBuild {
+    pub tool: &'static str,
+    /// What `outcomes` lists: "modules" (Maven reactor) or "tasks" (Gradle)
+    pub unit: &'static str,
+    pub success: bool,
+    pub duration: Option<String>,
+    /// (module or task, status) in execution order
+    pub outcomes: Vec<(String, String)>,
+    pub compile_errors: Vec<LintIssue>,
+    pub tests: Option<TestResult>,
+    /// "What went wrong" / "Failed to execute goal" lines
+    pub failure: Vec<String>,
+}
+
+impl JvmBuild {
+    pub fn new(tool: &'static str, unit: &'static str) -> Self {
+        JvmBuild {
+            tool,
+            unit,
+            success: true,
+            duration: None,
+            outcomes: Vec::new(),
+            compile_errors: Vec::new(),
+            tests: None,
+            failure: Vec::new(),
+        }
+    }
+
+    /// Add a compiler diagnostic, skipping exact repeats (Maven prints them twice).
+    pub fn push_compile_error(&mut self, issue: LintIssue) {
+        let duplicate = self.compile_errors.iter().any(|e| {
+            e.file_path == issue.file_path
+                && e.line == issue.line
+                && e.column == issue.column
+                && e.message == issue.message
+        });
+        if !duplicate {
+            self.compile_errors.push(issue);
+        }
+    }
+
+    fn header(&self) -> String {
+        let status = if self.success {
+            format!("✓ {} build succeeded", self.tool)
+        } else {
+            format!("❌ {} build failed", self.tool)
+        };
+        match &self.duration {
+            Some(duration) => format!("{} ({})", status, duration),
+            None => status,
+        }
+    }
+
+    /// "modules: 3 SUCCESS, 1 FAILURE — failed: api"
+    fn outcome_line(&self) -> Option<String> {
+        if self.outcomes.is_empty() {
+            return None;
+        }
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+        for (_, status) in &self.outcomes {
+            match counts.iter_mut().find(|(s, _)| s == status) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((status, 1)),
+            }
+        }
+        let summary: Vec<String> = counts
+            .iter()
+            .map(|(status, n)| format!("{} {}", n, status))
+            .collect();
+        let failed: Vec<&str> = self
+            .outcomes
+            .iter()
+            .filter(|(_, status)| status == "FAILED" || status == "FAILURE")
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let mut line = format!("{}: {}", self.unit, summary.join(", "));
+        if !failed.is_empty() {
+            line.push_str(&format!(" — failed: {}", failed.join(", ")));
+        }
+        Some(line)
+    }
+
+    fn compile_lines(&self, include_warnings: bool, limit: usize) -> Vec<String> {
+        let errors = self
+            .compile_errors
+            .iter()
+            .filter(|e| e.severity == LintSeverity::Error)
+            .count();
+        let warnings = self.compile_errors.len() - errors;
+        if errors == 0 && (warnings == 0 || !include_warnings) {
+            return Vec::new();
+        }
+
+        let shown: Vec<&LintIssue> = self
+            .compile_errors
+            .iter()
+            .filter(|e| include_warnings || e.severity == LintSeverity::Error)
+            .collect();
+
+        // Group by file, keeping first-seen order
+        let mut files: Vec<&str> = Vec::new();
+        let mut by_file: HashMap<&str, Vec<&LintIssue>> = HashMap::new();
+        for issue in &shown {
+            if !by_file.contains_key(issue.file_path.as_str()) {
+                files.push(&issue.file_path);
+            }
+            by_file.entry(&issue.file_path).or_default().push(issue);
+        }
+
+        let mut lines = vec![format!(
+            "Compile: {} errors, {} warnings in {} files",
+            errors,
+            warnings,
+            files.len()
+        )];
+        let mut remaining = limit;
+        for file in files {
+            if remaining == 0 {
+                break;
+            }
+            let issues = &by_file[file];
+            lines.push(format!("{} ({})", file, issues.len()));
+            for issue in issues.iter().take(remaining) {
+                let location = if issue.column > 0 {
+                    format!("L{}:{}", issue.line, issue.column)
+                } else {
+                    format!("L{}", issue.line)
+                };
+                let marker = if issue.severity == LintSeverity::Warning {
+                    " warning:"
+                } else {
+                    ""
+                };
+                lines.push(format!(
+                    "  {}{} {}",
+                    location,
+                    marker,
+                    truncate(&issue.message, 150)
+                ));
+            }
+            remaining = remaining.saturating_sub(issues.len());
+        }
+        if shown.len() > limit {
+            lines.push(format!("... +{} more", shown.len() - limit));
+        }
+        lines
+    }
+
+    fn format_with(&self, mode: FormatMode) -> String {
+        let mut lines = vec![self.header()];
+        if let Some(outcome) = self.outcome_line() {
+            lines.push(outcome);
+        }
+        if mode == FormatMode::Verbose {
+            for (name, status) in &self.outcomes {
+                lines.push(format!("  {} {}", name, status));
+            }
+        }
+
+        let verbose = mode == FormatMode::Verbose;
+        let compile = self.compile_lines(
+            verbose,
+            if verbose {
+                usize::MAX
+            } else {
+                MAX_COMPACT_ERRORS
+            },
+        );
+        if !compile.is_empty() {
+            lines.push(String::new());
+            lines.extend(compile);
+        }
+
+        if let Some(tests) = &self.tests {
+            lines.push(String::new());
+            lines.push(tests.format(mode));
+        }
+
+        if !self.failure.is_empty() {
+            lines.push(String::new());
+            let limit = if verbose { self.failure.len() } else { 3 };
+            lines.extend(self.failure.iter().take(limit).cloned());
+        }
+        lines.join("\n")
+    }
+}
+
+impl TokenFormatter for JvmBuild {
+    fn format_compact(&self) -> String {
+        self.format_with(FormatMode::Compact)
+    }
+
+    fn format_verbose(&self) -> String {
+        self.format_with(FormatMode::Verbose)
+    }
+
+    fn format_ultra(&self) -> String {
+        let mut parts = vec![format!(
+            "{} {}",
+            if self.success { "✓" } else { "✗" },
+            self.tool
+        )];
+        if !self.outcomes.is_empty() {
+            let failed = self
+                .outcomes
+                .iter()
+                .filter(|(_, s)| s == "FAILED" || s == "FAILURE")
+                .count();
+            parts.push(format!("{}:{}/{}", self.unit, failed, self.outcomes.len()));
+        }
+        let errors = self
+            .compile_errors
+            .iter()
+            .filter(|e| e.severity == LintSeverity::Error)
+            .count();
+        if errors > 0 {
+            parts.push(format!("javac✗{}", errors));
+        }
+        if let Some(tests) = &self.tests {
+            parts.push(tests.format_ultra());
+        }
+        parts.join(" | ")
+    }
+}
+
+/// Exception line plus the first few application frames of a Java stack trace.
+pub(crate) fn trim_stack_trace(trace: &str) -> (String, Option<String>) {
+    let mut lines = trace.lines().map(str::trim).filter(|l| !l.is_empty());
+    let exception = lines.next().unwrap_or("").to_string();
+    let frames: Vec<String> = lines
+        .filter_map(|l| l.strip_prefix("at "))
+        // Gradle prefixes frames with the class loader: "app//com.acme.Foo.bar(...)"
+        .map(|frame| match frame.split_once("//") {
+            Some((loader, rest)) if !loader.contains('(') => rest,
+            _ => frame,
+        })
+        .filter(|frame| !FRAMEWORK_FRAMES.iter().any(|p| frame.starts_with(p)))
+        .take(MAX_STACK_FRAMES)
+        .map(|frame| format!("at {}", frame))
+        .collect();
+    if frames.is_empty() {
+        (exception, None)
+    } else {
+        (exception, Some(frames.join("\n")))
+    }
+}
+
+/// Failed test with its exception located at the first application frame,
+/// e.g. "AssertionFailedError: expected: <2> but was: <3> (Calc.java:20)".
+pub(crate) fn test_failure(test_name: String, file_path: String, trace: &str) -> TestFailure {
+    let (exception, frames) = trim_stack_trace(trace);
+    let location = frames
+        .as_deref()
+        .and_then(|f| f.lines().next())
+        .and_then(|first| {
+            first
+                .rsplit_once('(')
+                .map(|(_, loc)| loc.trim_end_matches(')'))
+        });
+    let error_message = match location {
+        Some(location) => format!("{} ({})", exception, location),
+        None => exception,
+    };
+    TestFailure {
+        test_name,
+        file_path,
+        error_message,
+        stack_trace: frames,
+    }
+}
+
+/// Parses one or more concatenated JUnit XML reports (`TEST-*.xml`).
+pub(crate) struct JunitReportParser;
+
+impl OutputParser for JunitReportParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let result = parse_junit(input, |attrs, text| {
+            let class = xml_attr(attrs, "classname").unwrap_or_default();
+            let name = xml_attr(attrs, "name").unwrap_or_default();
+            let short_class = class.rsplit('.').next().unwrap_or(&class).to_string();
+            test_failure(format!("{}.{}", short_class, name), class, text)
+        });
+        match result {
+            Some(result) => ParseResult::Full(result),
+            None => ParseResult::Passthrough(String::new()),
+        }
+    }
+}
+
+/// Concatenated `TEST-*.xml` reports under `report_dirs` written since `since`.
+pub(crate) fn collect_reports(report_dirs: &[&str], since: SystemTime) -> String {
+    let mut reports = String::new();
+    let walker = WalkDir::new(".")
+        .max_depth(8)
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            !(name.starts_with('.') && name.len() > 1) && name != "node_modules" && name != "src"
+        })
+        .filter_map(|e| e.ok());
+
+    for entry in walker {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy();
+        if !name.starts_with("TEST-") || !name.ends_with(".xml") {
+            continue;
+        }
+        let in_report_dir = path
+            .components()
+            .any(|c| report_dirs.contains(&c.as_os_str().to_string_lossy().as_ref()));
+        let fresh = entry
+            .metadata()
+            .ok()
+            .and_then(|m| m.modified().ok())
+            .is_some_and(|modified| modified >= since);
+        if in_report_dir && fresh {
+            if let Ok(content) = std::fs::read_to_string(path) {
+                reports.push_str(&content);
+                reports.push('\n');
+            }
+        }
+    }
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_junit_report_parser() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuite name="com.acme.CalculatorTest" tests="3" failures="1" errors="0" skipped="1" time="0.25">
+  <testcase name="adds" classname="com.acme.CalculatorTest" time="0.01"/>
+  <testcase name="divides" classname="com.acme.CalculatorTest" time="0.02">
+    <failure message="expected: &lt;2&gt; but was: &lt;3&gt;" type="org.opentest4j.AssertionFailedError"><![CDATA[org.opentest4j.AssertionFailedError: expected: <2> but was: <3>
+	at org.junit.jupiter.api.AssertionUtils.fail(AssertionUtils.java:55)
+	at org.junit.jupiter.api.Assertions.assertEquals(Assertions.java:145)
+	at com.acme.Calculator.divide(Calculator.java:20)
+	at com.acme.CalculatorTest.divides(CalculatorTest.java:31)
+	at java.base/java.lang.reflect.Method.invoke(Method.java:580)
+]]></failure>
+  </testcase>
+  <testcase name="pending" classname="com.acme.CalculatorTest" time="0"><skipped/></testcase>
+</testsuite>
+"#;
+        let result = JunitReportParser::parse(xml).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (3, 1, 1, 1)
+        );
+        assert_eq!(result.duration_ms, Some(250));
+        let failure = &result.failures[0];
+        assert_eq!(failure.test_name, "CalculatorTest.divides");
+        assert_eq!(
+            failure.error_message,
+            "org.opentest4j.AssertionFailedError: expected: <2> but was: <3> (Calculator.java:20)"
+        );
+        assert_eq!(
+            failure.stack_trace.as_deref(),
+            Some("at com.acme.Calculator.divide(Calculator.java:20)\nat com.acme.CalculatorTest.divides(CalculatorTest.java:31)")
+        );
+    }
+
+    #[test]
+    fn test_jvm_build_format_modes() {
+        let mut build = JvmBuild::new("mvn", "modules");
+        build.success = false;
+        build.outcomes = vec![
+            ("core".to_string(), "SUCCESS".to_string()),
+            ("api".to_string(), "FAILURE".to_string()),
+            ("app".to_string(), "SKIPPED".to_string()),
+        ];
+        for _ in 0..2 {
+            build.push_compile_error(LintIssue {
+                file_path: "api/src/main/java/Api.java".to_string(),
+                line: 12,
+                column: 5,
+                severity: LintSeverity::Error,
+                rule_id: "javac".to_string(),
+                message: "cannot find symbol".to_string(),
+            });
+        }
+
+        assert_eq!(
+            build.format(FormatMode::Compact),
+            "❌ mvn build failed\nmodules: 1 SUCCESS, 1 FAILURE, 1 SKIPPED — failed: api\n\nCompile: 1 errors, 0 warnings in 1 files\napi/src/main/java/Api.java (1)\n  L12:5 cannot find symbol"
+        );
+        assert_eq!(
+            build.format(FormatMode::Ultra),
+            "✗ mvn | modules:1/3 | javac✗1"
+        );
+        assert!(build.format(FormatMode::Verbose).contains("  app SKIPPED"));
+    }
+}
@@ -51,25 +51,38 @@ fn is_python_linter(linter: &str) -> bool {
     matches!(linter, "ruff" | "pylint" | "mypy" | "flake8")
 }
 
-pub fn run(args: &[String], verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
-
-    // Detect linter name (first arg if not a path/flag, else default to eslint)
+/// Detect linter name (first arg if not a path/flag, else default to eslint)
+fn detect_linter(args: &[String]) -> (&str, bool) {
     let is_path_or_flag = args.is_empty()
         || args[0].starts_with('-')
         || args[0].contains('/')
         || args[0].contains('.');
 
     let linter = if is_path_or_flag { "eslint" } else { &args[0] };
+    (linter, is_path_or_flag)
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let (linter, _) = detect_linter(args);
 
     // Python linters use Command::new() directly (they're on PATH via pip/pipx)
     // JS linters use package_manager_exec (npx/pnpm exec)
-    let mut cmd = if is_python_linter(linter) {
+    let cmd = if is_python_linter(linter) {
         Command::new(linter)
     } else {
         package_manager_exec(linter)
     };
 
+    run_with(cmd, args, verbose)
+}
+
+/// Run a linter through an already-built command (e.g. `uv run mypy`).
+/// `args` are the same as for `rtk lint`, linter name first.
+pub(crate) fn run_with(mut cmd: Command, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let (linter, is_path_or_flag) = detect_linter(args);
+
     // Add format flags based on linter
     match linter {
         "eslint" => {
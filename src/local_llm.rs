@@ -120,6 +120,7 @@ fn lang_display_name(lang: &Language) -> &'static str {
         Language::Cpp => "C++",
         Language::Java => "Java",
         Language::Ruby => "Ruby",
+        Language::Php => "PHP",
         Language::Shell => "Shell",
         Language::Unknown => "Code",
     }
@@ -133,6 +134,9 @@ fn extract_imports(content: &str, lang: &Language) -> Vec<String> {
             r#"(?:import.*from\s+['"]([^'"]+)['"]|require\(['"]([^'"]+)['"]\))"#
         }
         Language::Go => r#"^\s*"([^"]+)"$"#,
+        Language::Php => {
+            r"^use\s+(?:function\s+|const\s+)?\\?([A-Za-z_][A-Za-z0-9_]*(?:\\[A-Za-z_][A-Za-z0-9_]*)?)"
+        }
         _ => return Vec::new(),
     };
 
@@ -172,6 +176,7 @@ fn extract_functions(content: &str, lang: &Language) -> Vec<String> {
             r"(?:async\s+)?function\s+([a-zA-Z_][a-zA-Z0-9_]*)|(?:const|let|var)\s+([a-zA-Z_][a-zA-Z0-9_]*)\s*=\s*(?:async\s+)?\("
         }
         Language::Go => r"func\s+(?:\([^)]+\)\s+)?([a-zA-Z_][a-zA-Z0-9_]*)",
+        Language::Php => r"function\s+&?([a-zA-Z_][a-zA-Z0-9_]*)",
         _ => return Vec::new(),
     };
 
@@ -199,6 +204,9 @@ fn extract_structs(content: &str, lang: &Language) -> Vec<String> {
         Language::TypeScript => r"(?:interface|class|type)\s+([a-zA-Z_][a-zA-Z0-9_]*)",
         Language::Go => r"type\s+([a-zA-Z_][a-zA-Z0-9_]*)\s+struct",
         Language::Java => r"(?:public\s+)?class\s+([a-zA-Z_][a-zA-Z0-9_]*)",
+        Language::Php => {
+            r"(?m)^\s*(?:(?:abstract|final|readonly)\s+)*(?:class|enum)\s+([a-zA-Z_][a-zA-Z0-9_]*)"
+        }
         _ => return Vec::new(),
     };
 
@@ -213,6 +221,7 @@ fn extract_traits(content: &str, lang: &Language) -> Vec<String> {
     let pattern = match lang {
         Language::Rust => r"(?:pub\s+)?trait\s+([a-zA-Z_][a-zA-Z0-9_]*)",
         Language::TypeScript => r"interface\s+([a-zA-Z_][a-zA-Z0-9_]*)",
+        Language::Php => r"(?m)^\s*(?:interface|trait)\s+([a-zA-Z_][a-zA-Z0-9_]*)",
         _ => return Vec::new(),
     };
 
@@ -265,6 +274,17 @@ fn detect_patterns(content: &str, lang: &Language) -> Vec<String> {
                 patterns.push("ES modules".to_string());
             }
         }
+        Language::Php => {
+            if content.contains("Illuminate\\") {
+                patterns.push("Laravel".to_string());
+            }
+            if content.contains("Symfony\\") {
+                patterns.push("Symfony".to_string());
+            }
+            if content.contains("extends TestCase") {
+                patterns.push("tests".to_string());
+            }
+        }
         _ => {}
     }
 
@@ -312,4 +332,33 @@ def load_config():
         let summary = analyze_code(code, &Language::Python);
         assert!(summary.line1.contains("Python"));
     }
+
+    #[test]
+    fn test_php_analysis() {
+        let code = r#"<?php
+namespace App\Http\Controllers;
+
+use Illuminate\Http\Request;
+use App\Models\User;
+
+final class UserController extends Controller
+{
+    public function index(Request $request)
+    {
+        return User::paginate();
+    }
+
+    public function show(User $user)
+    {
+        return $user;
+    }
+}
+"#;
+        let summary = analyze_code(code, &Language::Php);
+        assert_eq!(summary.line1, "PHP module (2 fn, 1 struct) - 18 lines");
+        assert!(summary
+            .line2
+            .contains("uses: Illuminate\\Http, App\\Models"));
+        assert!(summary.line2.contains("Laravel"));
+    }
 }
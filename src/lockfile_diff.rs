@@ -0,0 +1,451 @@
+//! Semantic summaries for lockfile and generated-file diffs.
+//!
+//! Lockfile hunks are mostly hash and metadata churn. Instead of showing them,
+//! the package/version pairs on each side are compared and reported as
+//! added, removed and bumped packages. Generated files get a one-line note.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Max packages listed per lockfile before collapsing.
+const MAX_PACKAGES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum LockKind {
+    /// `[[package]]` tables with name/version (Cargo.lock, poetry.lock, uv.lock)
+    Toml,
+    Npm,
+    Pnpm,
+    Yarn,
+    GoSum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SpecialFile {
+    Lockfile(LockKind),
+    Generated,
+}
+
+/// Detect lockfiles and generated files by path.
+pub(crate) fn classify_path(path: &str) -> Option<SpecialFile> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+
+    let lock = match name {
+        "Cargo.lock" | "poetry.lock" | "uv.lock" => Some(LockKind::Toml),
+        "package-lock.json" | "npm-shrinkwrap.json" => Some(LockKind::Npm),
+        "pnpm-lock.yaml" => Some(LockKind::Pnpm),
+        "yarn.lock" => Some(LockKind::Yarn),
+        "go.sum" => Some(LockKind::GoSum),
+        _ => None,
+    };
+    if let Some(kind) = lock {
+        return Some(SpecialFile::Lockfile(kind));
+    }
+
+    const GENERATED_SUFFIXES: &[&str] = &[
+        ".min.js",
+        ".min.css",
+        ".min.mjs",
+        ".js.map",
+        ".css.map",
+        ".pb.go",
+        ".pb.gw.go",
+        ".pb.cc",
+        ".pb.h",
+        ".pb.rs",
+        ".pb.swift",
+        ".pb.dart",
+        "_pb2.py",
+        "_pb2.pyi",
+        "_pb2_grpc.py",
+        "_pb.js",
+        "_pb.d.ts",
+        "_grpc_pb.js",
+        "_grpc_pb.d.ts",
+    ];
+    if GENERATED_SUFFIXES.iter().any(|s| name.ends_with(s)) {
+        return Some(SpecialFile::Generated);
+    }
+
+    None
+}
+
+/// Summarize the hunk lines (`+`, `-` and context) of one file's diff.
+pub(crate) fn summarize_hunks(kind: SpecialFile, hunk_lines: &[&str]) -> Vec<String> {
+    let changed = hunk_lines
+        .iter()
+        .filter(|l| l.starts_with('+') || l.starts_with('-'))
+        .count();
+
+    match kind {
+        SpecialFile::Generated => vec![generated_note(changed)],
+        SpecialFile::Lockfile(lock) => {
+            // Rebuild both sides of the hunks (context belongs to both)
+            let old: Vec<&str> = hunk_lines
+                .iter()
+                .filter(|l| !l.starts_with('+') && !l.starts_with('\\'))
+                .map(|l| l.get(1..).unwrap_or(""))
+                .collect();
+            let new: Vec<&str> = hunk_lines
+                .iter()
+                .filter(|l| !l.starts_with('-') && !l.starts_with('\\'))
+                .map(|l| l.get(1..).unwrap_or(""))
+                .collect();
+            summarize_packages(
+                &parse_packages(lock, &old),
+                &parse_packages(lock, &new),
+                changed,
+            )
+        }
+    }
+}
+
+/// Summarize a change between two full versions of a file.
+pub(crate) fn summarize_contents(kind: SpecialFile, old: &str, new: &str) -> Vec<String> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let changed = old_lines.len().abs_diff(new_lines.len())
+        + old_lines
+            .iter()
+            .zip(new_lines.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+
+    match kind {
+        SpecialFile::Generated => vec![generated_note(changed)],
+        SpecialFile::Lockfile(lock) => summarize_packages(
+            &parse_packages(lock, &old_lines),
+            &parse_packages(lock, &new_lines),
+            changed,
+        ),
+    }
+}
+
+fn generated_note(changed: usize) -> String {
+    format!("⚙️  generated, {} lines changed", changed)
+}
+
+fn summarize_packages(
+    old: &[(String, String)],
+    new: &[(String, String)],
+    changed: usize,
+) -> Vec<String> {
+    let group = |pkgs: &[(String, String)]| {
+        let mut map: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for (name, version) in pkgs {
+            map.entry(name.clone()).or_default().insert(version.clone());
+        }
+        map
+    };
+    let old = group(old);
+    let new = group(new);
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut bumped = Vec::new();
+
+    for (name, versions) in &new {
+        match old.get(name) {
+            None => added.push(format!("+ {} {}", name, join(versions.iter()))),
+            Some(old_versions) if old_versions != versions => {
+                let before = join(old_versions.difference(versions));
+                let after = join(versions.difference(old_versions));
+                if before.is_empty() {
+                    added.push(format!("+ {} {}", name, after));
+                } else if after.is_empty() {
+                    removed.push(format!("- {} {}", name, before));
+                } else {
+                    bumped.push(format!("~ {} {} → {}", name, before, after));
+                }
+            }
+            _ => {}
+        }
+    }
+    for (name, versions) in &old {
+        if !new.contains_key(name) {
+            removed.push(format!("- {} {}", name, join(versions.iter())));
+        }
+    }
+
+    if added.is_empty() && removed.is_empty() && bumped.is_empty() {
+        return vec![format!(
+            "📦 lockfile: no package changes ({} lines of metadata churn)",
+            changed
+        )];
+    }
+
+    let mut out = vec![format!(
+        "📦 lockfile: {} added, {} removed, {} bumped",
+        added.len(),
+        removed.len(),
+        bumped.len()
+    )];
+    let entries: Vec<String> = added.into_iter().chain(removed).chain(bumped).collect();
+    let total = entries.len();
+    out.extend(
+        entries
+            .into_iter()
+            .take(MAX_PACKAGES)
+            .map(|e| format!("  {}", e)),
+    );
+    if total > MAX_PACKAGES {
+        out.push(format!("  ... +{} more", total - MAX_PACKAGES));
+    }
+    out
+}
+
+fn join<'a>(versions: impl Iterator<Item = &'a String>) -> String {
+    versions.map(|v| v.as_str()).collect::<Vec<_>>().join(", ")
+}
+
+/// Extract (name, version) pairs from lockfile lines.
+fn parse_packages(kind: LockKind, lines: &[&str]) -> Vec<(String, String)> {
+    match kind {
+        LockKind::Toml => parse_toml_lock(lines),
+        LockKind::Npm => parse_npm_lock(lines),
+        LockKind::Pnpm => parse_pnpm_lock(lines),
+        LockKind::Yarn => parse_yarn_lock(lines),
+        LockKind::GoSum => parse_go_sum(lines),
+    }
+}
+
+fn quoted_value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let rest = line.trim().strip_prefix(key)?.trim_start();
+    let rest = rest.strip_prefix('=').or_else(|| rest.strip_prefix(':'))?;
+    Some(rest.trim().trim_end_matches(',').trim_matches('"'))
+}
+
+fn parse_toml_lock(lines: &[&str]) -> Vec<(String, String)> {
+    let mut packages = Vec::new();
+    let mut name: Option<String> = None;
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            name = None;
+        } else if let Some(n) = quoted_value(trimmed, "name") {
+            name = Some(n.to_string());
+        } else if let Some(v) = quoted_value(trimmed, "version") {
+            if let Some(n) = name.take() {
+                packages.push((n, v.to_string()));
+            }
+        }
+    }
+    packages
+}
+
+fn parse_npm_lock(lines: &[&str]) -> Vec<(String, String)> {
+    const NON_PACKAGE_KEYS: &[&str] = &[
+        "",
+        "packages",
+        "dependencies",
+        "devDependencies",
+        "peerDependencies",
+        "optionalDependencies",
+        "peerDependenciesMeta",
+        "requires",
+        "engines",
+        "bin",
+        "funding",
+    ];
+
+    let mut packages = Vec::new();
+    let mut name: Option<String> = None;
+    for line in lines {
+        let trimmed = line.trim();
+        if let Some(key) = trimmed
+            .strip_suffix('{')
+            .and_then(|k| k.trim_end().strip_suffix(':'))
+        {
+            let key = key.trim().trim_matches('"');
+            name = if NON_PACKAGE_KEYS.contains(&key) {
+                None
+            } else {
+                let pkg = key.rsplit("node_modules/").next().unwrap_or(key);
+                Some(pkg.to_string())
+            };
+        } else if let Some(v) = quoted_value(trimmed, "\"version\"") {
+            if let Some(n) = name.take() {
+                packages.push((n, v.to_string()));
+            }
+        }
+    }
+    packages
+}
+
+/// Split `name@version` where the name itself may start with `@`.
+fn split_at_version(spec: &str) -> Option<(&str, &str)> {
+    let at = spec.rfind('@').filter(|i| *i > 0)?;
+    Some((&spec[..at], &spec[at + 1..]))
+}
+
+fn parse_pnpm_lock(lines: &[&str]) -> Vec<(String, String)> {
+    let mut packages = Vec::new();
+    for line in lines {
+        let indent = line.len() - line.trim_start().len();
+        let trimmed = line.trim();
+        if indent != 2 || !trimmed.ends_with(':') {
+            continue;
+        }
+        let key = trimmed
+            .trim_end_matches(':')
+            .trim_matches(|c| c == '\'' || c == '"');
+        let key = key.strip_prefix('/').unwrap_or(key);
+        // Drop peer-dependency suffix: foo@1.0.0(react@18.0.0)
+        let key = key.split('(').next().unwrap_or(key);
+
+        if let Some((name, version)) = split_at_version(key) {
+            packages.push((name.to_string(), version.to_string()));
+        } else if let Some((name, version)) = key.rsplit_once('/') {
+            // lockfile v5: /foo/1.0.0
+            if version.starts_with(|c: char| c.is_ascii_digit()) {
+                packages.push((name.to_string(), version.to_string()));
+            }
+        }
+    }
+    packages
+}
+
+fn parse_yarn_lock(lines: &[&str]) -> Vec<(String, String)> {
+    let mut packages = Vec::new();
+    let mut name: Option<String> = None;
+    for line in lines {
+        if !line.starts_with(' ') && line.trim_end().ends_with(':') && !line.starts_with('#') {
+            // "@babel/core@^7.0.0", "@babel/core@^7.1.0":
+            let first = line.split(',').next().unwrap_or("").trim_end_matches(':');
+            let spec = first.trim().trim_matches('"');
+            name = split_at_version(spec).map(|(n, _)| n.to_string());
+        } else if let Some(v) = quoted_value(line, "version") {
+            if let Some(n) = name.take() {
+                packages.push((n, v.to_string()));
+            }
+        } else if let Some(v) = line.trim().strip_prefix("version ") {
+            if let Some(n) = name.take() {
+                packages.push((n, v.trim_matches('"').to_string()));
+            }
+        }
+    }
+    packages
+}
+
+fn parse_go_sum(lines: &[&str]) -> Vec<(String, String)> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let module = parts.next()?;
+            let version = parts.next()?;
+            let version = version.strip_suffix("/go.mod").unwrap_or(version);
+            Some((module.to_string(), version.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_path() {
+        assert_eq!(
+            classify_path("Cargo.lock"),
+            Some(SpecialFile::Lockfile(LockKind::Toml))
+        );
+        assert_eq!(
+            classify_path("web/package-lock.json"),
+            Some(SpecialFile::Lockfile(LockKind::Npm))
+        );
+        assert_eq!(
+            classify_path("api/v1/user.pb.go"),
+            Some(SpecialFile::Generated)
+        );
+        assert_eq!(
+            classify_path("static/app.min.js"),
+            Some(SpecialFile::Generated)
+        );
+        assert_eq!(classify_path("src/main.rs"), None);
+    }
+
+    #[test]
+    fn test_cargo_lock_hunks() {
+        let hunks = [
+            " [[package]]",
+            " name = \"serde\"",
+            "-version = \"1.0.100\"",
+            "+version = \"1.0.200\"",
+            " source = \"registry+https://github.com/rust-lang/crates.io-index\"",
+            "-checksum = \"aaaa\"",
+            "+checksum = \"bbbb\"",
+            "+",
+            "+[[package]]",
+            "+name = \"tempfile\"",
+            "+version = \"3.8.0\"",
+            " [[package]]",
+            " name = \"regex\"",
+            " version = \"1.10.0\"",
+            "-[[package]]",
+            "-name = \"old-crate\"",
+            "-version = \"0.1.0\"",
+        ];
+        let out = summarize_hunks(SpecialFile::Lockfile(LockKind::Toml), &hunks);
+        assert_eq!(out[0], "📦 lockfile: 1 added, 1 removed, 1 bumped");
+        assert!(out.contains(&"  + tempfile 3.8.0".to_string()));
+        assert!(out.contains(&"  - old-crate 0.1.0".to_string()));
+        assert!(out.contains(&"  ~ serde 1.0.100 → 1.0.200".to_string()));
+        assert!(!out.iter().any(|l| l.contains("regex")));
+    }
+
+    #[test]
+    fn test_npm_lock_hunks() {
+        let hunks = [
+            "     \"node_modules/@types/node\": {",
+            "-      \"version\": \"20.1.0\",",
+            "-      \"resolved\": \"https://registry.npmjs.org/@types/node/-/node-20.1.0.tgz\",",
+            "+      \"version\": \"20.2.5\",",
+            "+      \"resolved\": \"https://registry.npmjs.org/@types/node/-/node-20.2.5.tgz\",",
+        ];
+        let out = summarize_hunks(SpecialFile::Lockfile(LockKind::Npm), &hunks);
+        assert_eq!(out[1], "  ~ @types/node 20.1.0 → 20.2.5");
+    }
+
+    #[test]
+    fn test_pnpm_and_go_sum() {
+        let pnpm = [
+            "-  /lodash@4.17.20:",
+            "+  /lodash@4.17.21:",
+            "+  '@scope/pkg@1.0.0(react@18.2.0)':",
+        ];
+        let out = summarize_hunks(SpecialFile::Lockfile(LockKind::Pnpm), &pnpm);
+        assert!(out.contains(&"  + @scope/pkg 1.0.0".to_string()));
+        assert!(out.contains(&"  ~ lodash 4.17.20 → 4.17.21".to_string()));
+
+        let go_sum = [
+            "-golang.org/x/net v0.10.0 h1:aaa=",
+            "-golang.org/x/net v0.10.0/go.mod h1:bbb=",
+            "+golang.org/x/net v0.17.0 h1:ccc=",
+            "+golang.org/x/net v0.17.0/go.mod h1:ddd=",
+        ];
+        let out = summarize_hunks(SpecialFile::Lockfile(LockKind::GoSum), &go_sum);
+        assert_eq!(out[1], "  ~ golang.org/x/net v0.10.0 → v0.17.0");
+    }
+
+    #[test]
+    fn test_metadata_churn_and_generated() {
+        let hunks = ["-checksum = \"aaaa\"", "+checksum = \"bbbb\""];
+        let out = summarize_hunks(SpecialFile::Lockfile(LockKind::Toml), &hunks);
+        assert_eq!(
+            out,
+            vec!["📦 lockfile: no package changes (2 lines of metadata churn)"]
+        );
+
+        let out = summarize_hunks(SpecialFile::Generated, &["+a", "-b", " c"]);
+        assert_eq!(out, vec!["⚙️  generated, 2 lines changed"]);
+    }
+
+    #[test]
+    fn test_summarize_contents_yarn() {
+        let old = "\"lodash@^4.17.0\":\n  version \"4.17.20\"\n  resolved \"x\"\n";
+        let new = "\"lodash@^4.17.0\":\n  version \"4.17.21\"\n  resolved \"y\"\n\nleft-pad@^1.0.0:\n  version \"1.3.0\"\n";
+        let out = summarize_contents(SpecialFile::Lockfile(LockKind::Yarn), old, new);
+        assert_eq!(out[0], "📦 lockfile: 1 added, 0 removed, 1 bumped");
+        assert!(out.contains(&"  ~ lodash 4.17.20 → 4.17.21".to_string()));
+    }
+}
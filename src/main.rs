@@ -1,6 +1,11 @@
+mod audit_cmd;
+mod bun_cmd;
+mod bundle_cmd;
 mod cargo_cmd;
 mod cc_economics;
 mod ccusage;
+mod ci_log;
+mod composer_cmd;
 mod config;
 mod container;
 mod curl_cmd;
@@ -8,6 +13,8 @@ mod deps;
 mod diff_cmd;
 mod discover;
 mod display_helpers;
+mod doctor;
+mod dotnet_cmd;
 mod env_cmd;
 mod filter;
 mod find_cmd;
@@ -17,33 +24,50 @@ mod gh_cmd;
 mod git;
 mod go_cmd;
 mod golangci_cmd;
+mod gradle_cmd;
 mod grep_cmd;
+mod hook_cmd;
 mod init;
 mod json_cmd;
+mod jvm_build;
 mod learn;
 mod lint_cmd;
 mod local_llm;
+mod lockfile_diff;
 mod log_cmd;
 mod ls;
+mod make_cmd;
+mod mcp;
+mod mvn_cmd;
 mod next_cmd;
 mod npm_cmd;
 mod parser;
+mod phpstan_cmd;
+mod phpunit_cmd;
 mod pip_cmd;
 mod playwright_cmd;
 mod pnpm_cmd;
+mod poetry_cmd;
 mod prettier_cmd;
 mod prisma_cmd;
 mod pytest_cmd;
+mod python_env;
+mod rake_cmd;
 mod read;
+mod rspec_cmd;
+mod rubocop_cmd;
 mod ruff_cmd;
 mod runner;
 mod summary;
+mod terraform_cmd;
 mod tracking;
 mod tree;
 mod tsc_cmd;
 mod utils;
+mod uv_cmd;
 mod vitest_cmd;
 mod wget_cmd;
+mod yarn_cmd;
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
@@ -125,7 +149,7 @@ enum Commands {
 
     /// GitHub CLI (gh) commands with token-optimized output
     Gh {
-        /// Subcommand: pr, issue, run, repo
+        /// Subcommand: pr, issue, run, repo, log-file <path>
         subcommand: String,
         /// Additional arguments
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
@@ -138,6 +162,18 @@ enum Commands {
         command: PnpmCommands,
     },
 
+    /// Yarn (classic and Berry) commands with ultra-compact output
+    Yarn {
+        #[command(subcommand)]
+        command: YarnCommands,
+    },
+
+    /// Bun package manager and test runner with ultra-compact output
+    Bun {
+        #[command(subcommand)]
+        command: BunCommands,
+    },
+
     /// Run command and show only errors/warnings
     Err {
         /// Command to run
@@ -168,6 +204,19 @@ enum Commands {
         path: PathBuf,
     },
 
+    /// Audit dependencies for known vulnerabilities (npm/pnpm audit, cargo audit, pip-audit)
+    Audit {
+        /// Project path
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Only audit one ecosystem: cargo, npm or pip
+        #[arg(long)]
+        only: Option<String>,
+        /// Extra arguments for the audit tool (after --; needs a single ecosystem)
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+
     /// Show environment variables (filtered, sensitive masked)
     Env {
         /// Filter by name (e.g. PATH, AWS)
@@ -279,6 +328,17 @@ enum Commands {
         /// Remove all RTK artifacts (hook, RTK.md, CLAUDE.md reference, settings.json entry)
         #[arg(long)]
         uninstall: bool,
+
+        /// Target another agent's instruction file: codex, cursor, gemini, copilot, aider, windsurf
+        #[arg(long, conflicts_with_all = ["claude_md", "hook_only", "auto_patch", "no_patch"])]
+        agent: Option<init::Agent>,
+    },
+
+    /// Diagnose installation: hook drift, settings.json, PATH collisions, tools, DB, config
+    Doctor {
+        /// Repair what `rtk init -g` installs (hook, RTK.md, CLAUDE.md, settings.json)
+        #[arg(long)]
+        fix: bool,
     },
 
     /// Download with compact output (strips progress bars)
@@ -448,6 +508,12 @@ enum Commands {
         /// Output format: text, json
         #[arg(short, long, default_value = "text")]
         format: String,
+        /// Restrict to one session: session id (prefix ok) or "latest"
+        #[arg(long)]
+        session: Option<String>,
+        /// Show per-call token timeline for the session (requires --session)
+        #[arg(long, requires = "session")]
+        timeline: bool,
     },
 
     /// Learn CLI corrections from Claude Code error history
@@ -475,6 +541,15 @@ enum Commands {
         min_occurrences: usize,
     },
 
+    /// Agent hook handlers (e.g. Claude Code PostToolUse output compression)
+    Hook {
+        #[command(subcommand)]
+        command: HookCommands,
+    },
+
+    /// Run an MCP (Model Context Protocol) server on stdio exposing rtk filters as tools
+    Mcp,
+
     /// Execute command without filtering but track usage
     Proxy {
         /// Command and arguments to execute
@@ -503,6 +578,18 @@ enum Commands {
         args: Vec<String>,
     },
 
+    /// uv project commands with compact output (lockfile changes summarized)
+    Uv {
+        #[command(subcommand)]
+        command: UvCommands,
+    },
+
+    /// Poetry commands with compact output (lockfile changes summarized)
+    Poetry {
+        #[command(subcommand)]
+        command: PoetryCommands,
+    },
+
     /// Go commands with compact output
     Go {
         #[command(subcommand)]
@@ -516,6 +603,108 @@ enum Commands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+
+    /// make with compact output (progress dropped, GCC/Clang diagnostics grouped by file)
+    Make {
+        /// make arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// cmake with compact output (`--build` uses the make filter)
+    Cmake {
+        /// cmake arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// ninja with compact output (progress dropped, diagnostics grouped by file)
+    Ninja {
+        /// ninja arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Maven with compact output (reactor summary, compile errors, Surefire results)
+    Mvn {
+        /// Maven arguments (goals, phases, flags)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Gradle with compact output (task outcomes, compile errors, test reports)
+    Gradle {
+        /// Gradle arguments (tasks, flags)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Terraform commands with compact output (plan summarized from JSON)
+    Terraform {
+        #[command(subcommand)]
+        command: TerraformCommands,
+    },
+
+    /// OpenTofu commands with compact output (same filters as terraform)
+    Tofu {
+        #[command(subcommand)]
+        command: TerraformCommands,
+    },
+
+    /// .NET commands with compact output (MSBuild diagnostics grouped by code)
+    Dotnet {
+        #[command(subcommand)]
+        command: DotnetCommands,
+    },
+
+    /// RSpec with compact output (JSON formatter, failures only)
+    Rspec {
+        /// RSpec arguments (files, --tag, etc.)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Rake with compact output (Minitest results summarized, failures only)
+    Rake {
+        /// Rake arguments (tasks, flags)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// RuboCop with compact output (offenses grouped by cop and file)
+    Rubocop {
+        /// RuboCop arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Bundler with compact output (install noise stripped, outdated as name: old → new)
+    Bundle {
+        /// Bundler arguments (install, update, outdated, ...)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// PHPUnit with compact output (JUnit/TeamCity results, failures only)
+    Phpunit {
+        /// PHPUnit arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// PHPStan with compact output (errors grouped by file and identifier)
+    Phpstan {
+        /// PHPStan arguments (analyse is implied)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Composer with compact output (package operations, outdated as name: old → new)
+    Composer {
+        /// Composer arguments (install, update, outdated, ...)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -594,6 +783,21 @@ enum GitCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Conflicted files and ours/theirs regions of a stopped merge/rebase/cherry-pick
+    Conflicts,
+    /// Blame grouped into commit ranges (--authors adds ownership percentages)
+    Blame {
+        /// File, -L a,b, revision and other git blame arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// What changed between two refs: commits, stat by directory, top hunks, notable files
+    Compare {
+        /// Base ref (or a `base..head` / `base...head` range)
+        base: String,
+        /// Head ref (default: HEAD)
+        head: Option<String>,
+    },
     /// Passthrough: runs any unsupported git subcommand directly
     #[command(external_subcommand)]
     Other(Vec<OsString>),
@@ -641,6 +845,104 @@ enum PnpmCommands {
     Other(Vec<OsString>),
 }
 
+#[derive(Subcommand)]
+enum YarnCommands {
+    /// List installed packages (ultra-dense)
+    List {
+        /// Depth level (default: 0)
+        #[arg(short, long, default_value = "0")]
+        depth: usize,
+        /// Additional yarn arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Show outdated packages (condensed: "pkg: old → new")
+    Outdated {
+        /// Additional yarn arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Install dependencies (filter progress steps)
+    Install {
+        /// Additional yarn arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Add packages (filter progress steps)
+    Add {
+        /// Packages and yarn arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Explain why a package is installed
+    Why {
+        /// Package and yarn arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Run a package.json script (strip boilerplate)
+    Run {
+        /// Script name and arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Passthrough: runs any unsupported yarn subcommand directly
+    #[command(external_subcommand)]
+    Other(Vec<OsString>),
+}
+
+#[derive(Subcommand)]
+enum BunCommands {
+    /// List installed packages via `bun pm ls` (ultra-dense)
+    List {
+        /// Depth level (default: 0, anything higher shows the full tree)
+        #[arg(short, long, default_value = "0")]
+        depth: usize,
+        /// Additional bun arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Show outdated packages (condensed: "pkg: old → new")
+    Outdated {
+        /// Additional bun arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Install dependencies (filter progress)
+    Install {
+        /// Additional bun arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Add packages (filter progress)
+    Add {
+        /// Packages and bun arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Explain why a package is installed
+    Why {
+        /// Package and bun arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Run a package.json script (strip boilerplate)
+    Run {
+        /// Script name and arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Run tests (failures only)
+    Test {
+        /// Additional bun test arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Passthrough: runs any unsupported bun subcommand directly
+    #[command(external_subcommand)]
+    Other(Vec<OsString>),
+}
+
 #[derive(Subcommand)]
 enum DockerCommands {
     /// List running containers
@@ -771,11 +1073,115 @@ enum CargoCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// nextest with failures-only output (`rtk cargo nextest run ...`)
+    Nextest {
+        /// nextest subcommand and arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Bench with criterion changes summarized as a regression table
+    Bench {
+        /// Additional cargo bench arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Doc with rustdoc warnings grouped by lint
+    Doc {
+        /// Additional cargo doc arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Tree capped at --depth 1 by default (-d: duplicate versions summarized)
+    Tree {
+        /// Additional cargo tree arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Udeps with unused dependencies listed per crate
+    Udeps {
+        /// Additional cargo udeps arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
     /// Passthrough: runs any unsupported cargo subcommand directly
     #[command(external_subcommand)]
     Other(Vec<OsString>),
 }
 
+#[derive(Subcommand)]
+enum UvCommands {
+    /// Sync the environment (strip progress, summarize changes)
+    Sync {
+        /// Additional uv arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Update the lockfile (summarize added, removed and upgraded packages)
+    Lock {
+        /// Additional uv arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Add dependencies (strip progress, summarize changes)
+    Add {
+        /// Packages and uv arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Run a command (pytest, ruff and mypy output filtered)
+    Run {
+        /// Command and uv arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Show the dependency tree
+    Tree {
+        /// Depth below direct dependencies (default: 1)
+        #[arg(short, long, default_value = "1")]
+        depth: usize,
+        /// Additional uv arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Passthrough: runs any unsupported uv subcommand directly
+    #[command(external_subcommand)]
+    Other(Vec<OsString>),
+}
+
+#[derive(Subcommand)]
+enum PoetryCommands {
+    /// Install dependencies (strip progress, summarize changes)
+    Install {
+        /// Additional poetry arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Update the lockfile (summarize added, removed and upgraded packages)
+    Lock {
+        /// Additional poetry arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Show packages (--tree pruned to --depth, --outdated condensed)
+    Show {
+        /// Tree depth below top-level packages (default: 1)
+        #[arg(short, long, default_value = "1")]
+        depth: usize,
+        /// Additional poetry arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Run a command (pytest, ruff and mypy output filtered)
+    Run {
+        /// Command and poetry arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Passthrough: runs any unsupported poetry subcommand directly
+    #[command(external_subcommand)]
+    Other(Vec<OsString>),
+}
+
 #[derive(Subcommand)]
 enum GoCommands {
     /// Run tests with compact output (90% token reduction via JSON streaming)
@@ -801,6 +1207,72 @@ enum GoCommands {
     Other(Vec<OsString>),
 }
 
+#[derive(Subcommand)]
+enum TerraformCommands {
+    /// Plan summarized by action, module and type (changed attributes only)
+    Plan {
+        /// Additional plan arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Apply with progress noise removed
+    Apply {
+        /// Additional apply arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Validate with diagnostics as file:line
+    Validate {
+        /// Additional validate arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Init with a provider/module summary
+    Init {
+        /// Additional init arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Passthrough: runs any unsupported subcommand directly
+    #[command(external_subcommand)]
+    Other(Vec<OsString>),
+}
+
+#[derive(Subcommand)]
+enum DotnetCommands {
+    /// Build with diagnostics deduplicated and grouped by code
+    Build {
+        /// Additional dotnet build arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Run tests showing only failures (TRX or console results)
+    Test {
+        /// Additional dotnet test arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Restore with a one-line summary
+    Restore {
+        /// Additional dotnet restore arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Passthrough: runs any unsupported dotnet subcommand directly
+    #[command(external_subcommand)]
+    Other(Vec<OsString>),
+}
+
+#[derive(Subcommand)]
+enum HookCommands {
+    /// Claude Code PostToolUse hook: compress large MCP outputs (reads event JSON on stdin)
+    ClaudePost {
+        /// Also attach a digest of large Bash outputs as extra context (costs tokens, Bash output can't be replaced)
+        #[arg(long)]
+        bash_context: bool,
+    },
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
@@ -842,7 +1314,14 @@ fn main() -> Result<()> {
                 git::run(git::GitCommand::Log, &args, None, cli.verbose)?;
             }
             GitCommands::Status { args } => {
-                git::run(git::GitCommand::Status, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Status {
+                        ultra_compact: cli.ultra_compact,
+                    },
+                    &args,
+                    None,
+                    cli.verbose,
+                )?;
             }
             GitCommands::Show { args } => {
                 git::run(git::GitCommand::Show, &args, None, cli.verbose)?;
@@ -876,6 +1355,20 @@ fn main() -> Result<()> {
             GitCommands::Worktree { args } => {
                 git::run(git::GitCommand::Worktree, &args, None, cli.verbose)?;
             }
+            GitCommands::Conflicts => {
+                git::run(git::GitCommand::Conflicts, &[], None, cli.verbose)?;
+            }
+            GitCommands::Blame { args } => {
+                git::run(git::GitCommand::Blame, &args, None, cli.verbose)?;
+            }
+            GitCommands::Compare { base, head } => {
+                git::run(
+                    git::GitCommand::Compare { base, head },
+                    &[],
+                    None,
+                    cli.verbose,
+                )?;
+            }
             GitCommands::Other(args) => {
                 git::run_passthrough(&args, cli.verbose)?;
             }
@@ -910,6 +1403,57 @@ fn main() -> Result<()> {
             }
         },
 
+        Commands::Yarn { command } => match command {
+            YarnCommands::List { depth, args } => {
+                yarn_cmd::run(yarn_cmd::YarnCommand::List { depth }, &args, cli.verbose)?;
+            }
+            YarnCommands::Outdated { args } => {
+                yarn_cmd::run(yarn_cmd::YarnCommand::Outdated, &args, cli.verbose)?;
+            }
+            YarnCommands::Install { args } => {
+                yarn_cmd::run(yarn_cmd::YarnCommand::Install, &args, cli.verbose)?;
+            }
+            YarnCommands::Add { args } => {
+                yarn_cmd::run(yarn_cmd::YarnCommand::Add, &args, cli.verbose)?;
+            }
+            YarnCommands::Why { args } => {
+                yarn_cmd::run(yarn_cmd::YarnCommand::Why, &args, cli.verbose)?;
+            }
+            YarnCommands::Run { args } => {
+                yarn_cmd::run(yarn_cmd::YarnCommand::Run, &args, cli.verbose)?;
+            }
+            YarnCommands::Other(args) => {
+                yarn_cmd::run_passthrough(&args, cli.verbose)?;
+            }
+        },
+
+        Commands::Bun { command } => match command {
+            BunCommands::List { depth, args } => {
+                bun_cmd::run(bun_cmd::BunCommand::List { depth }, &args, cli.verbose)?;
+            }
+            BunCommands::Outdated { args } => {
+                bun_cmd::run(bun_cmd::BunCommand::Outdated, &args, cli.verbose)?;
+            }
+            BunCommands::Install { args } => {
+                bun_cmd::run(bun_cmd::BunCommand::Install, &args, cli.verbose)?;
+            }
+            BunCommands::Add { args } => {
+                bun_cmd::run(bun_cmd::BunCommand::Add, &args, cli.verbose)?;
+            }
+            BunCommands::Why { args } => {
+                bun_cmd::run(bun_cmd::BunCommand::Why, &args, cli.verbose)?;
+            }
+            BunCommands::Run { args } => {
+                bun_cmd::run(bun_cmd::BunCommand::Run, &args, cli.verbose)?;
+            }
+            BunCommands::Test { args } => {
+                bun_cmd::run(bun_cmd::BunCommand::Test, &args, cli.verbose)?;
+            }
+            BunCommands::Other(args) => {
+                bun_cmd::run_passthrough(&args, cli.verbose)?;
+            }
+        },
+
         Commands::Err { command } => {
             let cmd = command.join(" ");
             runner::run_err(&cmd, cli.verbose)?;
@@ -932,6 +1476,10 @@ fn main() -> Result<()> {
             deps::run(&path, cli.verbose)?;
         }
 
+        Commands::Audit { path, only, args } => {
+            audit_cmd::run(&path, only.as_deref(), &args, cli.verbose)?;
+        }
+
         Commands::Env { filter, show_all } => {
             env_cmd::run(filter.as_deref(), show_all, cli.verbose)?;
         }
@@ -1044,9 +1592,16 @@ fn main() -> Result<()> {
             auto_patch,
             no_patch,
             uninstall,
+            agent,
         } => {
             if show {
                 init::show_config()?;
+            } else if let Some(agent) = agent {
+                if uninstall {
+                    init::uninstall_agent(agent, global, cli.verbose)?;
+                } else {
+                    init::run_agent(agent, global, cli.verbose)?;
+                }
             } else if uninstall {
                 init::uninstall(global, cli.verbose)?;
             } else {
@@ -1061,6 +1616,10 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Doctor { fix } => {
+            doctor::run(fix, cli.verbose)?;
+        }
+
         Commands::Wget { url, stdout, args } => {
             if stdout {
                 wget_cmd::run_stdout(&url, &args, cli.verbose)?;
@@ -1197,6 +1756,21 @@ fn main() -> Result<()> {
             CargoCommands::Install { args } => {
                 cargo_cmd::run(cargo_cmd::CargoCommand::Install, &args, cli.verbose)?;
             }
+            CargoCommands::Nextest { args } => {
+                cargo_cmd::run(cargo_cmd::CargoCommand::Nextest, &args, cli.verbose)?;
+            }
+            CargoCommands::Bench { args } => {
+                cargo_cmd::run(cargo_cmd::CargoCommand::Bench, &args, cli.verbose)?;
+            }
+            CargoCommands::Doc { args } => {
+                cargo_cmd::run(cargo_cmd::CargoCommand::Doc, &args, cli.verbose)?;
+            }
+            CargoCommands::Tree { args } => {
+                cargo_cmd::run(cargo_cmd::CargoCommand::Tree, &args, cli.verbose)?;
+            }
+            CargoCommands::Udeps { args } => {
+                cargo_cmd::run(cargo_cmd::CargoCommand::Udeps, &args, cli.verbose)?;
+            }
             CargoCommands::Other(args) => {
                 cargo_cmd::run_passthrough(&args, cli.verbose)?;
             }
@@ -1216,8 +1790,19 @@ fn main() -> Result<()> {
             all,
             since,
             format,
+            session,
+            timeline,
         } => {
-            discover::run(project.as_deref(), all, since, limit, &format, cli.verbose)?;
+            discover::run(
+                project.as_deref(),
+                all,
+                since,
+                limit,
+                &format,
+                session.as_deref(),
+                timeline,
+                cli.verbose,
+            )?;
         }
 
         Commands::Learn {
@@ -1330,6 +1915,49 @@ fn main() -> Result<()> {
             pip_cmd::run(&args, cli.verbose)?;
         }
 
+        Commands::Uv { command } => match command {
+            UvCommands::Sync { args } => {
+                uv_cmd::run(uv_cmd::UvCommand::Sync, &args, cli.verbose)?;
+            }
+            UvCommands::Lock { args } => {
+                uv_cmd::run(uv_cmd::UvCommand::Lock, &args, cli.verbose)?;
+            }
+            UvCommands::Add { args } => {
+                uv_cmd::run(uv_cmd::UvCommand::Add, &args, cli.verbose)?;
+            }
+            UvCommands::Run { args } => {
+                uv_cmd::run(uv_cmd::UvCommand::Run, &args, cli.verbose)?;
+            }
+            UvCommands::Tree { depth, args } => {
+                uv_cmd::run(uv_cmd::UvCommand::Tree { depth }, &args, cli.verbose)?;
+            }
+            UvCommands::Other(args) => {
+                uv_cmd::run_passthrough(&args, cli.verbose)?;
+            }
+        },
+
+        Commands::Poetry { command } => match command {
+            PoetryCommands::Install { args } => {
+                poetry_cmd::run(poetry_cmd::PoetryCommand::Install, &args, cli.verbose)?;
+            }
+            PoetryCommands::Lock { args } => {
+                poetry_cmd::run(poetry_cmd::PoetryCommand::Lock, &args, cli.verbose)?;
+            }
+            PoetryCommands::Show { depth, args } => {
+                poetry_cmd::run(
+                    poetry_cmd::PoetryCommand::Show { depth },
+                    &args,
+                    cli.verbose,
+                )?;
+            }
+            PoetryCommands::Run { args } => {
+                poetry_cmd::run(poetry_cmd::PoetryCommand::Run, &args, cli.verbose)?;
+            }
+            PoetryCommands::Other(args) => {
+                poetry_cmd::run_passthrough(&args, cli.verbose)?;
+            }
+        },
+
         Commands::Go { command } => match command {
             GoCommands::Test { args } => {
                 go_cmd::run_test(&args, cli.verbose)?;
@@ -1349,6 +1977,87 @@ fn main() -> Result<()> {
             golangci_cmd::run(&args, cli.verbose)?;
         }
 
+        Commands::Make { args } => {
+            make_cmd::run_make(&args, cli.verbose)?;
+        }
+
+        Commands::Cmake { args } => {
+            make_cmd::run_cmake(&args, cli.verbose)?;
+        }
+
+        Commands::Ninja { args } => {
+            make_cmd::run_ninja(&args, cli.verbose)?;
+        }
+
+        Commands::Mvn { args } => {
+            mvn_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Gradle { args } => {
+            gradle_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Terraform { command } => {
+            run_terraform("terraform", command, cli.verbose)?;
+        }
+
+        Commands::Tofu { command } => {
+            run_terraform("tofu", command, cli.verbose)?;
+        }
+
+        Commands::Dotnet { command } => match command {
+            DotnetCommands::Build { args } => {
+                dotnet_cmd::run_build(&args, cli.verbose)?;
+            }
+            DotnetCommands::Test { args } => {
+                dotnet_cmd::run_test(&args, cli.verbose)?;
+            }
+            DotnetCommands::Restore { args } => {
+                dotnet_cmd::run_restore(&args, cli.verbose)?;
+            }
+            DotnetCommands::Other(args) => {
+                dotnet_cmd::run_other(&args, cli.verbose)?;
+            }
+        },
+
+        Commands::Rspec { args } => {
+            rspec_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Rake { args } => {
+            rake_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Rubocop { args } => {
+            rubocop_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Bundle { args } => {
+            bundle_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Phpunit { args } => {
+            phpunit_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Phpstan { args } => {
+            phpstan_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Composer { args } => {
+            composer_cmd::run(&args, cli.verbose)?;
+        }
+
+        Commands::Hook { command } => match command {
+            HookCommands::ClaudePost { bash_context } => {
+                hook_cmd::run_claude_post(bash_context, cli.verbose)?;
+            }
+        },
+
+        Commands::Mcp => {
+            mcp::run(cli.verbose)?;
+        }
+
         Commands::Proxy { args } => {
             use std::process::Command;
 
@@ -1400,3 +2109,14 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Terraform and OpenTofu share subcommands and filters; only the binary differs.
+fn run_terraform(bin: &str, command: TerraformCommands, verbose: u8) -> Result<()> {
+    match command {
+        TerraformCommands::Plan { args } => terraform_cmd::run_plan(bin, &args, verbose),
+        TerraformCommands::Apply { args } => terraform_cmd::run_apply(bin, &args, verbose),
+        TerraformCommands::Validate { args } => terraform_cmd::run_validate(bin, &args, verbose),
+        TerraformCommands::Init { args } => terraform_cmd::run_init(bin, &args, verbose),
+        TerraformCommands::Other(args) => terraform_cmd::run_other(bin, &args, verbose),
+    }
+}
@@ -0,0 +1,529 @@
+use crate::tracking;
+use crate::utils::truncate;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
+use std::process::Command;
+
+/// Warnings shown per file before collapsing.
+const MAX_WARNINGS_PER_FILE: usize = 10;
+/// Unrecognized lines kept when the build fails (recipe output, ...).
+const MAX_OTHER_LINES: usize = 40;
+
+lazy_static::lazy_static! {
+    // src/foo.c:12:5: error: 'x' undeclared (first use in this function)
+    static ref DIAGNOSTIC: Regex = Regex::new(
+        r"^(.+?):(\d+):(?:(\d+):)?\s+(fatal error|error|warning|note):\s+(.+)$"
+    ).unwrap();
+    // [ 42%] Building CXX object ... / [123/456] Building CXX object ...
+    static ref PROGRESS: Regex = Regex::new(r"^\[\s*\d+(%|/\d+)\]").unwrap();
+    // "   12 |   int x = y;" and "      |       ^"
+    static ref EXCERPT: Regex = Regex::new(r"^\s*\d*\s*\|").unwrap();
+    static ref CARET: Regex = Regex::new(r"^\s*[\^~][\^~\s]*$").unwrap();
+    static ref CONTEXT: Regex = Regex::new(
+        r"^(In file included from |\s+from |.+: In (function|member function|constructor|destructor|instantiation of|static member function) |.+: At (top level|global scope):)"
+    ).unwrap();
+    static ref MAKE_DIRECTORY: Regex =
+        Regex::new(r"^(g?make|ninja)(\[\d+\])?: (Entering|Leaving) directory").unwrap();
+    static ref COMPILER_CALL: Regex = Regex::new(
+        r"^(\S*/)?(cc|gcc|g\+\+|c\+\+|clang|clang\+\+|ar|ranlib|ld|(\S+-)?(gcc|g\+\+))(-\d+)?\s"
+    ).unwrap();
+    static ref CLANG_COUNT: Regex =
+        Regex::new(r"^\d+ (warnings?|errors?)( and \d+ (warnings?|errors?))? generated\.$").unwrap();
+}
+
+pub fn run_make(args: &[String], verbose: u8) -> Result<()> {
+    if is_make_inspection(args) {
+        return run_passthrough("make", args, verbose);
+    }
+    run_build("make", args, verbose, filter_build_output)
+}
+
+pub fn run_ninja(args: &[String], verbose: u8) -> Result<()> {
+    // -n dry run, -t tools (targets, graph, ...)
+    if args.iter().any(|a| a == "-n" || a.starts_with("-t")) {
+        return run_passthrough("ninja", args, verbose);
+    }
+    run_build("ninja", args, verbose, filter_build_output)
+}
+
+/// `make -n` / `-p` print commands or the database instead of building;
+/// that output is the point and must not be filtered away.
+fn is_make_inspection(args: &[String]) -> bool {
+    args.iter().any(|a| {
+        matches!(
+            a.as_str(),
+            "--dry-run" | "--just-print" | "--recon" | "--print-data-base"
+        ) || (a.starts_with('-')
+            && !a.starts_with("--")
+            && a.len() > 1
+            && a[1..].chars().all(|c| c.is_ascii_alphabetic())
+            && a[1..].contains(['n', 'p']))
+    })
+}
+
+fn run_passthrough(tool: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: {} {}", tool, args.join(" "));
+    }
+
+    let status = Command::new(tool)
+        .args(args)
+        .status()
+        .with_context(|| format!("Failed to run {}. Is it installed?", tool))?;
+
+    timer.track_passthrough(
+        &format!("{} {}", tool, args.join(" ")),
+        &format!("rtk {} {} (passthrough)", tool, args.join(" ")),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// `cmake --build` gets the build filter, configure runs the configure filter.
+pub fn run_cmake(args: &[String], verbose: u8) -> Result<()> {
+    if args.iter().any(|a| a == "--build") {
+        run_build("cmake", args, verbose, filter_build_output)
+    } else {
+        run_build("cmake", args, verbose, filter_cmake_configure)
+    }
+}
+
+fn run_build(tool: &str, args: &[String], verbose: u8, filter: fn(&str) -> String) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new(tool);
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: {} {}", tool, args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {}. Is it installed?", tool))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("{} {}", tool, args.join(" ")),
+        &format!("rtk {} {}", tool, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+fn is_linker_line(line: &str) -> bool {
+    line.contains("undefined reference to")
+        || line.contains("multiple definition of")
+        || line.contains("ld returned")
+        || line.contains("linker command failed")
+        || line.starts_with("/usr/bin/ld:")
+        || line.starts_with("ld: ")
+        || line.starts_with("ld.lld: ")
+        || line.starts_with("collect2:")
+        || line.starts_with("Undefined symbols for architecture")
+        || line.contains(", referenced from:")
+        || (line.starts_with(' ') && line.trim_start().starts_with("\"_"))
+}
+
+struct CompilerDiagnostic {
+    line: usize,
+    column: Option<usize>,
+    error: bool,
+    message: String,
+    count: usize,
+}
+
+/// Filter make/ninja/cmake --build output: drop progress, group GCC/Clang
+/// diagnostics by file, dedupe repeated header warnings, keep linker errors.
+pub(crate) fn filter_build_output(output: &str) -> String {
+    let lines: Vec<&str> = output.lines().collect();
+
+    // File order follows first appearance (i.e. compile order)
+    let mut files: Vec<String> = Vec::new();
+    let mut by_file: HashMap<String, Vec<CompilerDiagnostic>> = HashMap::new();
+    let mut linker: Vec<&str> = Vec::new();
+    let mut failures: Vec<&str> = Vec::new();
+    let mut other: Vec<&str> = Vec::new();
+    let mut targets = 0;
+    // Progress, compiler calls, ...: without any, this wasn't a build
+    let mut built = false;
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim_end();
+        i += 1;
+
+        if let Some(caps) = DIAGNOSTIC.captures(line) {
+            built = true;
+            let severity = &caps[4];
+            if severity == "note" {
+                continue;
+            }
+            let file = caps[1].to_string();
+            let diag = CompilerDiagnostic {
+                line: caps[2].parse().unwrap_or(0),
+                column: caps.get(3).and_then(|c| c.as_str().parse().ok()),
+                error: severity != "warning",
+                message: caps[5].to_string(),
+                count: 1,
+            };
+            if !by_file.contains_key(&file) {
+                files.push(file.clone());
+            }
+            let entries = by_file.entry(file).or_default();
+            // Same header warning reported once per including translation unit
+            match entries.iter_mut().find(|d| {
+                d.line == diag.line
+                    && d.column == diag.column
+                    && d.error == diag.error
+                    && d.message == diag.message
+            }) {
+                Some(existing) => existing.count += 1,
+                None => entries.push(diag),
+            }
+            continue;
+        }
+
+        if is_linker_line(line) {
+            if !linker.contains(&line) {
+                linker.push(line);
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if trimmed.starts_with("FAILED: ") {
+            // Ninja echoes the failed command on the next line
+            if lines.get(i).is_some_and(|next| !DIAGNOSTIC.is_match(next)) {
+                i += 1;
+            }
+            continue;
+        }
+        if trimmed.contains("*** ") || trimmed.starts_with("ninja: build stopped") {
+            if !failures.contains(&trimmed) {
+                failures.push(trimmed);
+            }
+            continue;
+        }
+
+        if trimmed.contains("] Built target ") {
+            targets += 1;
+            built = true;
+            continue;
+        }
+        if PROGRESS.is_match(trimmed) || COMPILER_CALL.is_match(trimmed) {
+            built = true;
+            continue;
+        }
+
+        // Clang prints the source line followed by a caret line
+        let next_is_caret = lines.get(i).is_some_and(|next| CARET.is_match(next));
+        if trimmed.is_empty()
+            || next_is_caret
+            || EXCERPT.is_match(line)
+            || CARET.is_match(line)
+            || CONTEXT.is_match(line)
+            || MAKE_DIRECTORY.is_match(trimmed)
+            || CLANG_COUNT.is_match(trimmed)
+            || trimmed.starts_with("Scanning dependencies of target")
+            || trimmed.starts_with("Consolidate compiler generated dependencies")
+            || trimmed.starts_with("ninja: Entering directory")
+        {
+            if next_is_caret {
+                i += 1;
+            }
+            continue;
+        }
+
+        if trimmed == "ninja: no work to do." || trimmed.ends_with("is up to date.") {
+            built = true;
+            continue;
+        }
+
+        other.push(trimmed);
+    }
+
+    let all: Vec<&CompilerDiagnostic> = by_file.values().flatten().collect();
+    let errors: usize = all.iter().filter(|d| d.error).map(|d| d.count).sum();
+    let warnings: usize = all.iter().filter(|d| !d.error).map(|d| d.count).sum();
+    let failed = errors > 0 || !failures.is_empty() || linker.iter().any(|l| l.contains("error"));
+
+    // `make help`, `make test`, ...: recipe output is all there is, keep it whole
+    if !failed && !built && !other.is_empty() {
+        return other.join("\n");
+    }
+
+    let mut out = Vec::new();
+    if failed {
+        out.push(format!(
+            "❌ Build failed: {} errors, {} warnings in {} files",
+            errors,
+            warnings,
+            files.len()
+        ));
+    } else {
+        let mut header = "✓ Build succeeded".to_string();
+        if targets > 0 {
+            header.push_str(&format!(" ({} targets)", targets));
+        }
+        if warnings > 0 {
+            header.push_str(&format!(": {} warnings in {} files", warnings, files.len()));
+        }
+        out.push(header);
+    }
+
+    for file in &files {
+        let diags = &by_file[file];
+        let file_errors: usize = diags.iter().filter(|d| d.error).map(|d| d.count).sum();
+        let file_warnings: usize = diags.iter().filter(|d| !d.error).map(|d| d.count).sum();
+        let mut counts = Vec::new();
+        if file_errors > 0 {
+            counts.push(format!("{} errors", file_errors));
+        }
+        if file_warnings > 0 {
+            counts.push(format!("{} warnings", file_warnings));
+        }
+        out.push(format!("{} ({})", file, counts.join(", ")));
+
+        // Errors first, every one of them; warnings capped
+        let mut shown_warnings = 0;
+        let mut hidden_warnings = 0;
+        for diag in diags
+            .iter()
+            .filter(|d| d.error)
+            .chain(diags.iter().filter(|d| !d.error))
+        {
+            if !diag.error {
+                if shown_warnings == MAX_WARNINGS_PER_FILE {
+                    hidden_warnings += 1;
+                    continue;
+                }
+                shown_warnings += 1;
+            }
+            let location = match diag.column {
+                Some(column) => format!("L{}:{}", diag.line, column),
+                None => format!("L{}", diag.line),
+            };
+            let repeat = if diag.count > 1 {
+                format!(" (x{})", diag.count)
+            } else {
+                String::new()
+            };
+            out.push(format!(
+                "  {} {}: {}{}",
+                location,
+                if diag.error { "error" } else { "warning" },
+                truncate(&diag.message, 150),
+                repeat
+            ));
+        }
+        if hidden_warnings > 0 {
+            out.push(format!("  ... +{} more warnings", hidden_warnings));
+        }
+    }
+
+    if !linker.is_empty() {
+        out.push("Linker:".to_string());
+        out.extend(linker.iter().map(|l| format!("  {}", l)));
+    }
+
+    // Recipe output after a successful build (test runs, ...) stays whole
+    let other_total = other.len();
+    let keep = if failed { MAX_OTHER_LINES } else { other_total };
+    out.extend(other.into_iter().take(keep).map(String::from));
+    if other_total > keep {
+        out.push(format!("... +{} more lines", other_total - keep));
+    }
+
+    out.extend(failures.iter().map(|f| f.to_string()));
+    out.join("\n")
+}
+
+/// Filter a cmake configure run: keep CMake errors/warnings and the outcome.
+fn filter_cmake_configure(output: &str) -> String {
+    let mut out = Vec::new();
+    let mut in_message = false;
+    let mut build_dir = None;
+    let mut incomplete = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim_end();
+        if trimmed.starts_with("CMake Error") || trimmed.starts_with("CMake Warning") {
+            in_message = true;
+            out.push(trimmed.to_string());
+            continue;
+        }
+        if in_message {
+            // Message bodies are indented; the block ends at the next "-- " line
+            if trimmed.starts_with("-- ") {
+                in_message = false;
+            } else {
+                if !trimmed.trim().is_empty() {
+                    out.push(format!("  {}", trimmed.trim()));
+                }
+                continue;
+            }
+        }
+        if let Some(dir) = trimmed.strip_prefix("-- Build files have been written to: ") {
+            build_dir = Some(dir.to_string());
+        } else if trimmed.starts_with("-- Configuring incomplete") {
+            incomplete = true;
+        }
+    }
+
+    let header = match (incomplete, build_dir) {
+        (true, _) => "❌ cmake: configuring incomplete, errors occurred".to_string(),
+        (false, Some(dir)) => format!("✓ cmake: build files written to {}", dir),
+        (false, None) => "cmake: done".to_string(),
+    };
+    out.insert(0, header);
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_build_output_groups_and_dedupes() {
+        let output = "\
+[ 25%] Building CXX object CMakeFiles/app.dir/src/main.cpp.o
+In file included from /src/app/src/main.cpp:3:
+/src/app/include/util.h:10:7: warning: unused variable 'tmp' [-Wunused-variable]
+   10 |   int tmp = 0;
+      |       ^~~
+[ 50%] Building CXX object CMakeFiles/app.dir/src/server.cpp.o
+In file included from /src/app/src/server.cpp:2:
+/src/app/include/util.h:10:7: warning: unused variable 'tmp' [-Wunused-variable]
+   10 |   int tmp = 0;
+      |       ^~~
+/src/app/src/server.cpp: In function 'int handle()':
+/src/app/src/server.cpp:42:12: error: 'reqest' was not declared in this scope; did you mean 'request'?
+   42 |     return reqest.id;
+      |            ^~~~~~
+      |            request
+/src/app/src/server.cpp:40:5: note: 'request' declared here
+gmake[2]: *** [CMakeFiles/app.dir/build.make:90: CMakeFiles/app.dir/src/server.cpp.o] Error 1
+gmake[1]: *** [CMakeFiles/Makefile2:83: CMakeFiles/app.dir/all] Error 2
+gmake: *** [Makefile:91: all] Error 2
+";
+        let result = filter_build_output(output);
+        assert_eq!(
+            result,
+            "\
+❌ Build failed: 1 errors, 2 warnings in 2 files
+/src/app/include/util.h (2 warnings)
+  L10:7 warning: unused variable 'tmp' [-Wunused-variable] (x2)
+/src/app/src/server.cpp (1 errors)
+  L42:12 error: 'reqest' was not declared in this scope; did you mean 'request'?
+gmake[2]: *** [CMakeFiles/app.dir/build.make:90: CMakeFiles/app.dir/src/server.cpp.o] Error 1
+gmake[1]: *** [CMakeFiles/Makefile2:83: CMakeFiles/app.dir/all] Error 2
+gmake: *** [Makefile:91: all] Error 2"
+        );
+    }
+
+    #[test]
+    fn test_filter_build_output_linker_and_clang() {
+        let output = "\
+[1/3] Building C object CMakeFiles/tool.dir/main.c.o
+main.c:5:9: warning: implicit conversion loses integer precision [-Wshorten-64-to-32]
+    int n = strlen(s);
+        ~   ^~~~~~~~~
+1 warning generated.
+[2/3] Linking C executable tool
+FAILED: tool
+: && /usr/bin/cc main.c.o -o tool && :
+/usr/bin/ld: main.c.o: in function `main':
+main.c:(.text+0x1f): undefined reference to `parse_args'
+collect2: error: ld returned 1 exit status
+ninja: build stopped: subcommand failed.
+";
+        let result = filter_build_output(output);
+        assert!(result.starts_with("❌ Build failed: 0 errors, 1 warnings in 1 files"));
+        assert!(result.contains("  L5:9 warning: implicit conversion loses integer precision"));
+        assert!(!result.contains("strlen"));
+        assert!(result.contains(
+            "Linker:\n  /usr/bin/ld: main.c.o: in function `main':\n  main.c:(.text+0x1f): undefined reference to `parse_args'\n  collect2: error: ld returned 1 exit status"
+        ));
+        assert!(result.ends_with("ninja: build stopped: subcommand failed."));
+        assert!(!result.contains("[1/3]"));
+    }
+
+    #[test]
+    fn test_filter_build_output_success() {
+        let output = "\
+make[1]: Entering directory '/src/app'
+cc -O2 -c -o main.o main.c
+cc -O2 -o app main.o
+make[1]: Leaving directory '/src/app'
+";
+        assert_eq!(filter_build_output(output), "✓ Build succeeded");
+
+        let cmake = "\
+[ 50%] Building CXX object CMakeFiles/app.dir/main.cpp.o
+[100%] Linking CXX executable app
+[100%] Built target app
+";
+        assert_eq!(filter_build_output(cmake), "✓ Build succeeded (1 targets)");
+    }
+
+    #[test]
+    fn test_filter_build_output_keeps_non_build_output() {
+        let help: String = (0..60)
+            .map(|i| format!("target{}  - does thing {}\n", i, i))
+            .collect();
+        let result = filter_build_output(&help);
+        assert!(!result.contains("Build succeeded"));
+        assert_eq!(result.lines().count(), 60);
+
+        let test_run = format!("cc -O2 -o app main.o\n{}", help);
+        let result = filter_build_output(&test_run);
+        assert!(result.starts_with("✓ Build succeeded"));
+        assert!(result.contains("target59"));
+        assert!(!result.contains("more lines"));
+    }
+
+    #[test]
+    fn test_is_make_inspection() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert!(is_make_inspection(&args(&["-n", "install"])));
+        assert!(is_make_inspection(&args(&["--dry-run"])));
+        assert!(is_make_inspection(&args(&["-kn"])));
+        assert!(is_make_inspection(&args(&["-p", "-f", "/dev/null"])));
+        assert!(!is_make_inspection(&args(&["-j8", "all"])));
+        assert!(!is_make_inspection(&args(&["-C", "src", "print"])));
+    }
+
+    #[test]
+    fn test_filter_cmake_configure() {
+        let output = "\
+-- The CXX compiler identification is GNU 13.2.0
+-- Detecting CXX compiler ABI info
+-- Detecting CXX compiler ABI info - done
+CMake Error at CMakeLists.txt:12 (find_package):
+  Could not find a package configuration file provided by \"fmt\".
+
+-- Configuring incomplete, errors occurred!
+";
+        assert_eq!(
+            filter_cmake_configure(output),
+            "❌ cmake: configuring incomplete, errors occurred\nCMake Error at CMakeLists.txt:12 (find_package):\n  Could not find a package configuration file provided by \"fmt\"."
+        );
+    }
+}
@@ -0,0 +1,423 @@
+//! MCP (Model Context Protocol) server exposing rtk filters as tools.
+//!
+//! Speaks newline-delimited JSON-RPC 2.0 over stdio. Each tool call re-invokes
+//! the current rtk binary with the matching subcommand, so filtering and
+//! tracking behave exactly like the CLI.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+use std::process::{Command, Stdio};
+
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+// JSON-RPC 2.0 error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Run the MCP server until stdin is closed.
+pub fn run(verbose: u8) -> Result<()> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    serve(stdin.lock(), stdout.lock(), verbose)
+}
+
+/// Read requests line by line and write one response per request (none for notifications).
+fn serve<R: BufRead, W: Write>(reader: R, mut writer: W, verbose: u8) -> Result<()> {
+    for line in reader.lines() {
+        let line = line.context("Failed to read from stdin")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if verbose > 0 {
+            eprintln!("mcp <- {}", line);
+        }
+
+        if let Some(response) = handle_message(&line) {
+            let serialized = serde_json::to_string(&response)?;
+            if verbose > 0 {
+                eprintln!("mcp -> {}", serialized);
+            }
+            writeln!(writer, "{}", serialized)?;
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle one JSON-RPC message, returning the response if one is due.
+fn handle_message(line: &str) -> Option<Value> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(v) => v,
+        Err(e) => return Some(error_response(Value::Null, PARSE_ERROR, &e.to_string())),
+    };
+
+    let id = request.get("id").cloned();
+    let method = match request.get("method").and_then(|m| m.as_str()) {
+        Some(m) => m,
+        None => {
+            return Some(error_response(
+                id.unwrap_or(Value::Null),
+                INVALID_REQUEST,
+                "missing method",
+            ))
+        }
+    };
+
+    // Notifications (no id) never get a response
+    let id = id?;
+
+    let params = request.get("params").cloned().unwrap_or_else(|| json!({}));
+    let result = match method {
+        "initialize" => Ok(json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": { "tools": {} },
+            "serverInfo": { "name": "rtk", "version": env!("CARGO_PKG_VERSION") }
+        })),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+        "tools/call" => call_tool(&params),
+        _ => Err((METHOD_NOT_FOUND, format!("method not found: {}", method))),
+    };
+
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => error_response(id, code, &message),
+    })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message }
+    })
+}
+
+/// Tool catalogue advertised by `tools/list`.
+fn tool_definitions() -> Value {
+    let args_schema = json!({
+        "type": "array",
+        "items": { "type": "string" },
+        "description": "Extra arguments passed to the underlying command"
+    });
+
+    json!([
+        {
+            "name": "run",
+            "description": "Run a shell command and return only errors/warnings (mode=errors) or test failures (mode=test)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "command": { "type": "string", "description": "Shell command to run" },
+                    "mode": { "type": "string", "enum": ["errors", "test"], "default": "errors" }
+                },
+                "required": ["command"]
+            }
+        },
+        {
+            "name": "read",
+            "description": "Read a file with language-aware filtering (comments/boilerplate stripped)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string" },
+                    "level": { "type": "string", "enum": ["none", "minimal", "aggressive"], "default": "minimal" },
+                    "max_lines": { "type": "integer", "minimum": 1 },
+                    "line_numbers": { "type": "boolean", "default": false }
+                },
+                "required": ["path"]
+            }
+        },
+        {
+            "name": "grep",
+            "description": "Search with ripgrep, results grouped by file and truncated",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "pattern": { "type": "string" },
+                    "path": { "type": "string", "default": "." },
+                    "max": { "type": "integer", "minimum": 1, "default": 50 },
+                    "file_type": { "type": "string", "description": "ripgrep type filter, e.g. rust, ts, py" },
+                    "context_only": { "type": "boolean", "default": false }
+                },
+                "required": ["pattern"]
+            }
+        },
+        {
+            "name": "git_status",
+            "description": "Compact git status",
+            "inputSchema": { "type": "object", "properties": { "args": args_schema } }
+        },
+        {
+            "name": "git_diff",
+            "description": "Condensed git diff (stat + compacted hunks)",
+            "inputSchema": { "type": "object", "properties": { "args": args_schema } }
+        },
+        {
+            "name": "git_log",
+            "description": "One-line git log",
+            "inputSchema": { "type": "object", "properties": { "args": args_schema } }
+        },
+        {
+            "name": "test",
+            "description": "Run a test runner and return failures only",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "runner": { "type": "string", "enum": ["cargo", "pytest", "vitest", "go", "playwright"] },
+                    "args": args_schema
+                },
+                "required": ["runner"]
+            }
+        },
+        {
+            "name": "json",
+            "description": "Show the structure (keys and types) of a JSON file without values",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string" },
+                    "depth": { "type": "integer", "minimum": 1, "default": 5 }
+                },
+                "required": ["path"]
+            }
+        },
+        {
+            "name": "deps",
+            "description": "Summarize project dependencies (Cargo.toml, package.json, pyproject.toml, go.mod...)",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "path": { "type": "string", "default": "." } }
+            }
+        }
+    ])
+}
+
+/// Handle `tools/call`: map the tool to rtk argv, run it, wrap the filtered output.
+fn call_tool(params: &Value) -> Result<Value, (i64, String)> {
+    let name = params
+        .get("name")
+        .and_then(|n| n.as_str())
+        .ok_or((INVALID_PARAMS, "missing tool name".to_string()))?;
+    let empty = json!({});
+    let arguments = params.get("arguments").unwrap_or(&empty);
+
+    let argv = tool_argv(name, arguments).map_err(|e| (INVALID_PARAMS, e))?;
+    let (text, success) = run_rtk(&argv).map_err(|e| (INVALID_PARAMS, format!("{:#}", e)))?;
+
+    Ok(json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": !success
+    }))
+}
+
+/// Translate a tool call into the equivalent rtk command-line arguments.
+fn tool_argv(name: &str, args: &Value) -> Result<Vec<String>, String> {
+    let str_arg = |key: &str| args.get(key).and_then(|v| v.as_str()).map(String::from);
+    let required = |key: &str| str_arg(key).ok_or_else(|| format!("missing argument: {}", key));
+    let uint_arg = |key: &str| args.get(key).and_then(|v| v.as_u64());
+    let bool_arg = |key: &str| args.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+    let extra = || -> Vec<String> {
+        args.get("args")
+            .and_then(|v| v.as_array())
+            .map(|a| {
+                a.iter()
+                    .filter_map(|s| s.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let mut argv: Vec<String> = Vec::new();
+    match name {
+        "run" => {
+            let mode = str_arg("mode").unwrap_or_else(|| "errors".to_string());
+            match mode.as_str() {
+                "errors" => argv.push("err".into()),
+                "test" => argv.push("test".into()),
+                other => return Err(format!("invalid mode: {}", other)),
+            }
+            // runner joins its args and hands them to `sh -c`, so pass the command whole
+            argv.push(required("command")?);
+        }
+        "read" => {
+            argv.extend(["read".into(), required("path")?]);
+            if let Some(level) = str_arg("level") {
+                argv.extend(["--level".into(), level]);
+            }
+            if let Some(max) = uint_arg("max_lines") {
+                argv.extend(["--max-lines".into(), max.to_string()]);
+            }
+            if bool_arg("line_numbers") {
+                argv.push("--line-numbers".into());
+            }
+        }
+        "grep" => {
+            argv.push("grep".into());
+            if let Some(max) = uint_arg("max") {
+                argv.extend(["--max".into(), max.to_string()]);
+            }
+            if let Some(file_type) = str_arg("file_type") {
+                argv.extend(["--file-type".into(), file_type]);
+            }
+            if bool_arg("context_only") {
+                argv.push("--context-only".into());
+            }
+            // After `--` so patterns like `-foo` aren't parsed as flags
+            argv.extend(["--".into(), required("pattern")?]);
+            argv.push(str_arg("path").unwrap_or_else(|| ".".to_string()));
+        }
+        "git_status" | "git_diff" | "git_log" => {
+            argv.extend(["git".into(), name.trim_start_matches("git_").to_string()]);
+            argv.extend(extra());
+        }
+        "test" => {
+            let runner = required("runner")?;
+            match runner.as_str() {
+                "cargo" => argv.extend(["cargo".into(), "test".into()]),
+                "pytest" => argv.push("pytest".into()),
+                "vitest" => argv.extend(["vitest".into(), "run".into()]),
+                "go" => argv.extend(["go".into(), "test".into()]),
+                "playwright" => argv.extend(["playwright".into(), "test".into()]),
+                other => return Err(format!("unsupported runner: {}", other)),
+            }
+            argv.extend(extra());
+        }
+        "json" => {
+            argv.extend(["json".into(), required("path")?]);
+            if let Some(depth) = uint_arg("depth") {
+                argv.extend(["--depth".into(), depth.to_string()]);
+            }
+        }
+        "deps" => {
+            argv.extend(["deps".into(), str_arg("path").unwrap_or_else(|| ".".into())]);
+        }
+        _ => return Err(format!("unknown tool: {}", name)),
+    }
+
+    Ok(argv)
+}
+
+/// Re-invoke rtk with the given args, returning combined output and success.
+fn run_rtk(argv: &[String]) -> Result<(String, bool)> {
+    let exe = std::env::current_exe().context("Failed to locate rtk executable")?;
+    let output = Command::new(exe)
+        .args(argv)
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run rtk {}", argv.join(" ")))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let text = if stderr.trim().is_empty() {
+        stdout.trim_end().to_string()
+    } else if stdout.trim().is_empty() {
+        stderr.trim_end().to_string()
+    } else {
+        format!("{}\n{}", stdout.trim_end(), stderr.trim_end())
+    };
+
+    Ok((text, output.status.success()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize_response() {
+        let resp = handle_message(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#)
+            .unwrap();
+        assert_eq!(resp["id"], 1);
+        assert_eq!(resp["result"]["serverInfo"]["name"], "rtk");
+        assert!(resp["result"]["capabilities"]["tools"].is_object());
+    }
+
+    #[test]
+    fn test_notification_has_no_response() {
+        assert!(
+            handle_message(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#).is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_error_and_unknown_method() {
+        let resp = handle_message("not json").unwrap();
+        assert_eq!(resp["error"]["code"], PARSE_ERROR);
+
+        let resp = handle_message(r#"{"jsonrpc":"2.0","id":"a","method":"nope"}"#).unwrap();
+        assert_eq!(resp["id"], "a");
+        assert_eq!(resp["error"]["code"], METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_tools_have_schemas() {
+        let tools = tool_definitions();
+        let tools = tools.as_array().unwrap();
+        for name in [
+            "run",
+            "read",
+            "grep",
+            "git_status",
+            "git_diff",
+            "git_log",
+            "test",
+            "json",
+            "deps",
+        ] {
+            let tool = tools.iter().find(|t| t["name"] == name).unwrap();
+            assert_eq!(tool["inputSchema"]["type"], "object", "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_tool_argv_mapping() {
+        let argv = tool_argv(
+            "read",
+            &json!({"path": "src/main.rs", "level": "aggressive", "max_lines": 20}),
+        )
+        .unwrap();
+        assert_eq!(
+            argv,
+            [
+                "read",
+                "src/main.rs",
+                "--level",
+                "aggressive",
+                "--max-lines",
+                "20"
+            ]
+        );
+
+        let argv = tool_argv("git_diff", &json!({"args": ["--cached"]})).unwrap();
+        assert_eq!(argv, ["git", "diff", "--cached"]);
+
+        let argv = tool_argv("test", &json!({"runner": "vitest"})).unwrap();
+        assert_eq!(argv, ["vitest", "run"]);
+
+        let argv = tool_argv("run", &json!({"command": "make all", "mode": "test"})).unwrap();
+        assert_eq!(argv, ["test", "make all"]);
+    }
+
+    #[test]
+    fn test_tool_argv_grep_dash_pattern() {
+        let argv = tool_argv(
+            "grep",
+            &json!({"pattern": "--no-verify", "path": "src", "max": 10}),
+        )
+        .unwrap();
+        assert_eq!(argv, ["grep", "--max", "10", "--", "--no-verify", "src"]);
+    }
+
+    #[test]
+    fn test_tool_argv_errors() {
+        assert!(tool_argv("read", &json!({})).is_err());
+        assert!(tool_argv("test", &json!({"runner": "rspec"})).is_err());
+        assert!(tool_argv("rm", &json!({})).is_err());
+    }
+}
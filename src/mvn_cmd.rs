@@ -0,0 +1,423 @@
+use crate::jvm_build::{self, JvmBuild};
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, FormatMode, LintIssue,
+    LintSeverity, OutputParser, ParseResult, TestResult, TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::relative_path;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::path::Path;
+use std::process::Command;
+use std::time::SystemTime;
+
+/// Lifecycle phases whose output is summarized; other goals (plugins such as
+/// `dependency:tree`) only get download/banner noise removed.
+const LIFECYCLE_PHASES: &[&str] = &[
+    "clean",
+    "validate",
+    "compile",
+    "test-compile",
+    "test",
+    "package",
+    "verify",
+    "install",
+    "deploy",
+];
+
+lazy_static::lazy_static! {
+    // [ERROR] /src/app/src/main/java/com/acme/Api.java:[12,5] cannot find symbol
+    static ref COMPILE_DIAG: Regex = Regex::new(
+        r"^\[(ERROR|WARNING)\] (.+?\.(?:java|kt|scala|groovy)):\[(\d+),(\d+)\] (.+)$"
+    ).unwrap();
+    // [ERROR] file:///src/app/src/main/kotlin/Api.kt:12:5 Unresolved reference: foo
+    static ref KOTLIN_DIAG: Regex = Regex::new(
+        r"^\[(ERROR|WARNING)\] (?:file://)?(.+?\.kts?):(\d+):(\d+):? (.+)$"
+    ).unwrap();
+    // [INFO] core ............................................. SUCCESS [  2.345 s]
+    static ref REACTOR_LINE: Regex = Regex::new(
+        r"^\[INFO\] (.+?) \.{2,}\s*(SUCCESS|FAILURE|SKIPPED)(?: \[\s*(.+?)\])?$"
+    ).unwrap();
+    static ref TESTS_TOTAL: Regex = Regex::new(
+        r"^\[(?:INFO|ERROR|WARNING)\] Tests run: (\d+), Failures: (\d+), Errors: (\d+), Skipped: (\d+)$"
+    ).unwrap();
+    // [ERROR] divides(com.acme.CalcTest)  Time elapsed: 0.01 s  <<< FAILURE!
+    // [ERROR] com.acme.CalcTest.divides -- Time elapsed: 0.01 s <<< ERROR!
+    static ref TEST_FAILURE: Regex = Regex::new(
+        r"^\[ERROR\] (.+?)\s+(?:--\s+)?Time elapsed: .*<<< (?:FAILURE|ERROR)!$"
+    ).unwrap();
+    static ref JUNIT4_NAME: Regex = Regex::new(r"^(\w+)\((.+)\)$").unwrap();
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let started = SystemTime::now();
+
+    // Prefer the project's wrapper
+    let tool = if Path::new("mvnw").exists() {
+        "./mvnw"
+    } else {
+        "mvn"
+    };
+    let mut cmd = Command::new(tool);
+    // Batch mode: no colors, no interactive download progress
+    if !args.iter().any(|a| a == "-B" || a == "--batch-mode") {
+        cmd.arg("-B");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: {} -B {}", tool, args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run mvn. Is Maven installed?")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let summarize = args.is_empty() || args.iter().any(|a| LIFECYCLE_PHASES.contains(&a.as_str()));
+
+    let filtered = if summarize {
+        let mode = FormatMode::from_verbosity(verbose);
+        match MavenParser::parse(&raw) {
+            ParseResult::Full(mut build) => {
+                attach_surefire_reports(&mut build, started);
+                build.format(mode)
+            }
+            ParseResult::Degraded(mut build, warnings) => {
+                if verbose > 0 {
+                    emit_degradation_warning("mvn", &warnings.join(", "));
+                }
+                attach_surefire_reports(&mut build, started);
+                build.format(mode)
+            }
+            ParseResult::Passthrough(truncated) => {
+                emit_passthrough_warning("mvn", "No build result found");
+                truncated
+            }
+        }
+    } else {
+        filter_mvn_noise(&raw)
+    };
+
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("mvn {}", args.join(" ")),
+        &format!("rtk mvn {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Surefire/Failsafe XML reports are more precise than the console summary.
+fn attach_surefire_reports(build: &mut JvmBuild, since: SystemTime) {
+    let reports = jvm_build::collect_reports(&["surefire-reports", "failsafe-reports"], since);
+    if let ParseResult::Full(tests) = jvm_build::JunitReportParser::parse(&reports) {
+        build.tests = Some(tests);
+    }
+}
+
+fn is_noise(line: &str) -> bool {
+    line.contains("Downloading from ")
+        || line.contains("Downloaded from ")
+        || line.starts_with("Progress (")
+        || line.trim_start_matches("[INFO] ").starts_with("--- ")
+        || line == "[INFO]"
+        || line.starts_with("[INFO] ----")
+}
+
+/// Light filter for non-lifecycle goals: keep plugin output, drop transfer noise.
+fn filter_mvn_noise(output: &str) -> String {
+    output
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !is_noise(l))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// "divides(com.acme.CalcTest)" / "com.acme.CalcTest.divides" → ("CalcTest.divides", class)
+fn split_test_name(name: &str) -> (String, String) {
+    if let Some(caps) = JUNIT4_NAME.captures(name) {
+        let class = caps[2].to_string();
+        let short = class.rsplit('.').next().unwrap_or(&class).to_string();
+        return (format!("{}.{}", short, &caps[1]), class);
+    }
+    match name.rsplit_once('.') {
+        Some((class, method)) => {
+            let short = class.rsplit('.').next().unwrap_or(class);
+            (format!("{}.{}", short, method), class.to_string())
+        }
+        None => (name.to_string(), String::new()),
+    }
+}
+
+struct MavenParser;
+
+impl OutputParser for MavenParser {
+    type Output = JvmBuild;
+
+    fn parse(input: &str) -> ParseResult<JvmBuild> {
+        let mut build = JvmBuild::new("mvn", "modules");
+        let mut found_result = false;
+        let mut tests: Option<TestResult> = None;
+        let lines: Vec<&str> = input.lines().collect();
+
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i].trim_end();
+            i += 1;
+
+            if let Some(caps) = COMPILE_DIAG
+                .captures(line)
+                .or_else(|| KOTLIN_DIAG.captures(line))
+            {
+                let mut message = caps[5].to_string();
+                // javac "cannot find symbol" details follow on their own lines
+                while let Some(next) = lines.get(i) {
+                    let detail = next.trim_start_matches("[ERROR]").trim();
+                    if let Some(symbol) = detail.strip_prefix("symbol:") {
+                        message.push_str(&format!(" ({})", symbol.trim()));
+                        i += 1;
+                    } else if detail.starts_with("location:") {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                build.push_compile_error(LintIssue {
+                    file_path: relative_path(&caps[2]),
+                    line: caps[3].parse().unwrap_or(0),
+                    column: caps[4].parse().unwrap_or(0),
+                    severity: if &caps[1] == "ERROR" {
+                        LintSeverity::Error
+                    } else {
+                        LintSeverity::Warning
+                    },
+                    rule_id: "javac".to_string(),
+                    message,
+                });
+                continue;
+            }
+
+            if let Some(caps) = REACTOR_LINE.captures(line) {
+                build
+                    .outcomes
+                    .push((caps[1].to_string(), caps[2].to_string()));
+                continue;
+            }
+
+            if let Some(caps) = TESTS_TOTAL.captures(line) {
+                let n = |idx: usize| caps[idx].parse::<usize>().unwrap_or(0);
+                let t = tests.get_or_insert(TestResult {
+                    total: 0,
+                    passed: 0,
+                    failed: 0,
+                    skipped: 0,
+                    duration_ms: None,
+                    failures: Vec::new(),
+                });
+                t.total += n(1);
+                t.failed += n(2) + n(3);
+                t.skipped += n(4);
+                continue;
+            }
+
+            if let Some(caps) = TEST_FAILURE.captures(line) {
+                if caps[1].starts_with("Tests run:") {
+                    continue;
+                }
+                let (name, class) = split_test_name(&caps[1]);
+                let mut trace = Vec::new();
+                while let Some(next) = lines.get(i) {
+                    if next.trim().is_empty() || next.starts_with('[') {
+                        break;
+                    }
+                    trace.push(*next);
+                    i += 1;
+                }
+                let failure = jvm_build::test_failure(name, class, &trace.join("\n"));
+                let t = tests.get_or_insert(TestResult {
+                    total: 0,
+                    passed: 0,
+                    failed: 0,
+                    skipped: 0,
+                    duration_ms: None,
+                    failures: Vec::new(),
+                });
+                if !t.failures.iter().any(|f| f.test_name == failure.test_name) {
+                    t.failures.push(failure);
+                }
+                continue;
+            }
+
+            if line == "[INFO] BUILD SUCCESS" {
+                found_result = true;
+                build.success = true;
+            } else if line == "[INFO] BUILD FAILURE" {
+                found_result = true;
+                build.success = false;
+            } else if let Some(time) = line.strip_prefix("[INFO] Total time:") {
+                build.duration = Some(time.trim().to_string());
+            } else if let Some(goal) = line.strip_prefix("[ERROR] Failed to execute goal ") {
+                let goal = goal.replace("org.apache.maven.plugins:", "");
+                // The compile errors are listed separately
+                let goal = goal
+                    .split_once(": Compilation failure")
+                    .map(|(g, _)| format!("{}: Compilation failure", g))
+                    .unwrap_or(goal);
+                build
+                    .failure
+                    .push(format!("❌ {}", crate::utils::truncate(&goal, 200)));
+            }
+        }
+
+        if let Some(t) = tests.as_mut() {
+            t.passed = t.total.saturating_sub(t.failed + t.skipped);
+        }
+        build.tests = tests;
+
+        if found_result {
+            ParseResult::Full(build)
+        } else if !build.compile_errors.is_empty() || build.tests.is_some() {
+            build.success = false;
+            ParseResult::Degraded(build, vec!["no BUILD SUCCESS/FAILURE line".to_string()])
+        } else {
+            let kept = filter_mvn_noise(input);
+            ParseResult::Passthrough(truncate_output(&kept, 2000))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTI_MODULE_FAILURE: &str = "\
+[INFO] Scanning for projects...
+[INFO] ------------------------------------------------------------------------
+[INFO] Reactor Build Order:
+[INFO]
+[INFO] core                                                               [jar]
+[INFO] api                                                                [jar]
+[INFO]
+[INFO] --- maven-resources-plugin:3.3.1:resources (default-resources) @ core ---
+Downloading from central: https://repo.maven.apache.org/maven2/org/junit/junit-bom/5.10.0/junit-bom-5.10.0.pom
+Downloaded from central: https://repo.maven.apache.org/maven2/org/junit/junit-bom/5.10.0/junit-bom-5.10.0.pom (5.6 kB at 120 kB/s)
+[INFO] --- maven-surefire-plugin:3.2.2:test (default-test) @ core ---
+[INFO] Running com.acme.CalcTest
+[ERROR] Tests run: 3, Failures: 1, Errors: 0, Skipped: 0, Time elapsed: 0.05 s <<< FAILURE! -- in com.acme.CalcTest
+[ERROR] com.acme.CalcTest.divides -- Time elapsed: 0.01 s <<< FAILURE!
+org.opentest4j.AssertionFailedError: expected: <2> but was: <3>
+	at org.junit.jupiter.api.AssertionUtils.fail(AssertionUtils.java:151)
+	at com.acme.Calc.divide(Calc.java:20)
+	at com.acme.CalcTest.divides(CalcTest.java:31)
+
+[INFO]
+[INFO] Results:
+[INFO]
+[ERROR] Failures:
+[ERROR]   CalcTest.divides:31 expected: <2> but was: <3>
+[INFO]
+[ERROR] Tests run: 3, Failures: 1, Errors: 0, Skipped: 0
+[INFO]
+[INFO] --- maven-compiler-plugin:3.11.0:compile (default-compile) @ api ---
+[ERROR] COMPILATION ERROR :
+[ERROR] /work/api/src/main/java/com/acme/Api.java:[12,5] cannot find symbol
+[ERROR]   symbol:   variable client
+[ERROR]   location: class com.acme.Api
+[INFO] 1 error
+[INFO] ------------------------------------------------------------------------
+[INFO] Reactor Summary for acme 1.0-SNAPSHOT:
+[INFO]
+[INFO] core ............................................... SUCCESS [  2.345 s]
+[INFO] api ................................................ FAILURE [  0.512 s]
+[INFO] app ................................................ SKIPPED
+[INFO] ------------------------------------------------------------------------
+[INFO] BUILD FAILURE
+[INFO] ------------------------------------------------------------------------
+[INFO] Total time:  3.120 s
+[INFO] ------------------------------------------------------------------------
+[ERROR] Failed to execute goal org.apache.maven.plugins:maven-compiler-plugin:3.11.0:compile (default-compile) on project api: Compilation failure
+[ERROR] /work/api/src/main/java/com/acme/Api.java:[12,5] cannot find symbol
+[ERROR]   symbol:   variable client
+[ERROR]   location: class com.acme.Api
+[ERROR] -> [Help 1]
+";
+
+    #[test]
+    fn test_maven_parser_multi_module() {
+        let build = MavenParser::parse(MULTI_MODULE_FAILURE).unwrap();
+        assert!(!build.success);
+        assert_eq!(build.duration.as_deref(), Some("3.120 s"));
+        assert_eq!(build.outcomes.len(), 3);
+        assert_eq!(build.compile_errors.len(), 1);
+        assert_eq!(
+            build.compile_errors[0].message,
+            "cannot find symbol (variable client)"
+        );
+
+        let tests = build.tests.as_ref().unwrap();
+        assert_eq!((tests.total, tests.passed, tests.failed), (3, 2, 1));
+        assert_eq!(tests.failures[0].test_name, "CalcTest.divides");
+        assert_eq!(
+            tests.failures[0].stack_trace.as_deref(),
+            Some("at com.acme.Calc.divide(Calc.java:20)\nat com.acme.CalcTest.divides(CalcTest.java:31)")
+        );
+
+        let compact = build.format(FormatMode::Compact);
+        assert!(compact.starts_with(
+            "❌ mvn build failed (3.120 s)\nmodules: 1 SUCCESS, 1 FAILURE, 1 SKIPPED — failed: api"
+        ));
+        assert!(compact.contains("/work/api/src/main/java/com/acme/Api.java (1)\n  L12:5 cannot find symbol (variable client)"));
+        assert!(compact.contains("PASS (2) FAIL (1)"));
+        assert!(compact.ends_with(
+            "❌ maven-compiler-plugin:3.11.0:compile (default-compile) on project api: Compilation failure"
+        ));
+        assert!(!compact.contains("Downloading"));
+        assert!(!compact.contains("Help 1"));
+    }
+
+    #[test]
+    fn test_maven_parser_success_and_passthrough() {
+        let output = "\
+[INFO] --- maven-jar-plugin:3.3.0:jar (default-jar) @ core ---
+[INFO] Building jar: /work/core/target/core-1.0.jar
+[INFO] BUILD SUCCESS
+[INFO] Total time:  1.002 s
+";
+        let build = MavenParser::parse(output).unwrap();
+        assert_eq!(
+            build.format(FormatMode::Compact),
+            "✓ mvn build succeeded (1.002 s)"
+        );
+
+        let result = MavenParser::parse("Apache Maven 3.9.6\nMaven home: /usr/share/maven");
+        assert_eq!(result.tier(), 3);
+    }
+
+    #[test]
+    fn test_split_test_name() {
+        assert_eq!(
+            split_test_name("divides(com.acme.CalcTest)"),
+            (
+                "CalcTest.divides".to_string(),
+                "com.acme.CalcTest".to_string()
+            )
+        );
+        assert_eq!(
+            split_test_name("com.acme.CalcTest.divides"),
+            (
+                "CalcTest.divides".to_string(),
+                "com.acme.CalcTest".to_string()
+            )
+        );
+    }
+}
@@ -43,7 +43,7 @@ pub fn run(args: &[String], verbose: u8, skip_env: bool) -> Result<()> {
 }
 
 /// Filter npm run output - strip boilerplate, progress bars, npm WARN
-fn filter_npm_output(output: &str) -> String {
+pub(crate) fn filter_npm_output(output: &str) -> String {
     let mut result = Vec::new();
 
     for line in output.lines() {
@@ -0,0 +1,157 @@
+//! JUnit XML report parsing shared by every runner that writes the format
+//! (Surefire/Gradle, PHPUnit `--log-junit`, cargo-nextest).
+//!
+//! Counting testcases, spotting failures and summing suite times is the same
+//! everywhere; only turning a failed testcase into a `TestFailure` differs
+//! (test naming attributes, stack frame format), so callers supply that part.
+
+use super::{unescape_xml, xml_attr, TestFailure, TestResult};
+use regex::Regex;
+
+lazy_static::lazy_static! {
+    static ref TESTCASE: Regex =
+        Regex::new(r"(?s)<testcase\b([^>]*?)(?:/>|>(.*?)</testcase>)").unwrap();
+    static ref PROBLEM: Regex =
+        Regex::new(r"(?s)<(failure|error)\b([^>]*?)(?:/>|>(.*?)</(?:failure|error)>)").unwrap();
+    static ref SUITE: Regex = Regex::new(r"<(/?)testsuites?\b([^>]*?)(/?)>").unwrap();
+}
+
+/// Parses one or more concatenated JUnit reports.
+///
+/// `failure` receives a failed testcase's attribute string and its failure
+/// text (element body, CDATA unwrapped, falling back to the `message`
+/// attribute). Returns `None` when the input contains no testcases.
+pub fn parse_junit<F>(input: &str, mut failure: F) -> Option<TestResult>
+where
+    F: FnMut(&str, &str) -> TestFailure,
+{
+    let mut result = TestResult {
+        total: 0,
+        passed: 0,
+        failed: 0,
+        skipped: 0,
+        duration_ms: None,
+        failures: Vec::new(),
+    };
+
+    for caps in TESTCASE.captures_iter(input) {
+        let attrs = &caps[1];
+        let body = caps.get(2).map(|m| m.as_str()).unwrap_or("");
+        result.total += 1;
+
+        if let Some(problem) = PROBLEM.captures(body) {
+            result.failed += 1;
+            let text = problem
+                .get(3)
+                .map(|m| {
+                    let t = m.as_str().trim();
+                    let t = t
+                        .strip_prefix("<![CDATA[")
+                        .and_then(|t| t.strip_suffix("]]>"))
+                        .unwrap_or(t);
+                    unescape_xml(t)
+                })
+                .filter(|t| !t.trim().is_empty())
+                .or_else(|| xml_attr(&problem[2], "message"))
+                .unwrap_or_default();
+            result.failures.push(failure(attrs, &text));
+        } else if body.contains("<skipped") {
+            result.skipped += 1;
+        } else {
+            result.passed += 1;
+        }
+    }
+
+    if result.total == 0 {
+        return None;
+    }
+
+    let seconds = outer_suite_seconds(input);
+    if seconds > 0.0 {
+        result.duration_ms = Some((seconds * 1000.0) as u64);
+    }
+    Some(result)
+}
+
+/// Sum of the `time` of the outermost timed suite elements. Reports may be
+/// concatenated (one root suite each) and suites may nest (PHPUnit, nextest's
+/// `<testsuites>` wrapper), so suites inside a counted one are skipped and
+/// untimed wrappers are looked through.
+fn outer_suite_seconds(input: &str) -> f64 {
+    // One entry per open suite: whether its time was counted
+    let mut open: Vec<bool> = Vec::new();
+    let mut seconds = 0.0;
+    for caps in SUITE.captures_iter(input) {
+        if &caps[1] == "/" {
+            open.pop();
+            continue;
+        }
+        let time = xml_attr(&caps[2], "time").and_then(|t| t.parse::<f64>().ok());
+        let counted = match time {
+            Some(time) if !open.contains(&true) => {
+                seconds += time;
+                true
+            }
+            _ => false,
+        };
+        if caps[3].is_empty() {
+            open.push(counted);
+        }
+    }
+    seconds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn by_name(attrs: &str, text: &str) -> TestFailure {
+        TestFailure {
+            test_name: xml_attr(attrs, "name").unwrap_or_default(),
+            file_path: String::new(),
+            error_message: text.to_string(),
+            stack_trace: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_junit_counts_and_failure_text() {
+        let xml = r#"<testsuite name="a" time="0.5">
+  <testcase name="ok"/>
+  <testcase name="cdata"><failure><![CDATA[boom <here>]]></failure></testcase>
+  <testcase name="attr"><error message="x &lt; y"/></testcase>
+  <testcase name="skip"><skipped/></testcase>
+</testsuite>
+<testsuite name="b" time="0.25"><testcase name="ok2"/></testsuite>"#;
+        let result = parse_junit(xml, by_name).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (5, 2, 2, 1)
+        );
+        assert_eq!(result.duration_ms, Some(750));
+        assert_eq!(result.failures[0].error_message, "boom <here>");
+        assert_eq!(result.failures[1].error_message, "x < y");
+
+        assert!(parse_junit("<testsuites/>", by_name).is_none());
+    }
+
+    #[test]
+    fn test_parse_junit_nested_suites_count_once() {
+        let xml = r#"<testsuites name="phpunit">
+  <testsuite name="All" time="1.5">
+    <testsuite name="Unit" time="1.0"><testcase name="a"/></testsuite>
+    <testsuite name="Empty" time="0" tests="0"/>
+    <testsuite name="Feature" time="0.5"><testcase name="b"/></testsuite>
+  </testsuite>
+</testsuites>"#;
+        let result = parse_junit(xml, by_name).unwrap();
+        assert_eq!(result.duration_ms, Some(1500));
+
+        // nextest: only the wrapper is timed
+        let xml = r#"<testsuites name="nextest-run" time="0.2">
+  <testsuite name="calc"><testcase name="a"/></testsuite>
+</testsuites>"#;
+        let result = parse_junit(xml, by_name).unwrap();
+        assert_eq!(result.duration_ms, Some(200));
+    }
+}
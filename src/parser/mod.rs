@@ -9,6 +9,7 @@
 
 pub mod error;
 pub mod formatter;
+pub mod junit;
 pub mod types;
 
 pub use formatter::{FormatMode, TokenFormatter};
@@ -187,6 +188,35 @@ pub fn extract_json_object(input: &str) -> Option<&str> {
     None
 }
 
+/// Decode the XML entities found in test report attributes and messages
+/// (JUnit, TRX). Carriage returns are dropped, whichever form they use.
+pub fn unescape_xml(s: &str) -> String {
+    s.replace("&#13;", "")
+        .replace("&#xD;", "")
+        .replace("&#xd;", "")
+        .replace("&#10;", "\n")
+        .replace("&#xA;", "\n")
+        .replace("&#xa;", "\n")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Unescaped value of attribute `name` in an element's attribute string.
+/// Matches whole names only (`name` does not match `classname`).
+pub fn xml_attr(attrs: &str, name: &str) -> Option<String> {
+    let needle = format!("{}=\"", name);
+    let start = attrs
+        .match_indices(&needle)
+        .find(|(i, _)| *i == 0 || attrs.as_bytes()[i - 1].is_ascii_whitespace())?
+        .0
+        + needle.len();
+    let end = attrs[start..].find('"')? + start;
+    Some(unescape_xml(&attrs[start..end]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,4 +338,19 @@ Scope: all 6 workspace projects
         assert!(extracted.contains("test {should} not confuse parser"));
         assert_eq!(extracted, input);
     }
+
+    #[test]
+    fn test_unescape_xml_line_endings() {
+        assert_eq!(unescape_xml("a&#13;&#10;b"), "a\nb");
+        assert_eq!(unescape_xml("a&#xD;&#xA;b"), "a\nb");
+        assert_eq!(unescape_xml("&lt;T&gt; &amp;lt;"), "<T> &lt;");
+    }
+
+    #[test]
+    fn test_xml_attr_whole_name() {
+        let attrs = r#"classname="CalcTest" name="adds &quot;1&quot;" time="0.01""#;
+        assert_eq!(xml_attr(attrs, "name").as_deref(), Some("adds \"1\""));
+        assert_eq!(xml_attr(attrs, "classname").as_deref(), Some("CalcTest"));
+        assert_eq!(xml_attr(attrs, "missing"), None);
+    }
 }
@@ -0,0 +1,251 @@
+use crate::tracking;
+use crate::utils::{relative_path, truncate, vendor_bin};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Files listed before collapsing.
+const MAX_FILES: usize = 10;
+/// Identifier groups listed per file.
+const MAX_GROUPS_PER_FILE: usize = 5;
+
+#[derive(Debug, Deserialize)]
+struct PhpstanOutput {
+    totals: PhpstanTotals,
+    #[serde(default, deserialize_with = "files_map")]
+    files: BTreeMap<String, PhpstanFile>,
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+/// PHP encodes an empty map as `[]`, so a clean run reports `"files":[]`.
+fn files_map<'de, D>(deserializer: D) -> Result<BTreeMap<String, PhpstanFile>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Array(_) => Ok(BTreeMap::new()),
+        value => serde_json::from_value(value).map_err(serde::de::Error::custom),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PhpstanTotals {
+    errors: usize,
+    file_errors: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct PhpstanFile {
+    messages: Vec<PhpstanMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PhpstanMessage {
+    message: String,
+    line: Option<usize>,
+    /// Missing before PHPStan 1.11
+    identifier: Option<String>,
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // `phpstan` alone or with flags means `phpstan analyse`
+    let first = args.first().map(|s| s.as_str());
+    let is_analyse = match first {
+        None => true,
+        Some(a) => a == "analyse" || a == "analyze" || a.starts_with('-'),
+    };
+    let user_format = args.iter().any(|a| a.starts_with("--error-format"));
+
+    let mut cmd = vendor_bin("phpstan");
+    if is_analyse && !user_format {
+        if first.is_none_or(|a| a.starts_with('-')) {
+            cmd.arg("analyse");
+        }
+        cmd.args(args)
+            .arg("--error-format=json")
+            .arg("--no-progress")
+            .arg("--no-interaction");
+    } else {
+        cmd.args(args);
+    }
+
+    if verbose > 0 {
+        eprintln!("Running: phpstan {}", args.join(" "));
+    }
+
+    let output = cmd.output().context(
+        "Failed to run phpstan. Is it installed? Try: composer require --dev phpstan/phpstan",
+    )?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = if is_analyse && !user_format {
+        filter_phpstan_json(&stdout, &stderr)
+    } else {
+        raw.trim().to_string()
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("phpstan {}", args.join(" ")),
+        &format!("rtk phpstan {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Filter PHPStan JSON output - group by file, then by identifier
+fn filter_phpstan_json(stdout: &str, stderr: &str) -> String {
+    let report: PhpstanOutput = match serde_json::from_str(stdout.trim()) {
+        Ok(r) => r,
+        Err(e) => {
+            // Config errors and crashes are reported as text
+            let text = if stdout.trim().is_empty() {
+                stderr
+            } else {
+                stdout
+            };
+            return format!(
+                "PHPStan output (JSON parse failed: {})\n{}",
+                e,
+                truncate(text.trim(), 500)
+            );
+        }
+    };
+
+    let total = report.totals.file_errors + report.totals.errors;
+    if total == 0 {
+        return "✓ PHPStan: No errors".to_string();
+    }
+
+    let mut files: Vec<(&String, &PhpstanFile)> = report.files.iter().collect();
+    files.sort_by(|a, b| {
+        b.1.messages
+            .len()
+            .cmp(&a.1.messages.len())
+            .then(a.0.cmp(b.0))
+    });
+
+    let mut result = String::new();
+    result.push_str(&format!(
+        "PHPStan: {} errors in {} files\n",
+        total,
+        files.len()
+    ));
+    result.push_str("═══════════════════════════════════════\n");
+
+    for (path, file) in files.iter().take(MAX_FILES) {
+        result.push_str(&format!(
+            "{} ({})\n",
+            relative_path(path),
+            file.messages.len()
+        ));
+
+        // Group by identifier (or the message itself when there is none)
+        let mut keys: Vec<&str> = Vec::new();
+        let mut groups: BTreeMap<&str, Vec<&PhpstanMessage>> = BTreeMap::new();
+        for msg in &file.messages {
+            let key = msg.identifier.as_deref().unwrap_or(&msg.message);
+            if !groups.contains_key(key) {
+                keys.push(key);
+            }
+            groups.entry(key).or_default().push(msg);
+        }
+
+        for key in keys.iter().take(MAX_GROUPS_PER_FILE) {
+            let group = &groups[key];
+            let lines: Vec<String> = group
+                .iter()
+                .filter_map(|m| m.line)
+                .map(|l| l.to_string())
+                .collect();
+            let location = if lines.is_empty() {
+                String::new()
+            } else {
+                format!(" (L{})", lines.join(", "))
+            };
+            let first = &group[0];
+            match &first.identifier {
+                Some(identifier) => {
+                    let count = if group.len() > 1 {
+                        format!(" ×{}", group.len())
+                    } else {
+                        String::new()
+                    };
+                    result.push_str(&format!(
+                        "  {}{}{}: {}\n",
+                        identifier,
+                        count,
+                        location,
+                        truncate(&first.message, 140)
+                    ));
+                }
+                None => {
+                    result.push_str(&format!(
+                        "  {}{}\n",
+                        truncate(&first.message, 160),
+                        location
+                    ));
+                }
+            }
+        }
+        if keys.len() > MAX_GROUPS_PER_FILE {
+            result.push_str(&format!(
+                "  ... +{} more\n",
+                keys.len() - MAX_GROUPS_PER_FILE
+            ));
+        }
+    }
+
+    if files.len() > MAX_FILES {
+        result.push_str(&format!("\n... +{} more files\n", files.len() - MAX_FILES));
+    }
+
+    // Errors not tied to a file (unmatched ignore patterns, config problems)
+    if !report.errors.is_empty() {
+        result.push_str("General:\n");
+        for error in report.errors.iter().take(5) {
+            result.push_str(&format!("  {}\n", truncate(error, 160)));
+        }
+    }
+
+    result.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_phpstan_json() {
+        let input = include_str!("../tests/fixtures/phpstan.json");
+        assert_eq!(
+            filter_phpstan_json(input, ""),
+            "\
+PHPStan: 5 errors in 2 files
+═══════════════════════════════════════
+/app/src/Models/User.php (3)
+  property.notFound ×2 (L12, 30): Access to an undefined property App\\Models\\User::$emial.
+  return.type (L41): Method App\\Models\\User::name() should return string but returns string|null.
+/app/src/Services/Billing.php (1)
+  method.nonObject (L22): Cannot call method amount() on App\\Models\\Order|null.
+General:
+  Ignored error pattern #^Call to an undefined method Foo::bar\\(\\)\\.$# was not matched in reported errors."
+        );
+    }
+
+    #[test]
+    fn test_filter_phpstan_no_errors() {
+        let input = r#"{"totals":{"errors":0,"file_errors":0},"files":[],"errors":[]}"#;
+        assert_eq!(filter_phpstan_json(input, ""), "✓ PHPStan: No errors");
+    }
+}
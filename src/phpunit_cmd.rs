@@ -0,0 +1,466 @@
+use crate::parser::junit::parse_junit;
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, xml_attr, FormatMode,
+    OutputParser, ParseResult, TestFailure, TestResult, TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::{relative_path, truncate, vendor_bin};
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Application frames kept per failure.
+const MAX_FRAMES: usize = 3;
+
+lazy_static::lazy_static! {
+    static ref TEAMCITY: Regex =
+        Regex::new(r"^##teamcity\[(\w+)((?:\s+\w+='(?:[^'|]|\|.)*')*)\s*\]").unwrap();
+    static ref TEAMCITY_ATTR: Regex = Regex::new(r"(\w+)='((?:[^'|]|\|.)*)'").unwrap();
+    // "/app/src/Billing.php:22"
+    static ref FRAME: Regex = Regex::new(r"^(\S+\.php):(\d+)$").unwrap();
+    // "Tests: 12, Assertions: 20, Errors: 1, Failures: 1, Skipped: 1."
+    static ref SUMMARY: Regex = Regex::new(r"^Tests: (\d+), Assertions: \d+(.*)$").unwrap();
+    static ref SUMMARY_COUNT: Regex = Regex::new(r"(\w+): (\d+)").unwrap();
+    // "OK (12 tests, 20 assertions)"
+    static ref SUMMARY_OK: Regex = Regex::new(r"^OK \((\d+) tests?, \d+ assertions?\)").unwrap();
+    static ref SECTION: Regex =
+        Regex::new(r"^There (?:was|were) \d+ (error|failure|warning|risky test|skipped test|incomplete test|deprecation|notice)s?:$").unwrap();
+    static ref ENTRY: Regex = Regex::new(r"^\d+\) (\S.*)$").unwrap();
+    static ref TIME: Regex = Regex::new(r"^Time: (?:(\d+):)?(\d+):(\d+)(?:\.(\d+))?").unwrap();
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // JUnit log in a scratch file unless the caller chose a logger
+    let report_dir = tempfile::Builder::new()
+        .prefix("rtk-phpunit-")
+        .tempdir()
+        .context("Failed to create report directory")?;
+    let junit_path = report_dir.path().join("junit.xml");
+    let user_logging = args
+        .iter()
+        .any(|a| a.starts_with("--log-junit") || a == "--teamcity");
+
+    let mut cmd = vendor_bin("phpunit");
+    if !user_logging {
+        cmd.arg("--log-junit").arg(&junit_path);
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: phpunit {}", args.join(" "));
+    }
+
+    let output = cmd.output().context(
+        "Failed to run phpunit. Is it installed? Try: composer require --dev phpunit/phpunit",
+    )?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let junit = std::fs::read_to_string(&junit_path).unwrap_or_default();
+    let parsed = if !junit.trim().is_empty() {
+        PhpunitJunitParser::parse(&junit)
+    } else if stdout.contains("##teamcity[") {
+        TeamcityParser::parse(&stdout)
+    } else {
+        PhpunitParser::parse(&raw)
+    };
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = match parsed {
+        ParseResult::Full(result) => result.format(mode),
+        ParseResult::Degraded(result, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("phpunit", &warnings.join(", "));
+            }
+            result.format(mode)
+        }
+        ParseResult::Passthrough(truncated) => {
+            emit_passthrough_warning("phpunit", "No test results found");
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("phpunit {}", args.join(" ")),
+        &format!("rtk phpunit {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// "Tests\Unit\UserTest" -> "UserTest"
+fn short_class(class: &str) -> &str {
+    class.rsplit('\\').next().unwrap_or(class)
+}
+
+/// Builds a failure from PHPUnit's message and "file.php:line" frames,
+/// located at the first frame outside vendor/.
+fn test_failure(
+    test_name: String,
+    file_path: String,
+    message: &[&str],
+    frames: &[&str],
+) -> TestFailure {
+    let message: Vec<&str> = message
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !matches!(*l, "--- Expected" | "+++ Actual" | "@@ @@"))
+        .collect();
+    let frames: Vec<String> = frames
+        .iter()
+        .map(|f| f.trim())
+        .filter(|f| FRAME.is_match(f) && !f.contains("/vendor/"))
+        .take(MAX_FRAMES)
+        .map(relative_path)
+        .collect();
+
+    let mut error_message = truncate(&message.join(" "), 200);
+    if let Some(first) = frames.first() {
+        let name = first.rsplit('/').next().unwrap_or(first);
+        error_message.push_str(&format!(" ({})", name));
+    }
+
+    TestFailure {
+        test_name,
+        file_path,
+        error_message,
+        stack_trace: if frames.is_empty() {
+            None
+        } else {
+            Some(frames.join("\n"))
+        },
+    }
+}
+
+/// Splits a PHPUnit failure body into message lines and trailing frames.
+fn split_body<'a>(lines: &[&'a str]) -> (Vec<&'a str>, Vec<&'a str>) {
+    let first_frame = lines
+        .iter()
+        .position(|l| FRAME.is_match(l.trim()))
+        .unwrap_or(lines.len());
+    (lines[..first_frame].to_vec(), lines[first_frame..].to_vec())
+}
+
+fn empty_result() -> TestResult {
+    TestResult {
+        total: 0,
+        passed: 0,
+        failed: 0,
+        skipped: 0,
+        duration_ms: None,
+        failures: Vec::new(),
+    }
+}
+
+/// Parses PHPUnit's `--log-junit` report.
+pub struct PhpunitJunitParser;
+
+impl OutputParser for PhpunitJunitParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let result = parse_junit(input, |attrs, text| {
+            let class = xml_attr(attrs, "class").unwrap_or_default();
+            let name = format!(
+                "{}::{}",
+                short_class(&class),
+                xml_attr(attrs, "name").unwrap_or_default()
+            );
+            // The first line repeats the fully qualified test name
+            let lines: Vec<&str> = text.lines().skip(1).collect();
+            let (message, frames) = split_body(&lines);
+            let file = xml_attr(attrs, "file").map(|f| relative_path(&f));
+            let line = xml_attr(attrs, "line");
+            let file_path = match (file, line) {
+                (Some(file), Some(line)) => format!("{}:{}", file, line),
+                (Some(file), None) => file,
+                _ => String::new(),
+            };
+            test_failure(name, file_path, &message, &frames)
+        });
+        match result {
+            Some(result) => ParseResult::Full(result),
+            None => ParseResult::Passthrough(truncate_output(input, 500)),
+        }
+    }
+}
+
+fn unescape_teamcity(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '|' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => {}
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// Parses `phpunit --teamcity` service messages.
+pub struct TeamcityParser;
+
+impl OutputParser for TeamcityParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let mut result = empty_result();
+        let mut suite = String::new();
+        let mut duration_ms = 0u64;
+
+        for line in input.lines() {
+            let Some(caps) = TEAMCITY.captures(line.trim()) else {
+                continue;
+            };
+            let attrs: HashMap<&str, String> = TEAMCITY_ATTR
+                .captures_iter(caps.get(2).map(|m| m.as_str()).unwrap_or(""))
+                .map(|a| (a.get(1).unwrap().as_str(), unescape_teamcity(&a[2])))
+                .collect();
+            let name = attrs.get("name").cloned().unwrap_or_default();
+
+            match &caps[1] {
+                "testSuiteStarted" => suite = short_class(&name).to_string(),
+                "testFinished" => {
+                    result.total += 1;
+                    duration_ms += attrs
+                        .get("duration")
+                        .and_then(|d| d.parse::<u64>().ok())
+                        .unwrap_or(0);
+                }
+                "testIgnored" => result.skipped += 1,
+                "testFailed" => {
+                    result.failed += 1;
+                    let mut message = attrs.get("message").cloned().unwrap_or_default();
+                    if let (Some(expected), Some(actual)) =
+                        (attrs.get("expected"), attrs.get("actual"))
+                    {
+                        message.push_str(&format!(" expected {}, got {}", expected, actual));
+                    }
+                    let details = attrs.get("details").cloned().unwrap_or_default();
+                    let frames: Vec<&str> = details.lines().collect();
+                    result.failures.push(test_failure(
+                        format!("{}::{}", suite, name),
+                        String::new(),
+                        &[message.as_str()],
+                        &frames,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        if result.total == 0 && result.failures.is_empty() {
+            return ParseResult::Passthrough(truncate_output(input, 500));
+        }
+        result.passed = result.total.saturating_sub(result.failed + result.skipped);
+        result.duration_ms = Some(duration_ms);
+        ParseResult::Full(result)
+    }
+}
+
+/// Parses PHPUnit's default console output.
+pub struct PhpunitParser;
+
+impl OutputParser for PhpunitParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let mut result = empty_result();
+        let mut found_summary = false;
+
+        let lines: Vec<&str> = input.lines().collect();
+        let mut section = "";
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i].trim_end();
+            i += 1;
+
+            if let Some(caps) = TIME.captures(line) {
+                let n = |idx: usize| {
+                    caps.get(idx)
+                        .and_then(|m| m.as_str().parse::<u64>().ok())
+                        .unwrap_or(0)
+                };
+                let millis = caps
+                    .get(4)
+                    .map(|m| format!("{:0<3}", &m.as_str()[..m.as_str().len().min(3)]))
+                    .and_then(|m| m.parse::<u64>().ok())
+                    .unwrap_or(0);
+                result.duration_ms = Some(((n(1) * 60 + n(2)) * 60 + n(3)) * 1000 + millis);
+                continue;
+            }
+            if let Some(caps) = SUMMARY_OK.captures(line) {
+                found_summary = true;
+                result.total = caps[1].parse().unwrap_or(0);
+                continue;
+            }
+            if let Some(caps) = SUMMARY.captures(line) {
+                found_summary = true;
+                result.total = caps[1].parse().unwrap_or(0);
+                for count in SUMMARY_COUNT.captures_iter(&caps[2]) {
+                    let n: usize = count[2].parse().unwrap_or(0);
+                    match &count[1] {
+                        "Errors" | "Failures" => result.failed += n,
+                        "Skipped" | "Incomplete" => result.skipped += n,
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+            if let Some(caps) = SECTION.captures(line) {
+                section = if matches!(&caps[1], "error" | "failure") {
+                    "failures"
+                } else {
+                    ""
+                };
+                continue;
+            }
+            if section != "failures" {
+                continue;
+            }
+            let Some(caps) = ENTRY.captures(line) else {
+                continue;
+            };
+
+            let full_name = caps[1].to_string();
+            let mut body = Vec::new();
+            while let Some(next) = lines.get(i) {
+                if *next == "--" || ENTRY.is_match(next) || SECTION.is_match(next) {
+                    break;
+                }
+                i += 1;
+                body.push(*next);
+            }
+            let name = match full_name.split_once("::") {
+                Some((class, method)) => format!("{}::{}", short_class(class), method),
+                None => full_name,
+            };
+            let (message, frames) = split_body(&body);
+            result
+                .failures
+                .push(test_failure(name, String::new(), &message, &frames));
+        }
+
+        if !found_summary {
+            return ParseResult::Passthrough(truncate_output(input.trim(), 2000));
+        }
+        result.passed = result.total.saturating_sub(result.failed + result.skipped);
+        ParseResult::Full(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_junit_parser() {
+        let input = include_str!("../tests/fixtures/phpunit_junit.xml");
+        let result = PhpunitJunitParser::parse(input).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (12, 9, 2, 1)
+        );
+        assert_eq!(result.duration_ms, Some(123));
+
+        let failure = &result.failures[0];
+        assert_eq!(failure.test_name, "UserTest::testDowncasesEmail");
+        assert_eq!(failure.file_path, "/app/tests/Unit/UserTest.php:15");
+        assert_eq!(
+            failure.error_message,
+            "Failed asserting that two strings are identical. -'bob@example.com' +'Bob@Example.com' (UserTest.php:18)"
+        );
+
+        let error = &result.failures[1];
+        assert_eq!(
+            error.error_message,
+            "Error: Call to a member function amount() on null (Billing.php:22)"
+        );
+        // vendor/ frames dropped
+        assert_eq!(
+            error.stack_trace.as_deref(),
+            Some("/app/src/Billing.php:22\n/app/tests/Unit/BillingTest.php:17")
+        );
+    }
+
+    #[test]
+    fn test_junit_parser_windows_line_endings() {
+        let input = r#"<testsuite name="Unit" time="0.01">
+  <testcase name="testSum" class="Tests\MathTest" file="C:\app\tests\MathTest.php" line="9">
+    <failure type="ExpectationFailedException">Tests\MathTest::testSum&#13;
+Failed asserting that 3 matches expected 4.&#13;
+&#13;
+C:\app\tests\MathTest.php:11&#13;
+</failure>
+  </testcase>
+</testsuite>"#;
+        let result = PhpunitJunitParser::parse(input).unwrap();
+        let failure = &result.failures[0];
+        assert!(!failure.error_message.contains("&#13;"));
+        assert!(failure
+            .error_message
+            .starts_with("Failed asserting that 3 matches expected 4."));
+    }
+
+    #[test]
+    fn test_teamcity_parser() {
+        let input = include_str!("../tests/fixtures/phpunit_teamcity.txt");
+        let result = TeamcityParser::parse(input).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (3, 1, 1, 1)
+        );
+        assert_eq!(result.failures[0].test_name, "UserTest::testDowncasesEmail");
+        assert_eq!(
+            result.failures[0].error_message,
+            "Failed asserting that two strings are identical. expected 'bob@example.com', got 'Bob@Example.com' (UserTest.php:18)"
+        );
+    }
+
+    #[test]
+    fn test_console_parser() {
+        let input = include_str!("../tests/fixtures/phpunit.txt");
+        let result = PhpunitParser::parse(input).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (12, 9, 2, 1)
+        );
+        assert_eq!(result.duration_ms, Some(123));
+        let names: Vec<&str> = result
+            .failures
+            .iter()
+            .map(|f| f.test_name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["BillingTest::testCharge", "UserTest::testDowncasesEmail"]
+        );
+        assert_eq!(
+            result.failures[0].error_message,
+            "Error: Call to a member function amount() on null (Billing.php:22)"
+        );
+    }
+
+    #[test]
+    fn test_console_parser_all_passing() {
+        let input = "PHPUnit 10.5.10\n\n....   4 / 4 (100%)\n\nTime: 00:00.010, Memory: 6.00 MB\n\nOK (4 tests, 9 assertions)\n";
+        let result = PhpunitParser::parse(input).unwrap();
+        assert_eq!((result.total, result.passed, result.failed), (4, 4, 0));
+        assert_eq!(result.duration_ms, Some(10));
+    }
+}
@@ -0,0 +1,426 @@
+use crate::parser::{
+    emit_passthrough_warning, truncate_output, Dependency, DependencyState, FormatMode,
+    OutputParser, ParseResult, TokenFormatter,
+};
+use crate::python_env::{self, LockSnapshot};
+use crate::tracking;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::ffi::OsString;
+use std::process::Command;
+
+/// Package names listed per operation before collapsing.
+const MAX_PACKAGES_SHOWN: usize = 10;
+/// Lines kept from errors and unrecognized output.
+const MAX_OTHER_LINES: usize = 30;
+/// Packages listed by plain `poetry show` before collapsing.
+const MAX_SHOW_LISTED: usize = 50;
+
+lazy_static::lazy_static! {
+    // "Package operations: 3 installs, 1 update, 1 removal"
+    static ref OPERATIONS: Regex = Regex::new(r"^Package operations: (.+)$").unwrap();
+    // "- Updating requests (2.31.0 -> 2.32.3)", "• Installing idna (3.6): Downloading..."
+    static ref OPERATION: Regex = Regex::new(
+        r"^[-•] (Installing|Updating|Downgrading|Removing) (\S+) \(([^)]+)\)"
+    ).unwrap();
+    // "├── certifi >=2017.4.17", "│   └── idna >=2.5"
+    static ref TREE_BRANCH: Regex = Regex::new(r"^((?:│   |    )*)[├└]── ").unwrap();
+}
+
+#[derive(Debug, Clone)]
+pub enum PoetryCommand {
+    Install,
+    Lock,
+    Show { depth: usize },
+    Run,
+}
+
+pub fn run(cmd: PoetryCommand, args: &[String], verbose: u8) -> Result<()> {
+    match cmd {
+        PoetryCommand::Install => run_project("install", args, verbose),
+        PoetryCommand::Lock => run_project("lock", args, verbose),
+        PoetryCommand::Show { depth } => run_show(depth, args, verbose),
+        PoetryCommand::Run => python_env::run_tool("poetry", args, verbose),
+    }
+}
+
+/// install/lock: strip resolution progress, report package operations and
+/// lockfile changes.
+fn run_project(subcommand: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: poetry {} {}", subcommand, args.join(" "));
+    }
+
+    let lock = LockSnapshot::take("poetry.lock");
+    let output = Command::new("poetry")
+        .arg(subcommand)
+        .args(args)
+        .output()
+        .with_context(|| {
+            format!(
+                "Failed to run poetry {}. Is Poetry installed? Try: pipx install poetry",
+                subcommand
+            )
+        })?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter_poetry_output(subcommand, &raw, output.status.success(), &lock.changes());
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("poetry {} {}", subcommand, args.join(" ")),
+        &format!("rtk poetry {} {}", subcommand, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+fn filter_poetry_output(
+    subcommand: &str,
+    output: &str,
+    success: bool,
+    lock_changes: &[String],
+) -> String {
+    let mut summary: Option<String> = None;
+    let mut installed: Vec<String> = Vec::new();
+    let mut updated: Vec<String> = Vec::new();
+    let mut removed: Vec<String> = Vec::new();
+    let mut other: Vec<&str> = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(caps) = OPERATIONS.captures(trimmed) {
+            summary = Some(caps[1].to_string());
+            continue;
+        }
+        if let Some(caps) = OPERATION.captures(trimmed) {
+            let entry = format!("{} {}", &caps[2], caps[3].replace(" -> ", " → "));
+            let list = match &caps[1] {
+                "Installing" => &mut installed,
+                "Removing" => &mut removed,
+                _ => &mut updated,
+            };
+            if !list.contains(&entry) {
+                list.push(entry);
+            }
+            continue;
+        }
+        if trimmed.starts_with("No dependencies to install or update") {
+            summary = Some("nothing to install or update".to_string());
+            continue;
+        }
+        if trimmed.starts_with("Installing dependencies from lock file")
+            || trimmed.starts_with("Updating dependencies")
+            || trimmed.starts_with("Resolving dependencies")
+            || trimmed.starts_with("Writing lock file")
+            || trimmed.starts_with("Installing the current project")
+            || trimmed.starts_with("Creating virtualenv")
+            || trimmed.starts_with("Using virtualenv")
+        {
+            continue;
+        }
+        other.push(trimmed);
+    }
+
+    let status = if success { "✓" } else { "❌" };
+    let mut lines = vec![match &summary {
+        Some(s) => format!("{} poetry {}: {}", status, subcommand, s),
+        None if success => format!("{} poetry {}", status, subcommand),
+        None => format!("{} poetry {} failed", status, subcommand),
+    }];
+
+    // The lockfile summary already names the packages when both changed
+    if lock_changes.is_empty() {
+        for (marker, list) in [("+", &installed), ("~", &updated), ("-", &removed)] {
+            for entry in list.iter().take(MAX_PACKAGES_SHOWN) {
+                lines.push(format!("  {} {}", marker, entry));
+            }
+            if list.len() > MAX_PACKAGES_SHOWN {
+                lines.push(format!("  ... +{} more", list.len() - MAX_PACKAGES_SHOWN));
+            }
+        }
+    }
+    lines.extend(lock_changes.iter().cloned());
+    for line in other.iter().take(MAX_OTHER_LINES) {
+        lines.push(format!("  {}", line));
+    }
+    if other.len() > MAX_OTHER_LINES {
+        lines.push(format!(
+            "  ... +{} more lines",
+            other.len() - MAX_OTHER_LINES
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn run_show(depth: usize, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let (depth, args) = python_env::take_depth(depth, args);
+
+    if verbose > 0 {
+        eprintln!("Running: poetry show {}", args.join(" "));
+    }
+
+    let output = Command::new("poetry")
+        .arg("show")
+        .args(&args)
+        .output()
+        .context("Failed to run poetry show")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    if !output.status.success() {
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+
+    let has = |flag: &str, short: &str| args.iter().any(|a| a == flag || a == short);
+    let filtered = if has("--tree", "--tree") {
+        prune_tree(&stdout, depth)
+    } else if has("--outdated", "-o") {
+        match PoetryOutdatedParser::parse(&stdout) {
+            ParseResult::Full(data) | ParseResult::Degraded(data, _) => {
+                data.format(FormatMode::from_verbosity(verbose))
+            }
+            ParseResult::Passthrough(truncated) => {
+                emit_passthrough_warning("poetry show --outdated", "No package lines found");
+                truncated
+            }
+        }
+    } else if args.iter().any(|a| !a.starts_with('-')) {
+        // Details for one package are already short
+        stdout.trim().to_string()
+    } else {
+        filter_show_list(&stdout)
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("poetry show {}", args.join(" ")),
+        &format!("rtk poetry show {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Splits a `poetry show` row into name, "(!)" (not installed) marker and
+/// the remaining columns.
+fn show_columns(line: &str) -> Option<(&str, bool, Vec<&str>)> {
+    let mut cols = line.split_whitespace();
+    let name = cols.next()?;
+    let mut rest: Vec<&str> = cols.collect();
+    let missing = rest.first() == Some(&"(!)");
+    if missing {
+        rest.remove(0);
+    }
+    Some((name, missing, rest))
+}
+
+/// "name version" per package; descriptions dropped.
+fn filter_show_list(output: &str) -> String {
+    let mut packages: Vec<String> = Vec::new();
+    for line in output.lines() {
+        let Some((name, missing, rest)) = show_columns(line) else {
+            continue;
+        };
+        let Some(version) = rest.first() else {
+            continue;
+        };
+        let marker = if missing { " (not installed)" } else { "" };
+        packages.push(format!("{} {}{}", name, version, marker));
+    }
+
+    if packages.is_empty() {
+        return "No packages installed".to_string();
+    }
+    let mut lines = vec![format!("{} packages", packages.len())];
+    lines.extend(packages.iter().take(MAX_SHOW_LISTED).cloned());
+    if packages.len() > MAX_SHOW_LISTED {
+        lines.push(format!("... +{} more", packages.len() - MAX_SHOW_LISTED));
+    }
+    lines.join("\n")
+}
+
+/// Keep `poetry show --tree` down to `depth` levels below the top-level
+/// packages (0 = top-level only), without descriptions.
+fn prune_tree(output: &str, depth: usize) -> String {
+    let mut lines = Vec::new();
+    let mut hidden = 0;
+
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match TREE_BRANCH.captures(line) {
+            Some(caps) => {
+                let level = caps[1].chars().count() / 4 + 1;
+                if level <= depth {
+                    lines.push(line.trim_end().to_string());
+                } else {
+                    hidden += 1;
+                }
+            }
+            None => {
+                // "requests 2.32.3 Python HTTP for Humans."
+                let mut cols = line.split_whitespace();
+                let name = cols.next().unwrap_or_default();
+                let version = cols.next().unwrap_or_default();
+                lines.push(format!("{} {}", name, version).trim_end().to_string());
+            }
+        }
+    }
+
+    if hidden > 0 {
+        lines.push(format!(
+            "({} deeper requirements hidden, use --depth {})",
+            hidden,
+            depth + 1
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Parser for `poetry show --outdated`: "name current latest description"
+pub struct PoetryOutdatedParser;
+
+impl OutputParser for PoetryOutdatedParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        let mut dependencies = Vec::new();
+        for line in input.lines() {
+            let Some((name, _, rest)) = show_columns(line) else {
+                continue;
+            };
+            let (Some(current), Some(latest)) = (rest.first(), rest.get(1)) else {
+                continue;
+            };
+            if !current.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+            dependencies.push(Dependency {
+                name: name.to_string(),
+                current_version: current.to_string(),
+                latest_version: Some(latest.to_string()),
+                wanted_version: None,
+                dev_dependency: false,
+            });
+        }
+
+        if dependencies.is_empty() && !input.trim().is_empty() {
+            return ParseResult::Passthrough(truncate_output(input.trim(), 500));
+        }
+        ParseResult::Full(DependencyState {
+            total_packages: dependencies.len(),
+            outdated_count: dependencies.len(),
+            dependencies,
+        })
+    }
+}
+
+/// Runs an unsupported poetry subcommand by passing it through directly
+pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("poetry passthrough: {:?}", args);
+    }
+    let status = Command::new("poetry")
+        .args(args)
+        .status()
+        .context("Failed to run poetry")?;
+
+    let args_str = tracking::args_display(args);
+    timer.track_passthrough(
+        &format!("poetry {}", args_str),
+        &format!("rtk poetry {} (passthrough)", args_str),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_poetry_install() {
+        let input = include_str!("../tests/fixtures/poetry_install.txt");
+        assert_eq!(
+            filter_poetry_output("install", input, true, &[]),
+            "\
+✓ poetry install: 3 installs, 1 update, 1 removal
+  + certifi 2024.2.2
+  + idna 3.6
+  + urllib3 2.2.1
+  ~ requests 2.31.0 → 2.32.3
+  - chardet 5.2.0"
+        );
+    }
+
+    #[test]
+    fn test_prune_tree() {
+        let input = include_str!("../tests/fixtures/poetry_show_tree.txt");
+        assert_eq!(
+            prune_tree(input, 0),
+            "\
+pytest 8.1.1
+requests 2.32.3
+(8 deeper requirements hidden, use --depth 1)"
+        );
+        assert_eq!(
+            prune_tree(input, 1),
+            "\
+pytest 8.1.1
+├── colorama *
+├── iniconfig *
+├── packaging *
+└── pluggy >=1.4,<2.0
+requests 2.32.3
+├── certifi >=2017.4.17
+├── idna >=2.5,<4
+└── urllib3 >=1.21.1,<3
+(1 deeper requirements hidden, use --depth 2)"
+        );
+    }
+
+    #[test]
+    fn test_poetry_show_lists() {
+        let input = "\
+certifi            2024.2.2  Python package for providing Mozilla's CA Bundle.
+requests           2.31.0    Python HTTP for Humans.
+urllib3        (!) 2.2.1     HTTP library with thread-safe connection pooling.
+";
+        assert_eq!(
+            filter_show_list(input),
+            "3 packages\ncertifi 2024.2.2\nrequests 2.31.0\nurllib3 2.2.1 (not installed)"
+        );
+
+        let outdated = "requests 2.31.0 2.32.3 Python HTTP for Humans.\n";
+        let data = PoetryOutdatedParser::parse(outdated).unwrap();
+        assert_eq!(
+            data.format(FormatMode::Compact),
+            "1 outdated packages (of 1)\nrequests: 2.31.0 → 2.32.3"
+        );
+    }
+}
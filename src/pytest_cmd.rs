@@ -12,10 +12,8 @@ enum ParseState {
 }
 
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
-
     // Try to detect pytest command (could be "pytest", "python -m pytest", etc.)
-    let mut cmd = if which_command("pytest").is_some() {
+    let cmd = if which_command("pytest").is_some() {
         Command::new("pytest")
     } else {
         // Fallback to python -m pytest
@@ -24,6 +22,13 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         c
     };
 
+    run_with(cmd, args, verbose)
+}
+
+/// Run pytest through an already-built command (e.g. `uv run pytest`).
+pub(crate) fn run_with(mut cmd: Command, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
     // Force short traceback and quiet mode for compact output
     let has_tb_flag = args.iter().any(|a| a.starts_with("--tb"));
     let has_quiet_flag = args.iter().any(|a| a == "-q" || a == "--quiet");
@@ -88,7 +93,7 @@ fn which_command(cmd: &str) -> Option<String> {
 }
 
 /// Parse pytest output using state machine
-fn filter_pytest_output(output: &str) -> String {
+pub(crate) fn filter_pytest_output(output: &str) -> String {
     let mut state = ParseState::Header;
     let mut test_files: Vec<String> = Vec::new();
     let mut failures: Vec<String> = Vec::new();
@@ -0,0 +1,177 @@
+//! Shared pieces of the uv and Poetry filters: lockfile snapshots summarized
+//! through `lockfile_diff`, and `run` forwarding to the pytest, ruff and mypy
+//! filters when one of them is the wrapped command.
+
+use crate::lockfile_diff::{self, LockKind, SpecialFile};
+use crate::{lint_cmd, pytest_cmd, ruff_cmd, tracking};
+use anyhow::{Context, Result};
+use std::process::Command;
+
+/// `uv run` / `poetry run` options that consume the following argument.
+const VALUE_FLAGS: &[&str] = &[
+    "--with",
+    "--with-editable",
+    "--with-requirements",
+    "--python",
+    "-p",
+    "--package",
+    "--extra",
+    "--group",
+    "--only-group",
+    "--env-file",
+    "--index",
+    "--directory",
+    "--project",
+    "-C",
+    "-P",
+];
+
+/// Lockfile contents captured before a command that may rewrite it.
+pub(crate) struct LockSnapshot {
+    path: &'static str,
+    before: Option<String>,
+}
+
+impl LockSnapshot {
+    pub(crate) fn take(path: &'static str) -> Self {
+        Self {
+            path,
+            before: std::fs::read_to_string(path).ok(),
+        }
+    }
+
+    /// Added, removed and bumped packages since the snapshot; empty when the
+    /// lockfile was not touched.
+    pub(crate) fn changes(&self) -> Vec<String> {
+        let Ok(after) = std::fs::read_to_string(self.path) else {
+            return Vec::new();
+        };
+        if self.before.as_deref() == Some(after.as_str()) {
+            return Vec::new();
+        }
+        lockfile_diff::summarize_contents(
+            SpecialFile::Lockfile(LockKind::Toml),
+            self.before.as_deref().unwrap_or(""),
+            &after,
+        )
+    }
+}
+
+/// Pulls a `--depth N` / `-d N` / `--depth=N` that clap left in the trailing
+/// arguments, so it is applied by rtk instead of being forwarded.
+pub(crate) fn take_depth(depth: usize, args: &[String]) -> (usize, Vec<String>) {
+    let mut depth = depth;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = match arg.as_str() {
+            "--depth" | "-d" => iter.next().cloned(),
+            a => a.strip_prefix("--depth=").map(String::from),
+        };
+        match value.and_then(|v| v.parse().ok()) {
+            Some(n) => depth = n,
+            None if arg == "--depth" || arg == "-d" || arg.starts_with("--depth=") => {}
+            None => rest.push(arg.clone()),
+        }
+    }
+    (depth, rest)
+}
+
+/// Index of the wrapped command in `run` arguments, skipping runner options.
+fn wrapped_command_index(args: &[String]) -> Option<usize> {
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i].as_str();
+        if arg == "--" {
+            i += 1;
+            continue;
+        }
+        if !arg.starts_with('-') {
+            return Some(i);
+        }
+        i += if VALUE_FLAGS.contains(&arg) { 2 } else { 1 };
+    }
+    None
+}
+
+/// Runs `<runner> run ...`, routing pytest, ruff and mypy through their
+/// filters and passing anything else through untouched.
+pub(crate) fn run_tool(runner: &str, args: &[String], verbose: u8) -> Result<()> {
+    let Some(idx) = wrapped_command_index(args) else {
+        return run_passthrough(runner, args, verbose);
+    };
+    let (runner_args, wrapped) = args.split_at(idx);
+    let base = |tool: &[&str]| {
+        let mut cmd = Command::new(runner);
+        cmd.arg("run").args(runner_args).args(tool);
+        cmd
+    };
+
+    let module = wrapped.get(1).map(|s| s.as_str()) == Some("-m");
+    match (wrapped[0].as_str(), wrapped.get(2).map(|s| s.as_str())) {
+        ("pytest", _) => pytest_cmd::run_with(base(&["pytest"]), &wrapped[1..], verbose),
+        (python @ ("python" | "python3"), Some("pytest")) if module => {
+            pytest_cmd::run_with(base(&[python, "-m", "pytest"]), &wrapped[3..], verbose)
+        }
+        ("ruff", _) => ruff_cmd::run_with(base(&["ruff"]), &wrapped[1..], verbose),
+        ("mypy", _) => lint_cmd::run_with(base(&["mypy"]), wrapped, verbose),
+        _ => run_passthrough(runner, args, verbose),
+    }
+}
+
+fn run_passthrough(runner: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: {} run {}", runner, args.join(" "));
+    }
+
+    let status = Command::new(runner)
+        .arg("run")
+        .args(args)
+        .status()
+        .with_context(|| format!("Failed to run {} run", runner))?;
+
+    timer.track_passthrough(
+        &format!("{} run {}", runner, args.join(" ")),
+        &format!("rtk {} run {} (passthrough)", runner, args.join(" ")),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_wrapped_command_index() {
+        assert_eq!(wrapped_command_index(&args("pytest -x")), Some(0));
+        assert_eq!(
+            wrapped_command_index(&args("--with ruff --frozen python -m pytest")),
+            Some(3)
+        );
+        assert_eq!(wrapped_command_index(&args("-- mypy src")), Some(1));
+        assert_eq!(wrapped_command_index(&args("--frozen")), None);
+    }
+
+    #[test]
+    fn test_take_depth() {
+        assert_eq!(take_depth(1, &args("--tree")), (1, args("--tree")));
+        assert_eq!(
+            take_depth(1, &args("--tree --depth 0")),
+            (0, args("--tree"))
+        );
+        assert_eq!(
+            take_depth(1, &args("--frozen --depth=3")),
+            (3, args("--frozen"))
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::parser::{
+    emit_passthrough_warning, truncate_output, FormatMode, OutputParser, ParseResult, TestFailure,
+    TestResult, TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::{bundle_exec, truncate};
+use anyhow::{Context, Result};
+use regex::Regex;
+
+/// Application backtrace lines kept per error.
+const MAX_BACKTRACE: usize = 3;
+
+lazy_static::lazy_static! {
+    // "14 runs, 30 assertions, 1 failures, 1 errors, 1 skips"
+    static ref SUMMARY_LINE: Regex = Regex::new(
+        r"^(\d+) (?:runs|tests), \d+ assertions, (\d+) failures, (\d+) errors, (\d+) skips"
+    ).unwrap();
+    static ref FINISHED_LINE: Regex = Regex::new(r"^Finished in ([\d.]+)s").unwrap();
+    static ref FAILURE_HEADER: Regex = Regex::new(r"^\s*\d+\) (Failure|Error):$").unwrap();
+    // "UserTest#test_downcases_email [/app/test/models/user_test.rb:12]:"
+    static ref FAILURE_NAME: Regex = Regex::new(r"^(\S+?)(?: \[(.+)\])?:$").unwrap();
+}
+
+/// Runs rake, summarizing Minitest results when the task ran tests.
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = bundle_exec("rake");
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: rake {}", args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run rake. Is it installed? Try: gem install rake")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = match MinitestParser::parse(&raw) {
+        ParseResult::Full(result) | ParseResult::Degraded(result, _) => {
+            result.format(FormatMode::from_verbosity(verbose))
+        }
+        ParseResult::Passthrough(truncated) => {
+            if verbose > 0 {
+                emit_passthrough_warning("rake", "No Minitest summary found");
+            }
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("rake {}", args.join(" ")),
+        &format!("rtk rake {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Parses Minitest's default reporter output.
+pub struct MinitestParser;
+
+impl OutputParser for MinitestParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        let lines: Vec<&str> = input.lines().collect();
+
+        // Several test tasks (e.g. test + test:system) print one summary each
+        let mut result = TestResult {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+        let mut found_summary = false;
+
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            i += 1;
+
+            if let Some(caps) = SUMMARY_LINE.captures(line) {
+                found_summary = true;
+                let n = |idx: usize| caps[idx].parse::<usize>().unwrap_or(0);
+                let (total, failures, errors, skips) = (n(1), n(2), n(3), n(4));
+                result.total += total;
+                result.failed += failures + errors;
+                result.skipped += skips;
+                result.passed += total.saturating_sub(failures + errors + skips);
+                continue;
+            }
+
+            if let Some(caps) = FINISHED_LINE.captures(line) {
+                let ms = (caps[1].parse::<f64>().unwrap_or(0.0) * 1000.0) as u64;
+                result.duration_ms = Some(result.duration_ms.unwrap_or(0) + ms);
+                continue;
+            }
+
+            if !FAILURE_HEADER.is_match(line) {
+                continue;
+            }
+            let Some(name_caps) = lines.get(i).and_then(|l| FAILURE_NAME.captures(l)) else {
+                continue;
+            };
+            i += 1;
+
+            let mut message = Vec::new();
+            let mut backtrace = Vec::new();
+            while let Some(next) = lines.get(i) {
+                if next.trim().is_empty() || FAILURE_HEADER.is_match(next) {
+                    break;
+                }
+                i += 1;
+                // Backtrace lines are indented "path:line:in `method'"
+                if next.starts_with("    ") && next.contains(":in ") {
+                    let frame = next.trim();
+                    if !frame.contains("/gems/") && backtrace.len() < MAX_BACKTRACE {
+                        backtrace.push(frame.to_string());
+                    }
+                } else {
+                    message.push(next.trim());
+                }
+            }
+
+            let location = name_caps.get(2).map(|m| m.as_str().to_string());
+            result.failures.push(TestFailure {
+                test_name: name_caps[1].to_string(),
+                file_path: location
+                    .or_else(|| {
+                        backtrace
+                            .first()
+                            .map(|f| f.split(":in ").next().unwrap_or(f).to_string())
+                    })
+                    .unwrap_or_default(),
+                error_message: truncate(&message.join(" "), 200),
+                stack_trace: if backtrace.is_empty() {
+                    None
+                } else {
+                    Some(backtrace.join("\n"))
+                },
+            });
+        }
+
+        if found_summary {
+            ParseResult::Full(result)
+        } else {
+            ParseResult::Passthrough(truncate_output(input.trim(), 2000))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_minitest_output() {
+        let input = include_str!("../tests/fixtures/minitest.txt");
+        let result = MinitestParser::parse(input).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (14, 11, 2, 1)
+        );
+        assert_eq!(result.duration_ms, Some(412));
+
+        let failure = &result.failures[0];
+        assert_eq!(failure.test_name, "UserTest#test_downcases_email");
+        assert_eq!(failure.file_path, "/app/test/models/user_test.rb:12");
+        assert_eq!(
+            failure.error_message,
+            "Expected: \"bob@example.com\" Actual: \"Bob@Example.com\""
+        );
+
+        let error = &result.failures[1];
+        assert_eq!(error.test_name, "BillingTest#test_charge");
+        assert_eq!(error.file_path, "app/services/billing.rb:22");
+        assert_eq!(
+            error.error_message,
+            "NoMethodError: undefined method `amount' for nil:NilClass"
+        );
+        assert_eq!(error.stack_trace.as_deref().unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_non_test_task_passes_through() {
+        let result = MinitestParser::parse("rake aborted!\nDon't know how to build task 'x'");
+        assert!(matches!(result, ParseResult::Passthrough(_)));
+    }
+}
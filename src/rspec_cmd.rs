@@ -0,0 +1,314 @@
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, FormatMode, OutputParser,
+    ParseResult, TestFailure, TestResult, TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::{bundle_exec, truncate};
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+
+/// Application backtrace lines kept per failure.
+const MAX_BACKTRACE: usize = 3;
+
+lazy_static::lazy_static! {
+    // "4 examples, 2 failures, 1 pending"
+    static ref SUMMARY_LINE: Regex = Regex::new(
+        r"(\d+) examples?, (\d+) failures?(?:, (\d+) pending)?"
+    ).unwrap();
+    // "rspec ./spec/models/user_spec.rb:9 # User downcases the email"
+    static ref RERUN_LINE: Regex = Regex::new(r"^rspec (\S+) # (.+)$").unwrap();
+}
+
+#[derive(Debug, Deserialize)]
+struct RspecOutput {
+    examples: Vec<RspecExample>,
+    summary: RspecSummary,
+    #[serde(default)]
+    messages: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RspecExample {
+    full_description: String,
+    status: String,
+    file_path: String,
+    line_number: usize,
+    exception: Option<RspecException>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RspecException {
+    class: String,
+    message: String,
+    #[serde(default)]
+    backtrace: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RspecSummary {
+    duration: f64,
+    example_count: usize,
+    failure_count: usize,
+    pending_count: usize,
+    #[serde(default)]
+    errors_outside_of_examples_count: usize,
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = bundle_exec("rspec");
+    // JSON to stdout unless the caller picked a formatter
+    if !args
+        .iter()
+        .any(|a| a == "-f" || a.starts_with("--format") || a.starts_with("-f"))
+    {
+        cmd.arg("--format").arg("json");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: rspec {}", args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run rspec. Is it installed? Try: gem install rspec")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = match RspecParser::parse(&stdout) {
+        ParseResult::Full(result) => result.format(FormatMode::from_verbosity(verbose)),
+        ParseResult::Degraded(result, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("rspec", &warnings.join(", "));
+            }
+            result.format(FormatMode::from_verbosity(verbose))
+        }
+        ParseResult::Passthrough(_) => {
+            emit_passthrough_warning("rspec", "No JSON or summary line found");
+            truncate_output(raw.trim(), 2000)
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("rspec {}", args.join(" ")),
+        &format!("rtk rspec {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Drops gem and Ruby stdlib frames, keeping the project's own.
+fn app_backtrace(backtrace: &[String]) -> Vec<String> {
+    backtrace
+        .iter()
+        .filter(|l| !l.contains("/gems/") && !l.contains("/lib/ruby/"))
+        .take(MAX_BACKTRACE)
+        .map(|l| l.trim_start_matches("./").to_string())
+        .collect()
+}
+
+/// Parses `rspec --format json`; falls back to the text summary and rerun lines.
+pub struct RspecParser;
+
+impl OutputParser for RspecParser {
+    type Output = TestResult;
+
+    fn parse(input: &str) -> ParseResult<TestResult> {
+        // Output printed by the specs themselves may precede the JSON document
+        let json = input
+            .find("{\"version\"")
+            .map(|start| &input[start..])
+            .unwrap_or(input);
+
+        match serde_json::from_str::<RspecOutput>(json.trim()) {
+            Ok(report) => ParseResult::Full(from_json(report)),
+            Err(e) => match extract_text(input) {
+                Some(result) => {
+                    ParseResult::Degraded(result, vec![format!("JSON parse failed: {}", e)])
+                }
+                None => ParseResult::Passthrough(truncate_output(input, 500)),
+            },
+        }
+    }
+}
+
+fn from_json(report: RspecOutput) -> TestResult {
+    let mut failures: Vec<TestFailure> = report
+        .examples
+        .iter()
+        .filter(|e| e.status == "failed")
+        .map(|example| {
+            let (message, backtrace) = match &example.exception {
+                Some(exc) => {
+                    let lines: Vec<&str> = exc
+                        .message
+                        .lines()
+                        .map(str::trim)
+                        .filter(|l| !l.is_empty())
+                        .collect();
+                    let mut message = truncate(&lines.join(" "), 200);
+                    if exc.class != "RSpec::Expectations::ExpectationNotMetError" {
+                        message = format!("{}: {}", exc.class, message);
+                    }
+                    let backtrace = app_backtrace(exc.backtrace.as_deref().unwrap_or(&[]));
+                    (message, backtrace)
+                }
+                None => (String::new(), Vec::new()),
+            };
+            TestFailure {
+                test_name: example.full_description.clone(),
+                file_path: format!(
+                    "{}:{}",
+                    example.file_path.trim_start_matches("./"),
+                    example.line_number
+                ),
+                error_message: message,
+                stack_trace: if backtrace.is_empty() {
+                    None
+                } else {
+                    Some(backtrace.join("\n"))
+                },
+            }
+        })
+        .collect();
+
+    // Load errors (syntax error in a spec file, missing constant) run no examples
+    if report.summary.errors_outside_of_examples_count > 0 {
+        for message in &report.messages {
+            let lines: Vec<&str> = message
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with("# "))
+                .collect();
+            failures.push(TestFailure {
+                test_name: "error outside of examples".to_string(),
+                file_path: String::new(),
+                error_message: truncate(&lines.join(" "), 300),
+                stack_trace: None,
+            });
+        }
+    }
+
+    let summary = &report.summary;
+    TestResult {
+        total: summary.example_count,
+        passed: summary
+            .example_count
+            .saturating_sub(summary.failure_count + summary.pending_count),
+        failed: summary.failure_count + summary.errors_outside_of_examples_count,
+        skipped: summary.pending_count,
+        duration_ms: Some((summary.duration * 1000.0) as u64),
+        failures,
+    }
+}
+
+/// Tier 2: progress/documentation formatter output.
+fn extract_text(output: &str) -> Option<TestResult> {
+    let caps = output
+        .lines()
+        .rev()
+        .find_map(|l| SUMMARY_LINE.captures(l))?;
+    let n = |idx: usize| {
+        caps.get(idx)
+            .and_then(|m| m.as_str().parse::<usize>().ok())
+            .unwrap_or(0)
+    };
+    let (total, failed, pending) = (n(1), n(2), n(3));
+
+    let failures = output
+        .lines()
+        .filter_map(|l| RERUN_LINE.captures(l.trim()))
+        .map(|caps| TestFailure {
+            test_name: caps[2].to_string(),
+            file_path: caps[1].trim_start_matches("./").to_string(),
+            error_message: String::new(),
+            stack_trace: None,
+        })
+        .collect();
+
+    Some(TestResult {
+        total,
+        passed: total.saturating_sub(failed + pending),
+        failed,
+        skipped: pending,
+        duration_ms: None,
+        failures,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RSPEC_JSON: &str = include_str!("../tests/fixtures/rspec.json");
+
+    #[test]
+    fn test_parse_rspec_json() {
+        let result = RspecParser::parse(RSPEC_JSON).unwrap();
+        assert_eq!(
+            (result.total, result.passed, result.failed, result.skipped),
+            (4, 1, 2, 1)
+        );
+        assert_eq!(result.duration_ms, Some(412));
+
+        let first = &result.failures[0];
+        assert_eq!(first.test_name, "User downcases the email");
+        assert_eq!(first.file_path, "spec/models/user_spec.rb:9");
+        assert_eq!(
+            first.error_message,
+            "expected: \"bob@example.com\" got: \"Bob@Example.com\" (compared using ==)"
+        );
+        assert_eq!(
+            first.stack_trace.as_deref(),
+            Some("spec/models/user_spec.rb:11:in `block (2 levels) in <top (required)>'")
+        );
+
+        let second = &result.failures[1];
+        assert_eq!(
+            second.error_message,
+            "NoMethodError: undefined method `amount' for nil:NilClass"
+        );
+        assert!(second
+            .stack_trace
+            .as_deref()
+            .unwrap()
+            .starts_with("app/services/billing.rb:22"));
+    }
+
+    #[test]
+    fn test_parse_rspec_json_after_puts_noise() {
+        let input = format!("debug: charging card\n{}", RSPEC_JSON);
+        assert!(matches!(RspecParser::parse(&input), ParseResult::Full(_)));
+    }
+
+    #[test]
+    fn test_parse_rspec_text_fallback() {
+        let input = "\
+..F*
+
+Finished in 0.41 seconds (files took 1.2 seconds to load)
+4 examples, 1 failure, 1 pending
+
+Failed examples:
+
+rspec ./spec/models/user_spec.rb:9 # User downcases the email
+";
+        match RspecParser::parse(input) {
+            ParseResult::Degraded(result, _) => {
+                assert_eq!((result.total, result.passed, result.failed), (4, 2, 1));
+                assert_eq!(result.failures[0].file_path, "spec/models/user_spec.rb:9");
+            }
+            other => panic!("expected Degraded, got {:?}", other.tier()),
+        }
+    }
+}
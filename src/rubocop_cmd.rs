@@ -0,0 +1,278 @@
+use crate::tracking;
+use crate::utils::{bundle_exec, truncate};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+struct RubocopOutput {
+    files: Vec<RubocopFile>,
+    summary: RubocopSummary,
+}
+
+#[derive(Debug, Deserialize)]
+struct RubocopFile {
+    path: String,
+    offenses: Vec<RubocopOffense>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RubocopOffense {
+    severity: String,
+    message: String,
+    cop_name: String,
+    #[serde(default)]
+    corrected: bool,
+    #[serde(default)]
+    correctable: bool,
+    location: RubocopLocation,
+}
+
+#[derive(Debug, Deserialize)]
+struct RubocopLocation {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct RubocopSummary {
+    inspected_file_count: usize,
+}
+
+pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let user_format = args
+        .iter()
+        .any(|a| a == "-f" || a.starts_with("--format") || a.starts_with("--format="));
+
+    let mut cmd = bundle_exec("rubocop");
+    if !user_format {
+        cmd.arg("--format").arg("json");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: rubocop {}", args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run rubocop. Is it installed? Try: gem install rubocop")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = if user_format {
+        raw.trim().to_string()
+    } else {
+        filter_rubocop_json(&stdout)
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("rubocop {}", args.join(" ")),
+        &format!("rtk rubocop {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Filter RuboCop JSON output - group by cop and file
+fn filter_rubocop_json(output: &str) -> String {
+    let report: RubocopOutput = match serde_json::from_str(output.trim()) {
+        Ok(r) => r,
+        Err(e) => {
+            // Fallback if JSON parsing fails (e.g. config error printed instead)
+            return format!(
+                "RuboCop output (JSON parse failed: {})\n{}",
+                e,
+                truncate(output, 500)
+            );
+        }
+    };
+
+    // Offenses fixed by -a/-A are reported too; only the remaining ones matter
+    let corrected: usize = report
+        .files
+        .iter()
+        .flat_map(|f| &f.offenses)
+        .filter(|o| o.corrected)
+        .count();
+    let remaining: Vec<(&str, &RubocopOffense)> = report
+        .files
+        .iter()
+        .flat_map(|f| f.offenses.iter().map(move |o| (f.path.as_str(), o)))
+        .filter(|(_, o)| !o.corrected)
+        .collect();
+
+    if remaining.is_empty() {
+        let mut msg = format!(
+            "✓ RuboCop: No offenses ({} files inspected)",
+            report.summary.inspected_file_count
+        );
+        if corrected > 0 {
+            msg.push_str(&format!(", {} corrected", corrected));
+        }
+        return msg;
+    }
+
+    let mut by_severity: HashMap<&str, usize> = HashMap::new();
+    let mut by_cop: HashMap<&str, usize> = HashMap::new();
+    let mut by_file: HashMap<&str, Vec<&RubocopOffense>> = HashMap::new();
+    for (path, offense) in &remaining {
+        *by_severity.entry(offense.severity.as_str()).or_insert(0) += 1;
+        *by_cop.entry(offense.cop_name.as_str()).or_insert(0) += 1;
+        by_file.entry(path).or_default().push(offense);
+    }
+    let correctable = remaining.iter().filter(|(_, o)| o.correctable).count();
+
+    let mut result = String::new();
+    let severities: Vec<String> = [
+        "fatal",
+        "error",
+        "warning",
+        "convention",
+        "refactor",
+        "info",
+    ]
+    .iter()
+    .filter_map(|s| by_severity.get(s).map(|n| format!("{} {}", n, s)))
+    .collect();
+    result.push_str(&format!(
+        "RuboCop: {} offenses in {} files ({})\n",
+        remaining.len(),
+        by_file.len(),
+        severities.join(", ")
+    ));
+    result.push_str("═══════════════════════════════════════\n");
+
+    // Errors and fatals break the code: list them individually
+    let broken: Vec<&(&str, &RubocopOffense)> = remaining
+        .iter()
+        .filter(|(_, o)| o.severity == "error" || o.severity == "fatal")
+        .collect();
+    if !broken.is_empty() {
+        result.push_str("Errors:\n");
+        for (path, offense) in &broken {
+            result.push_str(&format!(
+                "  {}:{}:{} {}\n",
+                path,
+                offense.location.line,
+                offense.location.column,
+                truncate(&strip_cop_prefix(offense), 120)
+            ));
+        }
+        result.push('\n');
+    }
+
+    // Show top cops
+    let mut cop_counts: Vec<_> = by_cop.iter().collect();
+    cop_counts.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    result.push_str("Top cops:\n");
+    for (cop, count) in cop_counts.iter().take(10) {
+        result.push_str(&format!("  {} ({}x)\n", cop, count));
+    }
+    result.push('\n');
+
+    // Show top files with most offenses
+    let mut file_counts: Vec<_> = by_file.iter().collect();
+    file_counts.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(b.0)));
+    result.push_str("Top files:\n");
+    for (path, offenses) in file_counts.iter().take(10) {
+        result.push_str(&format!("  {} ({} offenses)\n", path, offenses.len()));
+
+        // Show top 3 cops in this file with their lines
+        let mut cops: Vec<&str> = Vec::new();
+        let mut lines_by_cop: HashMap<&str, Vec<usize>> = HashMap::new();
+        for offense in offenses.iter() {
+            if !lines_by_cop.contains_key(offense.cop_name.as_str()) {
+                cops.push(&offense.cop_name);
+            }
+            lines_by_cop
+                .entry(&offense.cop_name)
+                .or_default()
+                .push(offense.location.line);
+        }
+        cops.sort_by_key(|cop| std::cmp::Reverse(lines_by_cop[cop].len()));
+        for cop in cops.iter().take(3) {
+            let lines: Vec<String> = lines_by_cop[cop].iter().map(|l| l.to_string()).collect();
+            result.push_str(&format!("    {} (L{})\n", cop, lines.join(", ")));
+        }
+    }
+
+    if file_counts.len() > 10 {
+        result.push_str(&format!("\n... +{} more files\n", file_counts.len() - 10));
+    }
+
+    if correctable > 0 {
+        result.push_str(&format!(
+            "\n💡 {} autocorrectable (rtk rubocop -a)\n",
+            correctable
+        ));
+    }
+    if corrected > 0 {
+        result.push_str(&format!("✓ {} corrected\n", corrected));
+    }
+
+    result.trim().to_string()
+}
+
+/// RuboCop prefixes each message with its cop name
+fn strip_cop_prefix(offense: &RubocopOffense) -> String {
+    offense
+        .message
+        .strip_prefix(&format!("{}: ", offense.cop_name))
+        .unwrap_or(&offense.message)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_rubocop_json() {
+        let input = include_str!("../tests/fixtures/rubocop.json");
+        let result = filter_rubocop_json(input);
+        assert_eq!(
+            result,
+            "\
+RuboCop: 6 offenses in 2 files (1 error, 1 warning, 4 convention)
+═══════════════════════════════════════
+Errors:
+  app/services/billing.rb:40:1 unexpected token kEND
+
+Top cops:
+  Style/StringLiterals (3x)
+  Lint/Syntax (1x)
+  Lint/UselessAssignment (1x)
+  Metrics/MethodLength (1x)
+
+Top files:
+  app/models/user.rb (3 offenses)
+    Style/StringLiterals (L3, 7)
+    Lint/UselessAssignment (L12)
+  app/services/billing.rb (3 offenses)
+    Metrics/MethodLength (L20)
+    Style/StringLiterals (L24)
+    Lint/Syntax (L40)
+
+💡 4 autocorrectable (rtk rubocop -a)"
+        );
+    }
+
+    #[test]
+    fn test_filter_rubocop_all_corrected() {
+        let input = r#"{"files":[{"path":"a.rb","offenses":[{"severity":"convention","message":"Style/StringLiterals: x","cop_name":"Style/StringLiterals","corrected":true,"correctable":true,"location":{"line":1,"column":1}}]}],"summary":{"offense_count":1,"target_file_count":1,"inspected_file_count":1}}"#;
+        assert_eq!(
+            filter_rubocop_json(input),
+            "✓ RuboCop: No offenses (1 files inspected), 1 corrected"
+        );
+    }
+}
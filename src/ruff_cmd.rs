@@ -29,6 +29,11 @@ struct RuffDiagnostic {
 }
 
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    run_with(Command::new("ruff"), args, verbose)
+}
+
+/// Run ruff through an already-built command (e.g. `uv run ruff`).
+pub(crate) fn run_with(mut cmd: Command, args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     // Detect subcommand: check, format, or version
@@ -38,8 +43,6 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 
     let is_format = args.iter().any(|a| a == "format");
 
-    let mut cmd = Command::new("ruff");
-
     if is_check {
         // Force JSON output for check command
         if !args.contains(&"--output-format".to_string()) {
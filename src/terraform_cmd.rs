@@ -0,0 +1,1086 @@
+//! Terraform / OpenTofu output compression. `tofu` is CLI-compatible, so
+//! every entry point takes the binary to run.
+//!
+//! `plan` is run with `-out` and summarized from `show -json` of the saved
+//! plan: resources grouped by action, module and type, with only the changed
+//! attributes of updated/replaced resources (sensitive values masked).
+//! `apply`, `init` and `validate` drop the refresh/progress noise and keep
+//! results and diagnostics with their file:line.
+
+use crate::tracking;
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::process::Command;
+
+/// Resources listed per action group before collapsing.
+const MAX_RESOURCES_PER_ACTION: usize = 20;
+/// Changed attributes shown per updated/replaced resource.
+const MAX_ATTRS_PER_RESOURCE: usize = 8;
+/// Attribute values longer than this are truncated.
+const MAX_VALUE_LEN: usize = 40;
+
+/// Planned action for one resource, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum PlanAction {
+    Create,
+    Update,
+    Replace,
+    Destroy,
+}
+
+impl PlanAction {
+    /// Map a `change.actions` list; `None` for no-op and data source reads.
+    fn from_actions(actions: &[&str]) -> Option<Self> {
+        match actions {
+            ["create"] => Some(PlanAction::Create),
+            ["update"] => Some(PlanAction::Update),
+            ["delete"] => Some(PlanAction::Destroy),
+            ["delete", "create"] | ["create", "delete"] => Some(PlanAction::Replace),
+            _ => None,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            PlanAction::Create => "+",
+            PlanAction::Update => "~",
+            PlanAction::Replace => "-/+",
+            PlanAction::Destroy => "-",
+        }
+    }
+
+    fn verb(&self) -> &'static str {
+        match self {
+            PlanAction::Create => "create",
+            PlanAction::Update => "update",
+            PlanAction::Replace => "replace",
+            PlanAction::Destroy => "destroy",
+        }
+    }
+}
+
+/// One changed attribute of an updated or replaced resource.
+#[derive(Debug, PartialEq)]
+struct AttrChange {
+    path: String,
+    before: String,
+    after: String,
+    forces_replacement: bool,
+}
+
+#[derive(Debug)]
+struct ResourceChange {
+    address: String,
+    module: Option<String>,
+    resource_type: String,
+    action: PlanAction,
+    attrs: Vec<AttrChange>,
+}
+
+impl ResourceChange {
+    /// Address without the module prefix and resource type, e.g. `old[0]`.
+    fn short_name(&self) -> &str {
+        let local = match &self.module {
+            Some(module) => self
+                .address
+                .strip_prefix(module.as_str())
+                .map(|a| a.trim_start_matches('.'))
+                .unwrap_or(&self.address),
+            None => &self.address,
+        };
+        local
+            .strip_prefix(&self.resource_type)
+            .map(|a| a.trim_start_matches('.'))
+            .unwrap_or(local)
+    }
+}
+
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// Flatten nested objects/arrays into `a.b[0].c` → leaf value.
+fn flatten_value(value: &Value, prefix: &str, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, v) in map {
+                flatten_value(v, &join_path(prefix, key), out);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, v) in items.iter().enumerate() {
+                flatten_value(v, &format!("{}[{}]", prefix, i), out);
+            }
+        }
+        _ => {
+            if !prefix.is_empty() {
+                out.insert(prefix.to_string(), value.clone());
+            }
+        }
+    }
+}
+
+/// Paths marked `true` in a sensitivity/unknown mirror structure.
+fn flagged_paths(value: &Value, prefix: &str, out: &mut Vec<String>) {
+    match value {
+        Value::Bool(true) => out.push(prefix.to_string()),
+        Value::Object(map) => {
+            for (key, v) in map {
+                flagged_paths(v, &join_path(prefix, key), out);
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                flagged_paths(v, &format!("{}[{}]", prefix, i), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `path` equals or lies below one of `flagged` (`""` flags everything).
+fn is_flagged(path: &str, flagged: &[String]) -> bool {
+    flagged.iter().any(|f| {
+        f.is_empty()
+            || path == f
+            || (path.starts_with(f.as_str())
+                && matches!(path.as_bytes().get(f.len()), Some(b'.') | Some(b'[')))
+    })
+}
+
+fn render_value(value: Option<&Value>) -> String {
+    let text = match value {
+        None | Some(Value::Null) => "null".to_string(),
+        Some(Value::String(s)) => format!("\"{}\"", s),
+        Some(other) => other.to_string(),
+    };
+    crate::utils::truncate(&text, MAX_VALUE_LEN)
+}
+
+/// Attributes whose value differs between `before` and `after` of a change.
+fn changed_attrs(change: &Value) -> Vec<AttrChange> {
+    let mut before = BTreeMap::new();
+    let mut after = BTreeMap::new();
+    flatten_value(&change["before"], "", &mut before);
+    flatten_value(&change["after"], "", &mut after);
+
+    let mut sensitive = Vec::new();
+    flagged_paths(&change["before_sensitive"], "", &mut sensitive);
+    flagged_paths(&change["after_sensitive"], "", &mut sensitive);
+    let mut unknown = Vec::new();
+    flagged_paths(&change["after_unknown"], "", &mut unknown);
+
+    let replace_paths: Vec<String> = change["replace_paths"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|p| p.as_array())
+        .map(|steps| {
+            steps.iter().fold(String::new(), |acc, step| match step {
+                Value::Number(n) => format!("{}[{}]", acc, n),
+                Value::String(s) => join_path(&acc, s),
+                _ => acc,
+            })
+        })
+        .collect();
+
+    let mut paths: Vec<&String> = before.keys().chain(after.keys()).collect();
+    paths.extend(unknown.iter().filter(|p| !p.is_empty()));
+    paths.sort();
+    paths.dedup();
+
+    let mut attrs = Vec::new();
+    for path in paths {
+        let is_unknown = is_flagged(path, &unknown);
+        if !is_unknown && before.get(path) == after.get(path) {
+            continue;
+        }
+        let masked = is_flagged(path, &sensitive);
+        let before_text = if masked {
+            "(sensitive)".to_string()
+        } else {
+            render_value(before.get(path))
+        };
+        let after_text = if is_unknown {
+            "(known after apply)".to_string()
+        } else if masked {
+            "(sensitive)".to_string()
+        } else {
+            render_value(after.get(path))
+        };
+        // Computed attributes that were never set add nothing
+        if is_unknown && !before.contains_key(path) {
+            continue;
+        }
+        attrs.push(AttrChange {
+            forces_replacement: is_flagged(path, &replace_paths),
+            path: path.clone(),
+            before: before_text,
+            after: after_text,
+        });
+    }
+    attrs
+}
+
+fn parse_plan_json(json: &Value) -> Vec<ResourceChange> {
+    json["resource_changes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|rc| {
+            let actions: Vec<&str> = rc["change"]["actions"]
+                .as_array()?
+                .iter()
+                .filter_map(|a| a.as_str())
+                .collect();
+            let action = PlanAction::from_actions(&actions)?;
+            let attrs = match action {
+                PlanAction::Update | PlanAction::Replace => changed_attrs(&rc["change"]),
+                PlanAction::Create | PlanAction::Destroy => Vec::new(),
+            };
+            Some(ResourceChange {
+                address: rc["address"].as_str().unwrap_or("?").to_string(),
+                module: rc["module_address"].as_str().map(String::from),
+                resource_type: rc["type"].as_str().unwrap_or("?").to_string(),
+                action,
+                attrs,
+            })
+        })
+        .collect()
+}
+
+/// Summarize `terraform show -json <planfile>` output.
+fn format_plan_json(json: &Value) -> String {
+    let changes = parse_plan_json(json);
+    let count = |action: PlanAction| changes.iter().filter(|c| c.action == action).count();
+
+    let mut out = Vec::new();
+
+    let drift: Vec<&str> = json["resource_drift"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|d| d["address"].as_str())
+        .collect();
+    if !drift.is_empty() {
+        out.push(format!(
+            "⚠️  {} changed outside Terraform: {}",
+            drift.len(),
+            drift.join(", ")
+        ));
+    }
+
+    if changes.is_empty() {
+        out.push("✓ No changes. Infrastructure matches the configuration.".to_string());
+    } else {
+        out.push(format!(
+            "📋 Plan: {} to create, {} to update, {} to replace, {} to destroy",
+            count(PlanAction::Create),
+            count(PlanAction::Update),
+            count(PlanAction::Replace),
+            count(PlanAction::Destroy)
+        ));
+    }
+
+    for action in [
+        PlanAction::Create,
+        PlanAction::Update,
+        PlanAction::Replace,
+        PlanAction::Destroy,
+    ] {
+        let group: Vec<&ResourceChange> = changes.iter().filter(|c| c.action == action).collect();
+        if group.is_empty() {
+            continue;
+        }
+        out.push(String::new());
+        out.push(format!(
+            "{} {} ({})",
+            action.symbol(),
+            action.verb(),
+            group.len()
+        ));
+
+        match action {
+            PlanAction::Create | PlanAction::Destroy => {
+                // One line per (module, type)
+                let mut by_type: BTreeMap<(String, &str), Vec<&str>> = BTreeMap::new();
+                for change in &group {
+                    by_type
+                        .entry((
+                            change.module.clone().unwrap_or_default(),
+                            &change.resource_type,
+                        ))
+                        .or_default()
+                        .push(change.short_name());
+                }
+                let total = by_type.len();
+                for ((module, resource_type), names) in
+                    by_type.into_iter().take(MAX_RESOURCES_PER_ACTION)
+                {
+                    let scope = if module.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" ({})", module)
+                    };
+                    out.push(format!(
+                        "  {}{}: {}",
+                        resource_type,
+                        scope,
+                        names.join(", ")
+                    ));
+                }
+                if total > MAX_RESOURCES_PER_ACTION {
+                    out.push(format!(
+                        "  ... +{} more types",
+                        total - MAX_RESOURCES_PER_ACTION
+                    ));
+                }
+            }
+            PlanAction::Update | PlanAction::Replace => {
+                for change in group.iter().take(MAX_RESOURCES_PER_ACTION) {
+                    out.push(format!("  {}", change.address));
+                    for attr in change.attrs.iter().take(MAX_ATTRS_PER_RESOURCE) {
+                        out.push(format!(
+                            "      {}: {} → {}{}",
+                            attr.path,
+                            attr.before,
+                            attr.after,
+                            if attr.forces_replacement {
+                                " (forces replacement)"
+                            } else {
+                                ""
+                            }
+                        ));
+                    }
+                    if change.attrs.len() > MAX_ATTRS_PER_RESOURCE {
+                        out.push(format!(
+                            "      ... +{} more attributes",
+                            change.attrs.len() - MAX_ATTRS_PER_RESOURCE
+                        ));
+                    }
+                }
+                if group.len() > MAX_RESOURCES_PER_ACTION {
+                    out.push(format!(
+                        "  ... +{} more",
+                        group.len() - MAX_RESOURCES_PER_ACTION
+                    ));
+                }
+            }
+        }
+    }
+
+    let outputs: Vec<String> = json["output_changes"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, change)| {
+            let actions: Vec<&str> = change["actions"]
+                .as_array()?
+                .iter()
+                .filter_map(|a| a.as_str())
+                .collect();
+            let action = PlanAction::from_actions(&actions)?;
+            Some(format!("{}{}", action.symbol(), name))
+        })
+        .collect();
+    if !outputs.is_empty() {
+        out.push(String::new());
+        out.push(format!("Outputs: {}", outputs.join(", ")));
+    }
+
+    out.join("\n")
+}
+
+/// An error or warning block from Terraform's human-readable output.
+#[derive(Debug, PartialEq)]
+struct Diagnostic {
+    error: bool,
+    summary: String,
+    location: Option<String>,
+    detail: Option<String>,
+}
+
+impl Diagnostic {
+    fn render(&self) -> String {
+        let icon = if self.error { "❌" } else { "⚠️ " };
+        let mut line = match &self.location {
+            Some(location) => format!("{} {}: {}", icon, location, self.summary),
+            None => format!("{} {}", icon, self.summary),
+        };
+        if let Some(detail) = &self.detail {
+            line.push_str(" — ");
+            line.push_str(&crate::utils::truncate(detail, 120));
+        }
+        line
+    }
+}
+
+/// Parse `Error:`/`Warning:` blocks (boxed `│` or plain) with their `on FILE line N` location.
+fn parse_diagnostics(output: &str) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut open = false;
+
+    for raw in output.lines() {
+        let line = raw.trim_start_matches(['│', '╷', '╵']).trim();
+
+        let (error, summary) = if let Some(s) = line.strip_prefix("Error: ") {
+            (true, s)
+        } else if let Some(s) = line.strip_prefix("Warning: ") {
+            (false, s)
+        } else {
+            if raw.starts_with('╵') {
+                open = false;
+            }
+            if !open {
+                continue;
+            }
+            let Some(diag) = diagnostics.last_mut() else {
+                continue;
+            };
+            if let Some(rest) = line.strip_prefix("on ") {
+                // "on main.tf line 12, in resource ...:"
+                if let Some((file, rest)) = rest.split_once(" line ") {
+                    let number: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+                    diag.location = Some(format!("{}:{}", file, number));
+                }
+            } else if diag.detail.is_none()
+                && !line.is_empty()
+                && !line.starts_with("with ")
+                && !line.starts_with('├')
+                && !line
+                    .split_once(':')
+                    .is_some_and(|(n, _)| n.chars().all(|c| c.is_ascii_digit()))
+            {
+                diag.detail = Some(line.to_string());
+            }
+            continue;
+        };
+
+        diagnostics.push(Diagnostic {
+            error,
+            summary: summary.to_string(),
+            location: None,
+            detail: None,
+        });
+        open = true;
+    }
+
+    diagnostics
+}
+
+/// Diagnostics only, or `fallback` when there are none.
+fn diagnostics_or(output: &str, fallback: &str) -> String {
+    let diagnostics = parse_diagnostics(output);
+    if diagnostics.is_empty() {
+        return fallback.to_string();
+    }
+    diagnostics
+        .iter()
+        .map(Diagnostic::render)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Progress lines that carry no information once the run is over.
+fn is_progress_noise(line: &str) -> bool {
+    [
+        ": Refreshing state...",
+        ": Reading...",
+        ": Read complete after",
+        ": Still creating...",
+        ": Still modifying...",
+        ": Still destroying...",
+        ": Still reading...",
+        ": Creating...",
+        ": Modifying...",
+        ": Destroying...",
+    ]
+    .iter()
+    .any(|noise| line.contains(noise))
+}
+
+/// Text plan output, used when `show -json` is unavailable.
+fn filter_plan_text(output: &str) -> String {
+    let mut out = Vec::new();
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if is_progress_noise(trimmed) {
+            continue;
+        }
+        if let Some(header) = trimmed.strip_prefix("# ") {
+            // "# aws_instance.web will be updated in-place"
+            if header.contains(" will be ") || header.contains(" must be ") {
+                out.push(format!("  {}", header));
+            }
+        } else if trimmed.starts_with("Plan: ") || trimmed.starts_with("No changes.") {
+            out.push(trimmed.to_string());
+        }
+    }
+    let diagnostics = diagnostics_or(output, "");
+    if !diagnostics.is_empty() {
+        out.push(diagnostics);
+    }
+    if out.is_empty() {
+        return "ok ✓".to_string();
+    }
+    out.join("\n")
+}
+
+fn filter_apply_output(output: &str) -> String {
+    let mut done = Vec::new();
+    let mut summary = None;
+    let mut outputs = Vec::new();
+    let mut in_outputs = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("Apply complete!") || trimmed.starts_with("Destroy complete!") {
+            summary = Some(trimmed.to_string());
+            continue;
+        }
+        if trimmed == "Outputs:" {
+            in_outputs = true;
+            continue;
+        }
+        if in_outputs {
+            if trimmed.is_empty() {
+                in_outputs = outputs.is_empty();
+            } else {
+                outputs.push(format!("  {}", trimmed));
+            }
+            continue;
+        }
+
+        for (marker, symbol) in [
+            (": Creation complete after ", "+"),
+            (": Modifications complete after ", "~"),
+            (": Destruction complete after ", "-"),
+        ] {
+            if let Some((address, rest)) = trimmed.split_once(marker) {
+                let elapsed = rest.split_whitespace().next().unwrap_or("");
+                done.push(format!("  {} {} ({})", symbol, address, elapsed));
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    let diagnostics = diagnostics_or(output, "");
+    match summary {
+        Some(summary) => out.push(format!("✓ {}", summary)),
+        None if !diagnostics.is_empty() => out.push("❌ Apply failed".to_string()),
+        None => {}
+    }
+    let total = done.len();
+    out.extend(done.into_iter().take(MAX_RESOURCES_PER_ACTION));
+    if total > MAX_RESOURCES_PER_ACTION {
+        out.push(format!("  ... +{} more", total - MAX_RESOURCES_PER_ACTION));
+    }
+    if !diagnostics.is_empty() {
+        out.push(diagnostics);
+    }
+    if !outputs.is_empty() {
+        out.push("Outputs:".to_string());
+        out.extend(outputs.into_iter().take(10));
+    }
+
+    if out.is_empty() {
+        // Nothing recognizable (e.g. an approval prompt): keep non-noise lines
+        return output
+            .lines()
+            .filter(|l| !l.trim().is_empty() && !is_progress_noise(l))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    out.join("\n")
+}
+
+fn filter_init_output(output: &str) -> String {
+    let mut providers = Vec::new();
+    let mut modules = 0;
+    let mut success = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed
+            .strip_prefix("- Installed ")
+            .or_else(|| trimmed.strip_prefix("- Using previously-installed "))
+        {
+            // "hashicorp/aws v5.31.0 (signed by HashiCorp)"
+            let provider: Vec<&str> = rest.split_whitespace().take(2).collect();
+            providers.push(provider.join(" "));
+        } else if trimmed.starts_with("- ") && trimmed.contains(" in ") && !trimmed.contains("...")
+        {
+            // "- vpc in modules/vpc"
+            modules += 1;
+        } else if trimmed.contains("has been successfully initialized") {
+            success = true;
+        }
+    }
+
+    let diagnostics = diagnostics_or(output, "");
+    let mut out = Vec::new();
+    if success {
+        let mut line = format!("✓ init: {} providers", providers.len());
+        if modules > 0 {
+            line.push_str(&format!(", {} modules", modules));
+        }
+        if !providers.is_empty() {
+            line.push_str(&format!(" ({})", providers.join(", ")));
+        }
+        out.push(line);
+    } else if diagnostics.is_empty() {
+        out.push("init: no result".to_string());
+    }
+    if !diagnostics.is_empty() {
+        out.push(diagnostics);
+    }
+    out.join("\n")
+}
+
+/// Format `validate -json` output.
+fn filter_validate_json(json: &Value) -> String {
+    let diagnostics: Vec<Diagnostic> = json["diagnostics"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|d| {
+            let location = d["range"]["filename"].as_str().map(|file| {
+                match d["range"]["start"]["line"].as_u64() {
+                    Some(line) => format!("{}:{}", file, line),
+                    None => file.to_string(),
+                }
+            });
+            Diagnostic {
+                error: d["severity"].as_str() == Some("error"),
+                summary: d["summary"].as_str().unwrap_or("").to_string(),
+                location,
+                detail: d["detail"]
+                    .as_str()
+                    .and_then(|s| s.lines().find(|l| !l.trim().is_empty()))
+                    .map(String::from),
+            }
+        })
+        .collect();
+
+    let errors = diagnostics.iter().filter(|d| d.error).count();
+    let warnings = diagnostics.len() - errors;
+    let mut out = vec![if json["valid"].as_bool() == Some(true) {
+        if warnings > 0 {
+            format!("✓ Configuration valid ({} warnings)", warnings)
+        } else {
+            "✓ Configuration valid".to_string()
+        }
+    } else {
+        format!("❌ Invalid: {} errors, {} warnings", errors, warnings)
+    }];
+    out.extend(diagnostics.iter().map(Diagnostic::render));
+    out.join("\n")
+}
+
+fn has_flag(args: &[String], flag: &str) -> bool {
+    args.iter()
+        .any(|a| a == flag || a.starts_with(&format!("{}=", flag)))
+}
+
+/// Value of `-out=FILE` or `-out FILE`.
+fn out_flag(args: &[String]) -> Option<String> {
+    args.iter().enumerate().find_map(|(i, a)| {
+        a.strip_prefix("-out=")
+            .map(String::from)
+            .or_else(|| (a == "-out").then(|| args.get(i + 1).cloned()).flatten())
+    })
+}
+
+pub fn run_plan(bin: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // Save the plan so it can be read back as JSON
+    let temp_plan = tempfile::Builder::new()
+        .prefix("rtk-plan-")
+        .tempfile()
+        .context("Failed to create temporary plan file")?;
+    let user_out = out_flag(args);
+    let plan_file = user_out
+        .clone()
+        .unwrap_or_else(|| temp_plan.path().to_string_lossy().to_string());
+
+    let mut cmd = Command::new(bin);
+    cmd.arg("plan").env("TF_IN_AUTOMATION", "1");
+    if !has_flag(args, "-input") {
+        cmd.arg("-input=false");
+    }
+    if !has_flag(args, "-no-color") {
+        cmd.arg("-no-color");
+    }
+    if user_out.is_none() {
+        cmd.arg(format!("-out={}", plan_file));
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: {} plan {}", bin, args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {} plan. Is it installed?", bin))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    // -detailed-exitcode: 2 means "changes present"
+    let code = output.status.code().unwrap_or(1);
+    let planned = output.status.success() || (code == 2 && has_flag(args, "-detailed-exitcode"));
+
+    let filtered = if planned {
+        let show = Command::new(bin)
+            .args(["show", "-json", &plan_file])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| serde_json::from_slice::<Value>(&o.stdout).ok());
+        match show {
+            Some(json) => {
+                let mut text = format_plan_json(&json);
+                let warnings = diagnostics_or(&raw, "");
+                if !warnings.is_empty() {
+                    text.push('\n');
+                    text.push_str(&warnings);
+                }
+                text
+            }
+            None => filter_plan_text(&raw),
+        }
+    } else {
+        diagnostics_or(&raw, raw.trim())
+    };
+
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("{} plan {}", bin, args.join(" ")),
+        &format!("rtk {} plan {}", bin, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(code);
+    }
+    Ok(())
+}
+
+pub fn run_validate(bin: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new(bin);
+    cmd.arg("validate");
+    if !has_flag(args, "-json") {
+        cmd.arg("-json");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: {} validate -json {}", bin, args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {} validate. Is it installed?", bin))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = match serde_json::from_str::<Value>(&stdout) {
+        Ok(json) => filter_validate_json(&json),
+        Err(_) => diagnostics_or(&raw, raw.trim()),
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("{} validate {}", bin, args.join(" ")),
+        &format!("rtk {} validate {}", bin, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+pub fn run_apply(bin: &str, args: &[String], verbose: u8) -> Result<()> {
+    run_filtered(bin, "apply", args, verbose, filter_apply_output)
+}
+
+pub fn run_init(bin: &str, args: &[String], verbose: u8) -> Result<()> {
+    run_filtered(bin, "init", args, verbose, filter_init_output)
+}
+
+/// Run a subcommand with `-no-color` and a text filter over its output.
+fn run_filtered(
+    bin: &str,
+    subcommand: &str,
+    args: &[String],
+    verbose: u8,
+    filter: fn(&str) -> String,
+) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new(bin);
+    cmd.arg(subcommand).env("TF_IN_AUTOMATION", "1");
+    if !has_flag(args, "-no-color") {
+        cmd.arg("-no-color");
+    }
+    if !has_flag(args, "-input") {
+        cmd.arg("-input=false");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: {} {} {}", bin, subcommand, args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {} {}. Is it installed?", bin, subcommand))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("{} {} {}", bin, subcommand, args.join(" ")),
+        &format!("rtk {} {} {}", bin, subcommand, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+pub fn run_other(bin: &str, args: &[OsString], verbose: u8) -> Result<()> {
+    if args.is_empty() {
+        anyhow::bail!("{}: no subcommand specified", bin);
+    }
+
+    let timer = tracking::TimedExecution::start();
+
+    let subcommand = args[0].to_string_lossy();
+    if verbose > 0 {
+        eprintln!("Running: {} {} ...", bin, subcommand);
+    }
+
+    let status = Command::new(bin)
+        .args(args)
+        .status()
+        .with_context(|| format!("Failed to run {} {}", bin, subcommand))?;
+
+    let args_str = tracking::args_display(args);
+    timer.track_passthrough(
+        &format!("{} {}", bin, args_str),
+        &format!("rtk {} {} (passthrough)", bin, args_str),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAN_FIXTURE: &str = include_str!("../tests/fixtures/terraform_plan.json");
+
+    #[test]
+    fn test_parse_plan_json() {
+        let json: Value = serde_json::from_str(PLAN_FIXTURE).unwrap();
+        let changes = parse_plan_json(&json);
+        // no-op and data source reads are dropped
+        assert_eq!(changes.len(), 6);
+
+        let web = changes
+            .iter()
+            .find(|c| c.address == "aws_instance.web")
+            .unwrap();
+        assert_eq!(web.action, PlanAction::Update);
+        let paths: Vec<&str> = web.attrs.iter().map(|a| a.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "instance_type",
+                "tags.Env",
+                "user_data",
+                "vpc_security_group_ids[1]"
+            ]
+        );
+        let user_data = &web.attrs[2];
+        assert_eq!(user_data.before, "(sensitive)");
+        assert_eq!(user_data.after, "(sensitive)");
+
+        let db = changes
+            .iter()
+            .find(|c| c.address == "aws_db_instance.main")
+            .unwrap();
+        assert_eq!(db.action, PlanAction::Replace);
+        let engine = db
+            .attrs
+            .iter()
+            .find(|a| a.path == "engine_version")
+            .unwrap();
+        assert!(engine.forces_replacement);
+        assert_eq!(engine.after, "\"15.3\"");
+        let id = db.attrs.iter().find(|a| a.path == "id").unwrap();
+        assert_eq!(id.after, "(known after apply)");
+
+        let old = changes
+            .iter()
+            .find(|c| c.action == PlanAction::Destroy)
+            .unwrap();
+        assert_eq!(old.short_name(), "old[0]");
+    }
+
+    #[test]
+    fn test_format_plan_json() {
+        let json: Value = serde_json::from_str(PLAN_FIXTURE).unwrap();
+        let result = format_plan_json(&json);
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "⚠️  1 changed outside Terraform: aws_s3_bucket.logs"
+        );
+        assert_eq!(
+            lines[1],
+            "📋 Plan: 3 to create, 1 to update, 1 to replace, 1 to destroy"
+        );
+        assert!(result.contains(
+            "+ create (3)\n  aws_s3_bucket: assets\n  aws_cloudfront_distribution (module.cdn): this\n  aws_s3_bucket (module.cdn): origin"
+        ));
+        assert!(result.contains("      instance_type: \"t3.micro\" → \"t3.small\""));
+        assert!(result.contains("      user_data: (sensitive) → (sensitive)"));
+        assert!(result.contains("      engine_version: \"13.7\" → \"15.3\" (forces replacement)"));
+        assert!(result.contains("      password: (sensitive) → (sensitive)"));
+        assert!(!result.contains("hunter"));
+        assert!(result.contains("- destroy (1)\n  aws_iam_role (module.legacy): old[0]"));
+        assert!(result.ends_with("Outputs: +bucket_arn, ~db_password"));
+        assert!(!result.contains("aws_vpc"));
+        assert!(!result.contains("aws_ami"));
+    }
+
+    #[test]
+    fn test_format_plan_no_changes() {
+        let json: Value = serde_json::from_str(r#"{"resource_changes": []}"#).unwrap();
+        assert_eq!(
+            format_plan_json(&json),
+            "✓ No changes. Infrastructure matches the configuration."
+        );
+    }
+
+    #[test]
+    fn test_parse_diagnostics() {
+        let output = "\
+aws_vpc.main: Refreshing state... [id=vpc-1]
+╷
+│ Error: Unsupported argument
+│
+│   on main.tf line 12, in resource \"aws_instance\" \"web\":
+│   12:   instance_typo = \"t3.micro\"
+│
+│ An argument named \"instance_typo\" is not expected here.
+╵
+╷
+│ Warning: Deprecated attribute
+│
+│   on modules/vpc/main.tf line 3, in resource \"aws_s3_bucket\" \"b\":
+│    3:   acl = \"private\"
+│
+│ The attribute \"acl\" is deprecated.
+╵
+";
+        let diagnostics = parse_diagnostics(output);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0].render(),
+            "❌ main.tf:12: Unsupported argument — An argument named \"instance_typo\" is not expected here."
+        );
+        assert!(!diagnostics[1].error);
+        assert_eq!(
+            diagnostics[1].location.as_deref(),
+            Some("modules/vpc/main.tf:3")
+        );
+    }
+
+    #[test]
+    fn test_filter_apply_output() {
+        let output = "\
+aws_vpc.main: Refreshing state... [id=vpc-1]
+aws_s3_bucket.assets: Creating...
+aws_s3_bucket.assets: Still creating... [10s elapsed]
+aws_s3_bucket.assets: Creation complete after 12s [id=acme-assets]
+aws_instance.web: Modifying... [id=i-123]
+aws_instance.web: Modifications complete after 3s [id=i-123]
+
+Apply complete! Resources: 1 added, 1 changed, 0 destroyed.
+
+Outputs:
+
+bucket_arn = \"arn:aws:s3:::acme-assets\"
+";
+        assert_eq!(
+            filter_apply_output(output),
+            "✓ Apply complete! Resources: 1 added, 1 changed, 0 destroyed.\n  + aws_s3_bucket.assets (12s)\n  ~ aws_instance.web (3s)\nOutputs:\n  bucket_arn = \"arn:aws:s3:::acme-assets\""
+        );
+    }
+
+    #[test]
+    fn test_filter_init_output() {
+        let output = "\
+Initializing the backend...
+Initializing modules...
+- vpc in modules/vpc
+Initializing provider plugins...
+- Finding hashicorp/aws versions matching \"~> 5.0\"...
+- Installing hashicorp/aws v5.31.0...
+- Installed hashicorp/aws v5.31.0 (signed by HashiCorp)
+
+Terraform has been successfully initialized!
+
+You may now begin working with Terraform.
+";
+        assert_eq!(
+            filter_init_output(output),
+            "✓ init: 1 providers, 1 modules (hashicorp/aws v5.31.0)"
+        );
+    }
+
+    #[test]
+    fn test_filter_validate_json() {
+        let json: Value = serde_json::from_str(
+            r#"{"valid": false, "error_count": 1, "warning_count": 0, "diagnostics": [
+                {"severity": "error", "summary": "Missing required argument",
+                 "detail": "The argument \"ami\" is required, but no definition was found.",
+                 "range": {"filename": "main.tf", "start": {"line": 5, "column": 1}}}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            filter_validate_json(&json),
+            "❌ Invalid: 1 errors, 0 warnings\n❌ main.tf:5: Missing required argument — The argument \"ami\" is required, but no definition was found."
+        );
+    }
+}
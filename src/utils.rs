@@ -173,25 +173,34 @@ pub fn ok_confirmation(action: &str, detail: &str) -> String {
 }
 
 /// Detect the package manager used in the current directory.
-/// Returns "pnpm", "yarn", or "npm" based on lockfile presence.
+/// Returns "pnpm", "bun", "yarn", or "npm" based on lockfile presence.
 ///
 /// # Examples
 /// ```no_run
 /// use rtk::utils::detect_package_manager;
 /// let pm = detect_package_manager();
-/// // Returns "pnpm" if pnpm-lock.yaml exists, "yarn" if yarn.lock, else "npm"
+/// // Returns "pnpm" if pnpm-lock.yaml exists, "bun" if bun.lockb/bun.lock,
+/// // "yarn" if yarn.lock or .yarnrc.yml, else "npm"
 /// ```
 #[allow(dead_code)]
 pub fn detect_package_manager() -> &'static str {
-    if std::path::Path::new("pnpm-lock.yaml").exists() {
+    let exists = |f: &str| std::path::Path::new(f).exists();
+    if exists("pnpm-lock.yaml") {
         "pnpm"
-    } else if std::path::Path::new("yarn.lock").exists() {
+    } else if exists("bun.lockb") || exists("bun.lock") {
+        "bun"
+    } else if exists("yarn.lock") || exists(".yarnrc.yml") {
         "yarn"
     } else {
         "npm"
     }
 }
 
+/// Yarn 2+ ("Berry") projects are configured through `.yarnrc.yml`.
+pub fn is_yarn_berry() -> bool {
+    std::path::Path::new(".yarnrc.yml").exists()
+}
+
 /// Build a Command using the detected package manager's exec mechanism.
 /// Returns a Command ready to have tool-specific args appended.
 pub fn package_manager_exec(tool: &str) -> Command {
@@ -216,6 +225,11 @@ pub fn package_manager_exec(tool: &str) -> Command {
                 c.arg("exec").arg("--").arg(tool);
                 c
             }
+            "bun" => {
+                let mut c = Command::new("bun");
+                c.arg("x").arg("--no-install").arg(tool);
+                c
+            }
             _ => {
                 let mut c = Command::new("npx");
                 c.arg("--no-install").arg("--").arg(tool);
@@ -225,6 +239,44 @@ pub fn package_manager_exec(tool: &str) -> Command {
     }
 }
 
+/// Build a Command for a Ruby tool, via `bundle exec` when a Gemfile is present
+/// so the project's locked version is used.
+pub fn bundle_exec(tool: &str) -> Command {
+    if std::path::Path::new("Gemfile").exists() {
+        let mut c = Command::new("bundle");
+        c.arg("exec").arg(tool);
+        c
+    } else {
+        Command::new(tool)
+    }
+}
+
+/// Build a Command for a PHP tool, preferring the project's Composer-installed
+/// `vendor/bin/<tool>` over a global install.
+pub fn vendor_bin(tool: &str) -> Command {
+    let local = std::path::Path::new("vendor/bin").join(tool);
+    if local.exists() {
+        Command::new(local)
+    } else {
+        Command::new(tool)
+    }
+}
+
+/// Path relative to the current directory when possible (`file://` URLs
+/// from test reports included).
+pub fn relative_path(path: &str) -> String {
+    let path = path.strip_prefix("file://").unwrap_or(path);
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            std::path::Path::new(path)
+                .strip_prefix(&cwd)
+                .ok()
+                .map(|p| p.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| path.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -369,7 +421,7 @@ mod tests {
         // In the test environment (rtk repo), there's no JS lockfile
         // so it should default to "npm"
         let pm = detect_package_manager();
-        assert!(["pnpm", "yarn", "npm"].contains(&pm));
+        assert!(["pnpm", "bun", "yarn", "npm"].contains(&pm));
     }
 
     #[test]
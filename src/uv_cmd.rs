@@ -0,0 +1,312 @@
+use crate::python_env::{self, LockSnapshot};
+use crate::tracking;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::ffi::OsString;
+use std::process::Command;
+
+/// Environment changes listed before collapsing.
+const MAX_CHANGES_SHOWN: usize = 10;
+/// Lines kept from errors and unrecognized output.
+const MAX_OTHER_LINES: usize = 30;
+
+lazy_static::lazy_static! {
+    // "Resolved 42 packages in 120ms"
+    static ref RESOLVED: Regex = Regex::new(r"^Resolved (\d+) packages? in ").unwrap();
+    // " + requests==2.32.3", " - requests==2.31.0", " ~ myproj==0.1.0 (from file:///app)"
+    static ref ENV_CHANGE: Regex = Regex::new(r"^([+\-~]) (\S+?)==(\S+)").unwrap();
+    // `uv lock` reports "Updated requests v2.31.0 -> v2.32.3"; the lockfile summary covers it
+    static ref LOCK_CHANGE: Regex = Regex::new(r"^(?:Updated|Added|Removed) \S+ v\S+").unwrap();
+}
+
+#[derive(Debug, Clone)]
+pub enum UvCommand {
+    Sync,
+    Lock,
+    Add,
+    Run,
+    Tree { depth: usize },
+}
+
+pub fn run(cmd: UvCommand, args: &[String], verbose: u8) -> Result<()> {
+    match cmd {
+        UvCommand::Sync => run_project("sync", args, verbose),
+        UvCommand::Lock => run_project("lock", args, verbose),
+        UvCommand::Add => run_project("add", args, verbose),
+        UvCommand::Run => python_env::run_tool("uv", args, verbose),
+        UvCommand::Tree { depth } => run_tree(depth, args, verbose),
+    }
+}
+
+/// sync/lock/add: strip resolution and download progress, report environment
+/// and lockfile changes.
+fn run_project(subcommand: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: uv {} {}", subcommand, args.join(" "));
+    }
+
+    let lock = LockSnapshot::take("uv.lock");
+    let output = Command::new("uv")
+        .arg(subcommand)
+        .args(args)
+        .output()
+        .with_context(|| {
+            format!(
+                "Failed to run uv {}. Is uv installed? Try: pip install uv",
+                subcommand
+            )
+        })?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter_uv_output(subcommand, &raw, output.status.success(), &lock.changes());
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("uv {} {}", subcommand, args.join(" ")),
+        &format!("rtk uv {} {}", subcommand, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+fn filter_uv_output(
+    subcommand: &str,
+    output: &str,
+    success: bool,
+    lock_changes: &[String],
+) -> String {
+    let mut resolved: Option<String> = None;
+    let mut installed: Vec<(String, String)> = Vec::new();
+    let mut uninstalled: Vec<(String, String)> = Vec::new();
+    let mut warnings: Vec<&str> = Vec::new();
+    let mut other: Vec<&str> = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(caps) = RESOLVED.captures(trimmed) {
+            resolved = Some(caps[1].to_string());
+            continue;
+        }
+        if let Some(caps) = ENV_CHANGE.captures(trimmed) {
+            let entry = (caps[2].to_string(), caps[3].to_string());
+            match &caps[1] {
+                "+" => installed.push(entry),
+                "-" => uninstalled.push(entry),
+                // "~" is a rebuild of the local project, not a dependency change
+                _ => {}
+            }
+            continue;
+        }
+        if trimmed.starts_with("warning: ") {
+            warnings.push(trimmed);
+            continue;
+        }
+        if trimmed.starts_with("Using CPython")
+            || trimmed.starts_with("Using Python")
+            || trimmed.starts_with("Creating virtual environment")
+            || trimmed.starts_with("Prepared ")
+            || trimmed.starts_with("Installed ")
+            || trimmed.starts_with("Uninstalled ")
+            || trimmed.starts_with("Audited ")
+            || trimmed.starts_with("Downloading ")
+            || trimmed.starts_with("Downloaded ")
+            || trimmed.starts_with("Building ")
+            || trimmed.starts_with("Built ")
+            || trimmed.starts_with("Bytecode compiled ")
+            || LOCK_CHANGE.is_match(trimmed)
+        {
+            continue;
+        }
+        other.push(trimmed);
+    }
+
+    // A version swap shows up as "- pkg==old" then "+ pkg==new"
+    let mut changes: Vec<String> = Vec::new();
+    for (name, version) in &installed {
+        match uninstalled.iter().find(|(n, _)| n == name) {
+            Some((_, old)) if old != version => {
+                changes.push(format!("~ {} {} → {}", name, old, version))
+            }
+            Some(_) => {}
+            None => changes.push(format!("+ {} {}", name, version)),
+        }
+    }
+    for (name, version) in &uninstalled {
+        if !installed.iter().any(|(n, _)| n == name) {
+            changes.push(format!("- {} {}", name, version));
+        }
+    }
+
+    let status = if success { "✓" } else { "❌" };
+    let mut header = format!("{} uv {}", status, subcommand);
+    if !success {
+        header.push_str(" failed");
+    }
+    let mut details = Vec::new();
+    if let Some(n) = &resolved {
+        details.push(format!("{} packages resolved", n));
+    }
+    if !changes.is_empty() {
+        details.push(format!("{} environment changes", changes.len()));
+    }
+    if !details.is_empty() {
+        header.push_str(&format!(" ({})", details.join(", ")));
+    }
+
+    let mut lines = vec![header];
+    // The lockfile summary already names the packages when both changed
+    if lock_changes.is_empty() {
+        for change in changes.iter().take(MAX_CHANGES_SHOWN) {
+            lines.push(format!("  {}", change));
+        }
+        if changes.len() > MAX_CHANGES_SHOWN {
+            lines.push(format!("  ... +{} more", changes.len() - MAX_CHANGES_SHOWN));
+        }
+    }
+    lines.extend(lock_changes.iter().cloned());
+    for warning in &warnings {
+        lines.push(format!("  {}", warning));
+    }
+    for line in other.iter().take(MAX_OTHER_LINES) {
+        lines.push(format!("  {}", line));
+    }
+    if other.len() > MAX_OTHER_LINES {
+        lines.push(format!(
+            "  ... +{} more lines",
+            other.len() - MAX_OTHER_LINES
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn run_tree(depth: usize, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let (depth, args) = python_env::take_depth(depth, args);
+
+    // uv counts the project itself as depth 0; rtk depth 0 means direct dependencies
+    let mut cmd = Command::new("uv");
+    cmd.arg("tree")
+        .arg("--depth")
+        .arg((depth + 1).to_string())
+        .args(&args);
+
+    if verbose > 0 {
+        eprintln!("Running: uv tree --depth {} {}", depth + 1, args.join(" "));
+    }
+
+    let output = cmd.output().context("Failed to run uv tree")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    if !output.status.success() {
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+
+    // Resolution chatter goes to stderr; the tree itself is already compact
+    let filtered = stdout
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("uv tree {}", args.join(" ")),
+        &format!("rtk uv tree {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Runs an unsupported uv subcommand by passing it through directly
+pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("uv passthrough: {:?}", args);
+    }
+    let status = Command::new("uv")
+        .args(args)
+        .status()
+        .context("Failed to run uv")?;
+
+    let args_str = tracking::args_display(args);
+    timer.track_passthrough(
+        &format!("uv {}", args_str),
+        &format!("rtk uv {} (passthrough)", args_str),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_uv_sync() {
+        let input = include_str!("../tests/fixtures/uv_sync.txt");
+        assert_eq!(
+            filter_uv_output("sync", input, true, &[]),
+            "\
+✓ uv sync (12 packages resolved, 3 environment changes)
+  + httpx 0.27.0
+  ~ requests 2.31.0 → 2.32.3
+  - chardet 5.2.0"
+        );
+    }
+
+    #[test]
+    fn test_filter_uv_lock_with_changes() {
+        let input = "\
+Resolved 12 packages in 320ms
+Updated requests v2.31.0 -> v2.32.3
+Added httpx v0.27.0
+";
+        let changes = vec![
+            "📦 lockfile: 1 added, 0 removed, 1 bumped".to_string(),
+            "  + httpx 0.27.0".to_string(),
+            "  ~ requests 2.31.0 → 2.32.3".to_string(),
+        ];
+        assert_eq!(
+            filter_uv_output("lock", input, true, &changes),
+            "\
+✓ uv lock (12 packages resolved)
+📦 lockfile: 1 added, 0 removed, 1 bumped
+  + httpx 0.27.0
+  ~ requests 2.31.0 → 2.32.3"
+        );
+    }
+
+    #[test]
+    fn test_filter_uv_resolution_failure() {
+        let input = "\
+  × No solution found when resolving dependencies:
+  ╰─▶ Because there is no version of requests==99.0 and your project depends on requests==99.0, we can conclude that your project's requirements are unsatisfiable.
+";
+        let result = filter_uv_output("add", input, false, &[]);
+        assert!(result.starts_with("❌ uv add failed\n  × No solution found"));
+        assert!(result.contains("requirements are unsatisfiable"));
+    }
+}
@@ -208,6 +208,16 @@ fn extract_failures_regex(output: &str) -> Vec<TestFailure> {
     failures
 }
 
+/// Filter vitest output that was not produced with the JSON reporter (e.g. CI logs)
+pub(crate) fn filter_vitest_output(output: &str) -> String {
+    match VitestParser::parse(output) {
+        ParseResult::Full(data) | ParseResult::Degraded(data, _) => {
+            data.format(FormatMode::Compact)
+        }
+        ParseResult::Passthrough(raw) => raw,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum VitestCommand {
     Run,
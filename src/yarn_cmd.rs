@@ -0,0 +1,747 @@
+use crate::npm_cmd::filter_npm_output;
+use crate::parser::{
+    emit_degradation_warning, emit_passthrough_warning, truncate_output, Dependency,
+    DependencyState, FormatMode, OutputParser, ParseResult, TokenFormatter,
+};
+use crate::tracking;
+use crate::utils::is_yarn_berry;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+use std::ffi::OsString;
+use std::process::Command;
+
+/// Packages listed by name after an install/add.
+const MAX_ADDED_SHOWN: usize = 10;
+/// Warnings kept from a successful install.
+const MAX_WARNINGS: usize = 5;
+/// Lines kept from errors and unrecognized output.
+const MAX_OTHER_LINES: usize = 30;
+/// Packages listed by `rtk yarn list` before collapsing.
+const MAX_LIST_SHOWN: usize = 50;
+
+lazy_static::lazy_static! {
+    // Berry: "➤ YN0002: │ react-dom@npm:18.2.0 doesn't provide react ..."
+    static ref BERRY_LINE: Regex = Regex::new(r"^➤ YN(\d{4}): (.*)$").unwrap();
+    // Classic: "[2/4] Fetching packages..."
+    static ref STEP: Regex = Regex::new(r"^\[\d+/\d+\] ").unwrap();
+    static ref DONE_IN: Regex = Regex::new(r"^Done in ([\d.]+m?s)\.?$").unwrap();
+    // Berry: "Done with warnings in 2s 3ms"
+    static ref BERRY_DONE: Regex = Regex::new(r"^(?:Done|Failed)(.*?) in (.+)$").unwrap();
+    // Classic: "success Saved 2 new dependencies."
+    static ref SAVED: Regex = Regex::new(r"^success (Saved \d+ new dependenc\w+)\.?$").unwrap();
+}
+
+/// `yarn outdated --json` (classic) emits one JSON object per line
+#[derive(Debug, Deserialize)]
+struct YarnJsonLine {
+    #[serde(rename = "type")]
+    kind: String,
+    data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct YarnTable {
+    head: Vec<String>,
+    body: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YarnTree {
+    trees: Vec<YarnTreeNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YarnTreeNode {
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum YarnCommand {
+    List { depth: usize },
+    Outdated,
+    Install,
+    Add,
+    Why,
+    Run,
+}
+
+pub fn run(cmd: YarnCommand, args: &[String], verbose: u8) -> Result<()> {
+    match cmd {
+        YarnCommand::List { depth } => run_list(depth, args, verbose),
+        YarnCommand::Outdated => run_outdated(args, verbose),
+        YarnCommand::Install => run_install("install", args, verbose),
+        YarnCommand::Add => run_install("add", args, verbose),
+        YarnCommand::Why => run_why(args, verbose),
+        YarnCommand::Run => run_script(args, verbose),
+    }
+}
+
+fn run_install(subcommand: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: yarn {} {}", subcommand, args.join(" "));
+    }
+
+    let output = Command::new("yarn")
+        .arg(subcommand)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run yarn {}", subcommand))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter_yarn_install(subcommand, &raw, output.status.success());
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("yarn {} {}", subcommand, args.join(" ")),
+        &format!("rtk yarn {} {}", subcommand, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Filter yarn install/add output (classic and Berry) - drop step/progress
+/// lines, keep added packages, warnings and errors.
+fn filter_yarn_install(subcommand: &str, output: &str, success: bool) -> String {
+    let mut summary: Option<String> = None;
+    let mut duration: Option<String> = None;
+    let mut added: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    let mut other: Vec<String> = Vec::new();
+    // Classic prints "Direct dependencies" then "All dependencies" trees
+    let mut in_direct = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(caps) = BERRY_LINE.captures(trimmed) {
+            let message = caps[2].trim_start_matches(['│', '┌', '└', '·', ' ']);
+            match &caps[1] {
+                "0000" => {
+                    if let Some(done) = BERRY_DONE.captures(message) {
+                        duration = Some(done[2].to_string());
+                    }
+                }
+                // Build/fetch/cache chatter
+                "0007" | "0008" | "0019" => {}
+                "0013" if !message.contains(" were ") => {}
+                // "9 packages were added to the project (+ 2.31 MiB)."
+                "0013" => {
+                    let count = message.split(" (").next().unwrap_or(message);
+                    summary = Some(count.trim_end_matches('.').to_string());
+                }
+                // "+ lodash@npm:4.17.21, and 3 more."
+                "0085" => added.push(message.trim_end_matches('.').to_string()),
+                code => {
+                    let entry = format!("YN{}: {}", code, message);
+                    if success {
+                        warnings.push(entry);
+                    } else {
+                        other.push(entry);
+                    }
+                }
+            }
+            continue;
+        }
+
+        if let Some(caps) = DONE_IN.captures(trimmed) {
+            duration = Some(caps[1].to_string());
+            continue;
+        }
+        if let Some(caps) = SAVED.captures(trimmed) {
+            summary = Some(caps[1].to_lowercase());
+            continue;
+        }
+        if trimmed == "success Already up-to-date." {
+            summary = Some("already up-to-date".to_string());
+            continue;
+        }
+        if trimmed == "info Direct dependencies" {
+            in_direct = true;
+            continue;
+        }
+        if trimmed == "info All dependencies" {
+            in_direct = false;
+            continue;
+        }
+        if let Some(pkg) = trimmed
+            .strip_prefix("├─ ")
+            .or_else(|| trimmed.strip_prefix("└─ "))
+        {
+            if in_direct {
+                added.push(format!("+ {}", pkg));
+            }
+            continue;
+        }
+        if let Some(warning) = trimmed.strip_prefix("warning ") {
+            warnings.push(warning.to_string());
+            continue;
+        }
+        if trimmed.starts_with("yarn ") && trimmed.contains(" v1.")
+            || STEP.is_match(trimmed)
+            || trimmed.starts_with("success ")
+            || trimmed.starts_with("info ")
+        {
+            continue;
+        }
+        other.push(trimmed.to_string());
+    }
+
+    let status = if success { "✓" } else { "❌" };
+    let mut header = format!("{} yarn {}", status, subcommand);
+    match &summary {
+        Some(s) => header.push_str(&format!(": {}", s)),
+        None if !success => header.push_str(" failed"),
+        None => {}
+    }
+    if let Some(d) = &duration {
+        header.push_str(&format!(" ({})", d));
+    }
+
+    let mut lines = vec![header];
+    if !added.is_empty() {
+        for pkg in added.iter().take(MAX_ADDED_SHOWN) {
+            lines.push(format!("  {}", pkg));
+        }
+        if added.len() > MAX_ADDED_SHOWN {
+            lines.push(format!("  ... +{} more", added.len() - MAX_ADDED_SHOWN));
+        }
+    }
+    if !warnings.is_empty() {
+        lines.push(format!("  ⚠ {} warnings:", warnings.len()));
+        for warning in warnings.iter().take(MAX_WARNINGS) {
+            lines.push(format!("    {}", warning));
+        }
+        if warnings.len() > MAX_WARNINGS {
+            lines.push(format!("    ... +{} more", warnings.len() - MAX_WARNINGS));
+        }
+    }
+    for line in other.iter().take(MAX_OTHER_LINES) {
+        lines.push(format!("  {}", line));
+    }
+    if other.len() > MAX_OTHER_LINES {
+        lines.push(format!(
+            "  ... +{} more lines",
+            other.len() - MAX_OTHER_LINES
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // Berry has no `yarn list`; `yarn info` lists the workspace's dependencies
+    let berry = is_yarn_berry();
+    let mut cmd = Command::new("yarn");
+    if berry {
+        cmd.args(["info", "--name-only", "--json"]);
+        if depth > 0 {
+            cmd.arg("--recursive");
+        }
+    } else {
+        cmd.arg("list")
+            .arg(format!("--depth={}", depth))
+            .arg("--json");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!(
+            "Running: yarn {} (depth {})",
+            if berry { "info" } else { "list" },
+            depth
+        );
+    }
+
+    let output = cmd.output().context("Failed to run yarn list")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if !output.status.success() {
+        anyhow::bail!("yarn list failed: {}", stderr.trim());
+    }
+
+    let filtered = match YarnListParser::parse(&stdout) {
+        ParseResult::Full(data) => format_dependency_list(&data),
+        ParseResult::Degraded(data, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("yarn list", &warnings.join(", "));
+            }
+            format_dependency_list(&data)
+        }
+        ParseResult::Passthrough(raw) => {
+            emit_passthrough_warning("yarn list", "All parsing tiers failed");
+            raw
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("yarn list --depth={}", depth),
+        &format!("rtk yarn list --depth={}", depth),
+        &stdout,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// "name@version" per line under a package count.
+pub(crate) fn format_dependency_list(data: &DependencyState) -> String {
+    if data.dependencies.is_empty() {
+        return "No packages installed".to_string();
+    }
+
+    let mut lines = vec![format!("{} packages", data.total_packages)];
+    for dep in data.dependencies.iter().take(MAX_LIST_SHOWN) {
+        let dev = if dep.dev_dependency { " (dev)" } else { "" };
+        lines.push(format!("{}@{}{}", dep.name, dep.current_version, dev));
+    }
+    if data.dependencies.len() > MAX_LIST_SHOWN {
+        lines.push(format!(
+            "... +{} more",
+            data.dependencies.len() - MAX_LIST_SHOWN
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Splits "name@1.2.3" / "@scope/name@1.2.3" / "name@npm:1.2.3".
+pub(crate) fn split_package_spec(spec: &str) -> Option<(&str, &str)> {
+    let at = spec[1..].find('@')? + 1;
+    let version = &spec[at + 1..];
+    let version = version.strip_prefix("npm:").unwrap_or(version);
+    if version.is_empty() {
+        return None;
+    }
+    Some((&spec[..at], version))
+}
+
+/// Parser for `yarn list --json` (classic) and `yarn info --name-only --json` (Berry)
+pub struct YarnListParser;
+
+impl OutputParser for YarnListParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        let mut names: Vec<String> = Vec::new();
+        let mut degraded = false;
+
+        for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            match serde_json::from_str::<serde_json::Value>(line) {
+                // Classic: {"type":"tree","data":{"type":"list","trees":[...]}}
+                Ok(serde_json::Value::Object(obj)) => {
+                    if obj.get("type").and_then(|t| t.as_str()) != Some("tree") {
+                        continue;
+                    }
+                    if let Some(Ok(tree)) = obj
+                        .get("data")
+                        .map(|d| serde_json::from_value::<YarnTree>(d.clone()))
+                    {
+                        names.extend(tree.trees.into_iter().map(|t| t.name));
+                    }
+                }
+                // Berry: "lodash@npm:4.17.21"
+                Ok(serde_json::Value::String(spec)) => names.push(spec),
+                // Plain tree output: "├─ lodash@4.17.21"
+                _ => {
+                    if let Some(spec) = line
+                        .strip_prefix("├─ ")
+                        .or_else(|| line.strip_prefix("└─ "))
+                    {
+                        degraded = true;
+                        names.push(spec.to_string());
+                    }
+                }
+            }
+        }
+
+        // Workspaces and patches have no plain version
+        let dependencies: Vec<Dependency> = names
+            .iter()
+            .filter(|spec| !spec.contains("@workspace:") && !spec.contains("@patch:"))
+            .filter_map(|spec| split_package_spec(spec))
+            .map(|(name, version)| Dependency {
+                name: name.to_string(),
+                current_version: version.to_string(),
+                latest_version: None,
+                wanted_version: None,
+                dev_dependency: false,
+            })
+            .collect();
+
+        if dependencies.is_empty() && names.is_empty() {
+            return ParseResult::Passthrough(truncate_output(input.trim(), 500));
+        }
+
+        let result = DependencyState {
+            total_packages: dependencies.len(),
+            outdated_count: 0,
+            dependencies,
+        };
+        if degraded {
+            ParseResult::Degraded(result, vec!["parsed tree output".to_string()])
+        } else {
+            ParseResult::Full(result)
+        }
+    }
+}
+
+fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // Only classic ships `outdated --json`; Berry's outdated plugin prints a table
+    let mut cmd = Command::new("yarn");
+    cmd.arg("outdated");
+    if !is_yarn_berry() && !args.iter().any(|a| a == "--json") {
+        cmd.arg("--json");
+    }
+    cmd.args(args);
+
+    if verbose > 0 {
+        eprintln!("Running: yarn outdated {}", args.join(" "));
+    }
+
+    let output = cmd.output().context("Failed to run yarn outdated")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = match YarnOutdatedParser::parse(&stdout) {
+        ParseResult::Full(data) => data.format(mode),
+        ParseResult::Degraded(data, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("yarn outdated", &warnings.join(", "));
+            }
+            data.format(mode)
+        }
+        ParseResult::Passthrough(truncated) => {
+            emit_passthrough_warning("yarn outdated", "All parsing tiers failed");
+            truncated
+        }
+    };
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("yarn outdated {}", args.join(" ")),
+        &format!("rtk yarn outdated {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    // Classic exits 1 when packages are outdated; only stderr means failure
+    let has_error = stderr.lines().any(|l| l.trim_start().starts_with("error"));
+    if !output.status.success() && has_error {
+        eprintln!("{}", stderr.trim());
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Parser for `yarn outdated --json` (table text as fallback)
+pub struct YarnOutdatedParser;
+
+impl OutputParser for YarnOutdatedParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        // Tier 1: NDJSON with a single "table" entry
+        let table = input
+            .lines()
+            .filter_map(|l| serde_json::from_str::<YarnJsonLine>(l.trim()).ok())
+            .find(|l| l.kind == "table")
+            .and_then(|l| serde_json::from_value::<YarnTable>(l.data).ok());
+        if let Some(table) = table {
+            let head: Vec<&str> = table.head.iter().map(|h| h.as_str()).collect();
+            return ParseResult::Full(outdated_from_rows(&head, &table.body));
+        }
+        if input.trim().is_empty() {
+            return ParseResult::Full(outdated_from_rows(&[], &[]));
+        }
+
+        // Tier 2: "Package  Current  Wanted  Latest  Package Type  URL" table
+        let mut head: Vec<&str> = Vec::new();
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for line in input.lines() {
+            let line = line.trim_start_matches(['➤', '│', ' ']);
+            let cells: Vec<&str> = line.split_whitespace().collect();
+            if head.is_empty() {
+                if cells.first() == Some(&"Package") && cells.contains(&"Current") {
+                    head = cells;
+                }
+                continue;
+            }
+            if cells.len() >= 3 && cells[1].starts_with(|c: char| c.is_ascii_digit()) {
+                rows.push(cells.iter().map(|c| c.to_string()).collect());
+            }
+        }
+        if head.is_empty() {
+            return ParseResult::Passthrough(truncate_output(input.trim(), 500));
+        }
+        ParseResult::Degraded(
+            outdated_from_rows(&head, &rows),
+            vec!["parsed table output".to_string()],
+        )
+    }
+}
+
+/// Maps table rows onto dependencies using the header's column names.
+fn outdated_from_rows(head: &[&str], rows: &[Vec<String>]) -> DependencyState {
+    let column = |name: &str| head.iter().position(|h| *h == name);
+    let (current, wanted, latest) = (column("Current"), column("Wanted"), column("Latest"));
+    let kind = column("Package Type").or_else(|| column("Type"));
+
+    let mut dependencies = Vec::new();
+    for row in rows {
+        let cell = |idx: Option<usize>| idx.and_then(|i| row.get(i)).cloned();
+        let (Some(name), Some(current)) = (row.first().cloned(), cell(current)) else {
+            continue;
+        };
+        dependencies.push(Dependency {
+            name,
+            current_version: current,
+            latest_version: cell(latest),
+            wanted_version: cell(wanted),
+            dev_dependency: cell(kind).is_some_and(|k| k == "devDependencies"),
+        });
+    }
+
+    let outdated_count = dependencies
+        .iter()
+        .filter(|d| d.latest_version.as_ref() != Some(&d.current_version))
+        .count();
+    DependencyState {
+        total_packages: dependencies.len(),
+        outdated_count,
+        dependencies,
+    }
+}
+
+fn run_why(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: yarn why {}", args.join(" "));
+    }
+
+    let output = Command::new("yarn")
+        .arg("why")
+        .args(args)
+        .output()
+        .context("Failed to run yarn why")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter_yarn_why(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("yarn why {}", args.join(" ")),
+        &format!("rtk yarn why {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Keep the match and the reasons; drop steps, disk sizes and tree spacers.
+fn filter_yarn_why(output: &str) -> String {
+    let lines: Vec<&str> = output
+        .lines()
+        .filter(|l| {
+            let t = l.trim();
+            !(t.is_empty()
+                || t == "│"
+                || t.starts_with("yarn why v")
+                || STEP.is_match(t)
+                || DONE_IN.is_match(t)
+                || t.starts_with("info Disk size")
+                || t.starts_with("info Number of shared")
+                || t.starts_with("info Size of"))
+        })
+        .map(|l| l.trim_end())
+        .collect();
+
+    if lines.is_empty() {
+        "No dependency path found".to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+fn run_script(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: yarn run {}", args.join(" "));
+    }
+
+    let output = Command::new("yarn")
+        .arg("run")
+        .args(args)
+        .output()
+        .context("Failed to run yarn run")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let filtered = filter_yarn_run(&raw);
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("yarn run {}", args.join(" ")),
+        &format!("rtk yarn run {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// Strip yarn's run banner, the echoed script and timing, then apply the
+/// npm boilerplate filter.
+fn filter_yarn_run(output: &str) -> String {
+    let mut echoed = false;
+    let kept: Vec<&str> = output
+        .lines()
+        .filter(|l| {
+            let t = l.trim();
+            if t.starts_with('$') && !echoed {
+                echoed = true;
+                return false;
+            }
+            !(t.starts_with("yarn run v")
+                || DONE_IN.is_match(t)
+                || t.starts_with("info Visit https://yarnpkg.com"))
+        })
+        .collect();
+    filter_npm_output(&kept.join("\n"))
+}
+
+/// Runs an unsupported yarn subcommand by passing it through directly
+pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("yarn passthrough: {:?}", args);
+    }
+    let status = Command::new("yarn")
+        .args(args)
+        .status()
+        .context("Failed to run yarn")?;
+
+    let args_str = tracking::args_display(args);
+    timer.track_passthrough(
+        &format!("yarn {}", args_str),
+        &format!("rtk yarn {} (passthrough)", args_str),
+    );
+
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_yarn_install_classic() {
+        let input = include_str!("../tests/fixtures/yarn_install.txt");
+        assert_eq!(
+            filter_yarn_install("add", input, true),
+            "\
+✓ yarn add: saved 2 new dependencies (3.21s)
+  + axios@1.6.8
+  + lodash@4.17.21
+  ⚠ 1 warnings:
+    \" > react-dom@18.2.0\" has unmet peer dependency \"react@^18.2.0\"."
+        );
+    }
+
+    #[test]
+    fn test_filter_yarn_install_berry() {
+        let input = include_str!("../tests/fixtures/yarn_berry_install.txt");
+        assert_eq!(
+            filter_yarn_install("install", input, true),
+            "\
+✓ yarn install: 9 packages were added to the project (2s 31ms)
+  + axios@npm:1.6.8, lodash@npm:4.17.21
+  ⚠ 2 warnings:
+    YN0002: react-dom@npm:18.2.0 doesn't provide react (p1a2b3), requested by my-app
+    YN0086: Some peer dependencies are incorrectly met; run yarn explain peer-requirements <hash> for details."
+        );
+    }
+
+    #[test]
+    fn test_yarn_outdated_parser_json() {
+        let input = include_str!("../tests/fixtures/yarn_outdated.json");
+        let result = YarnOutdatedParser::parse(input);
+        assert_eq!(result.tier(), 1);
+        let data = result.unwrap();
+        assert_eq!(data.outdated_count, 2);
+        assert!(data.dependencies[1].dev_dependency);
+        assert_eq!(
+            data.format(FormatMode::Compact),
+            "\
+2 outdated packages (of 2)
+axios: 1.6.0 → 1.6.8
+typescript: 5.3.3 → 5.4.5"
+        );
+    }
+
+    #[test]
+    fn test_yarn_list_parser() {
+        let classic = r#"{"type":"tree","data":{"type":"list","trees":[{"name":"@babel/core@7.24.0","children":[],"hint":null,"color":"bold","depth":0},{"name":"lodash@4.17.21","children":[],"hint":null,"color":"bold","depth":0}]}}"#;
+        let berry = "\"my-app@workspace:.\"\n\"lodash@npm:4.17.21\"\n\"@babel/core@npm:7.24.0\"\n";
+        for input in [classic, berry] {
+            let data = YarnListParser::parse(input).unwrap();
+            assert_eq!(data.total_packages, 2);
+            assert!(data
+                .dependencies
+                .iter()
+                .any(|d| d.name == "@babel/core" && d.current_version == "7.24.0"));
+        }
+    }
+
+    #[test]
+    fn test_filter_yarn_run() {
+        let input = "\
+yarn run v1.22.19
+$ tsc --noEmit
+src/index.ts(3,7): error TS2322: Type 'string' is not assignable to type 'number'.
+error Command failed with exit code 2.
+info Visit https://yarnpkg.com/en/docs/cli/run for documentation about this command.
+";
+        assert_eq!(
+            filter_yarn_run(input),
+            "\
+src/index.ts(3,7): error TS2322: Type 'string' is not assignable to type 'number'.
+error Command failed with exit code 2."
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Drives `rtk mcp` over a pipe with scripted JSON-RPC requests.
+
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+fn run_session(requests: &[Value], db_path: &std::path::Path) -> Vec<Value> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rtk"))
+        .arg("mcp")
+        .env("RTK_DB_PATH", db_path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn rtk mcp");
+
+    {
+        let mut stdin = child.stdin.take().unwrap();
+        for req in requests {
+            writeln!(stdin, "{}", req).unwrap();
+        }
+        // Closing stdin ends the session
+    }
+
+    let stdout = child.stdout.take().unwrap();
+    let responses = BufReader::new(stdout)
+        .lines()
+        .map(|l| serde_json::from_str(&l.unwrap()).expect("response is not JSON"))
+        .collect();
+
+    let status = child.wait().unwrap();
+    assert!(status.success());
+    responses
+}
+
+#[test]
+fn test_mcp_session_over_pipe() {
+    let dir = tempfile::tempdir().unwrap();
+    let data = dir.path().join("data.json");
+    std::fs::write(
+        &data,
+        r#"{"name":"rtk","tags":["a","b"],"nested":{"count":3}}"#,
+    )
+    .unwrap();
+    let db_path = dir.path().join("history.db");
+
+    let responses = run_session(
+        &[
+            json!({"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2024-11-05","capabilities":{},"clientInfo":{"name":"test","version":"0"}}}),
+            json!({"jsonrpc":"2.0","method":"notifications/initialized"}),
+            json!({"jsonrpc":"2.0","id":2,"method":"tools/list"}),
+            json!({"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"json","arguments":{"path":data}}}),
+            json!({"jsonrpc":"2.0","id":4,"method":"tools/call","params":{"name":"read","arguments":{}}}),
+            json!({"jsonrpc":"2.0","id":5,"method":"bogus"}),
+        ],
+        &db_path,
+    );
+
+    // The notification gets no response
+    assert_eq!(responses.len(), 5);
+    let ids: Vec<&Value> = responses.iter().map(|r| &r["id"]).collect();
+    assert_eq!(ids, [&json!(1), &json!(2), &json!(3), &json!(4), &json!(5)]);
+
+    assert_eq!(responses[0]["result"]["serverInfo"]["name"], "rtk");
+
+    let tools = responses[1]["result"]["tools"].as_array().unwrap();
+    assert!(tools.iter().any(|t| t["name"] == "git_diff"));
+    assert!(tools.iter().all(|t| t["inputSchema"].is_object()));
+
+    let call = &responses[2]["result"];
+    assert_eq!(call["isError"], false);
+    let text = call["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("name"), "unexpected output: {}", text);
+    assert!(text.contains("nested"), "unexpected output: {}", text);
+    assert!(
+        !text.contains("\"rtk\""),
+        "values must be stripped: {}",
+        text
+    );
+
+    assert_eq!(responses[3]["error"]["code"], -32602);
+    assert_eq!(responses[4]["error"]["code"], -32601);
+
+    // Tool calls are tracked like the CLI
+    assert!(db_path.exists());
+}